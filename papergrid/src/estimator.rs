@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+
+use crate::Grid;
+
+/// Estimator decides widths of columns instead of measuring a whole content of a grid.
+///
+/// It's useful when widths are known beforehand,
+/// when they're derived from something else than a content
+/// or when a content is too big or isn't complete yet, like in case of streamed data.
+///
+/// An estimated width includes a padding of a column.
+/// It's raised to a minimum width of the column and a content which doesn't fit is wrapped.
+/// A width forced by [`Grid::set_column_width`] takes precedence over an estimated one.
+///
+/// It's `Send + Sync` so a grid with an estimator can be moved to or shared with other threads.
+pub trait Estimator: Send + Sync {
+    /// Column_width returns a width of a column, `None` means that it's measured as usual.
+    fn column_width(&self, column: &ColumnRef<'_>) -> Option<usize>;
+}
+
+/// ColumnRef gives an [`Estimator`] access to a column being estimated.
+pub struct ColumnRef<'a> {
+    pub(crate) grid: &'a Grid,
+    pub(crate) contents: &'a [Vec<Cow<'a, str>>],
+    pub(crate) column: usize,
+}
+
+impl ColumnRef<'_> {
+    /// Index returns an index of the column.
+    pub fn index(&self) -> usize {
+        self.column
+    }
+
+    /// Count_rows returns an amount of cells in the column.
+    pub fn count_rows(&self) -> usize {
+        self.contents.len()
+    }
+
+    /// Count_columns returns an amount of columns in a grid.
+    pub fn count_columns(&self) -> usize {
+        self.grid.count_columns()
+    }
+
+    /// Cell_width returns a width of a cell in the column, including its padding.
+    ///
+    /// The function suppose you provide a correct row index.
+    pub fn cell_width(&self, row: usize) -> usize {
+        self.grid.cell_width(self.contents, row, self.column)
+    }
+}
+
+/// ExactWidths sets widths of columns by their indexes so no content is measured.
+///
+/// Columns which are not listed are measured as usual.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Grid, Entity, ExactWidths, Settings};
+///     let mut grid = Grid::new(1, 2);
+///     grid.set(Entity::Global, Settings::new().text("asdf"));
+///     grid.set_estimator(ExactWidths(vec![2]));
+///     assert_eq!(grid.to_string(), "+--+----+\n|as|asdf|\n|df|    |\n+--+----+\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExactWidths(pub Vec<usize>);
+
+impl Estimator for ExactWidths {
+    fn column_width(&self, column: &ColumnRef<'_>) -> Option<usize> {
+        self.0.get(column.index()).copied()
+    }
+}
+
+/// PercentWidths sets widths of columns as percentages of a given total width.
+///
+/// A total width doesn't include vertical lines.
+/// Widths are rounded by the largest remainder so they add up exactly to their share of the total,
+/// e.g. 2 columns of 50% of 11 get 6 and 5.
+/// Columns which are not listed are measured as usual.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Grid, Entity, PercentWidths, Settings};
+///     let mut grid = Grid::new(1, 2);
+///     grid.set(Entity::Global, Settings::new().text("asd"));
+///     grid.set_estimator(PercentWidths::new(10, vec![30, 70]));
+///     assert_eq!(grid.to_string(), "+---+-------+\n|asd|asd    |\n+---+-------+\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PercentWidths {
+    total: usize,
+    percents: Vec<usize>,
+}
+
+impl PercentWidths {
+    /// New creates an estimator which splits a `total` width between columns by `percents`.
+    pub fn new(total: usize, percents: Vec<usize>) -> Self {
+        Self { total, percents }
+    }
+
+    /// Widths returns widths of listed columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::PercentWidths;
+    ///     assert_eq!(PercentWidths::new(10, vec![33, 33, 34]).widths(), vec![3, 3, 4]);
+    ///     assert_eq!(PercentWidths::new(11, vec![50, 50]).widths(), vec![6, 5]);
+    ///     assert_eq!(PercentWidths::new(80, vec![20, 50, 30]).widths(), vec![16, 40, 24]);
+    /// ```
+    pub fn widths(&self) -> Vec<usize> {
+        let shares = self
+            .percents
+            .iter()
+            .map(|percent| self.total * percent)
+            .collect::<Vec<_>>();
+        let mut widths = shares.iter().map(|share| share / 100).collect::<Vec<_>>();
+
+        // a rounded down total is distributed between columns which lost the most by rounding,
+        // the leftmost ones go first in case of a tie
+        let total = shares.iter().sum::<usize>() / 100;
+        let left = total - widths.iter().sum::<usize>();
+        let mut columns = (0..widths.len()).collect::<Vec<_>>();
+        columns.sort_by_key(|&column| std::cmp::Reverse(shares[column] % 100));
+        for &column in columns.iter().take(left) {
+            widths[column] += 1;
+        }
+
+        widths
+    }
+}
+
+impl Estimator for PercentWidths {
+    fn column_width(&self, column: &ColumnRef<'_>) -> Option<usize> {
+        self.widths().get(column.index()).copied()
+    }
+}
+
+/// SampleWidths measures only a given number of first rows of a grid.
+///
+/// It's meant for streamed data where a table is rendered before all rows are known
+/// and a width of columns must not jump from frame to frame.
+/// A content of further rows which doesn't fit is wrapped.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Grid, Entity, SampleWidths, Settings};
+///     let mut grid = Grid::new(2, 1);
+///     grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+///     grid.set(Entity::Cell(1, 0), Settings::new().text("qwerty"));
+///     grid.set_estimator(SampleWidths(1));
+///     assert_eq!(grid.to_string(), "+---+\n|asd|\n+---+\n|qwe|\n|rty|\n+---+\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleWidths(pub usize);
+
+impl Estimator for SampleWidths {
+    fn column_width(&self, column: &ColumnRef<'_>) -> Option<usize> {
+        (0..column.count_rows().min(self.0))
+            .map(|row| column.cell_width(row))
+            .max()
+    }
+}
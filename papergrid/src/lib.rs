@@ -36,22 +36,67 @@ use std::{
     borrow::Cow,
     cmp::max,
     collections::HashMap,
-    fmt::{self, Display},
-    iter,
+    fmt,
+    hash::Hash,
+    io,
+    ops::{Index, IndexMut},
+    sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError},
 };
 
+mod estimator;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+
+pub use estimator::{ColumnRef, Estimator, ExactWidths, PercentWidths, SampleWidths};
+
 /// Grid provides a set of methods for building a text-based table
+///
+/// A clone of a grid shares providers and an estimator with the original one.
+#[derive(Clone)]
 pub struct Grid {
     size: (usize, usize),
     border_styles: Vec<Border>,
-    styles: HashMap<Entity, Style>,
-    cells: Vec<Vec<String>>,
+    theme: Option<Theme>,
+    styles: HashMap<Entity, StyleLayer>,
+    cells: Vec<Vec<Text>>,
+    providers: HashMap<Entity, Provider>,
+    cell_borders: HashMap<(usize, usize), CellBorder>,
+    row_borders: HashMap<usize, CellBorder>,
+    column_borders: HashMap<usize, CellBorder>,
+    horizontal_lines: HashMap<usize, HorizontalLine>,
+    horizontal_line_texts: HashMap<usize, String>,
+    vertical_lines: HashMap<usize, VerticalLine>,
+    sanitization: Sanitization,
+    tab_width: usize,
+    metadata: HashMap<usize, RowMetadata>,
+    details: HashMap<usize, String>,
+    show_details: bool,
+    widths: HashMap<usize, usize>,
+    heights: HashMap<usize, usize>,
+    size_policy: SizePolicy,
+    legend: Vec<(String, String)>,
+    captions: HashMap<CaptionPosition, (String, Alignment)>,
+    margin: Margin,
+    placement: Option<(Alignment, usize)>,
+    line_ending: LineEnding,
+    trailing_newline: bool,
+    header: bool,
+    estimator: Option<Arc<dyn Estimator>>,
+    spans: HashMap<(usize, usize), usize>,
+    row_spans: HashMap<(usize, usize), usize>,
+    span_distribution: SpanDistribution,
+    dimensions: DimensionsCache,
 }
 
+/// Provider is a function which produces a content of a cell by its row and column.
+///
+/// It's `Send + Sync` so a grid can be moved to or shared with other threads.
+pub type Provider = Arc<dyn Fn(usize, usize) -> String + Send + Sync>;
+
 impl Grid {
     /// The new method creates a grid instance with default styles.
     ///
-    /// The size of the grid can not be changed after the instance is created.
+    /// The size of the grid can be changed later only by adding or removing rows and columns.
     ///
     /// # Example
     ///
@@ -69,21 +114,92 @@ impl Grid {
     ///     )
     /// ```
     pub fn new(rows: usize, columns: usize) -> Self {
-        let mut styles = HashMap::new();
-        styles.insert(Entity::Global, Style::default());
-
-        let border_styles = iter::repeat(Self::default_border()).take(rows).collect();
+        let border_styles = vec![Self::default_border(); rows];
 
         Grid {
             size: (rows, columns),
-            cells: vec![vec![String::new(); columns]; rows],
+            cells: vec![vec![Text::default(); columns]; rows],
             border_styles,
-            styles,
+            theme: None,
+            styles: HashMap::new(),
+            providers: HashMap::new(),
+            cell_borders: HashMap::new(),
+            row_borders: HashMap::new(),
+            column_borders: HashMap::new(),
+            horizontal_lines: HashMap::new(),
+            horizontal_line_texts: HashMap::new(),
+            vertical_lines: HashMap::new(),
+            sanitization: Sanitization::default(),
+            tab_width: 4,
+            metadata: HashMap::new(),
+            details: HashMap::new(),
+            show_details: true,
+            widths: HashMap::new(),
+            heights: HashMap::new(),
+            size_policy: SizePolicy::default(),
+            legend: Vec::new(),
+            captions: HashMap::new(),
+            margin: Margin::default(),
+            placement: None,
+            line_ending: LineEnding::default(),
+            trailing_newline: true,
+            header: true,
+            estimator: None,
+            spans: HashMap::new(),
+            row_spans: HashMap::new(),
+            span_distribution: SpanDistribution::default(),
+            dimensions: DimensionsCache::new(Dimensions::new(rows, columns)),
+        }
+    }
+
+    /// From_rows creates a grid with a given content of cells.
+    ///
+    /// The number of columns is a length of the longest row, shorter rows are filled with empty cells.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let grid = Grid::from_rows(vec![vec!["0-0", "0-1"], vec!["1-0"]]);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |0-0|0-1|\n\
+    ///           +---+---+\n\
+    ///           |1-0|   |\n\
+    ///           +---+---+\n"
+    ///     )
+    /// ```
+    pub fn from_rows<R, C, S>(rows: R) -> Self
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut cells = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|text| Text::from(text.into()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let count_columns = cells.iter().map(|row| row.len()).max().unwrap_or(0);
+        for row in cells.iter_mut() {
+            row.resize(count_columns, Text::default());
         }
+
+        let mut grid = Self::new(cells.len(), count_columns);
+        grid.cells = cells;
+        grid
     }
 
     /// Set method is responsible for modification of cell/row/column.
     ///
+    /// Alignment and padding are kept as layers, a property which is set for a more specific entity wins:
+    /// a global setting is overridden by a row, a row by a column and a column by a cell.
+    /// A property which isn't set in `settings` is left as it is.
+    ///
     /// The method panics if incorrect cell/row/column index is given.
     ///
     /// # Example
@@ -105,30 +221,399 @@ impl Grid {
     /// ```
     pub fn set(&mut self, entity: Entity, settings: Settings) {
         if let Some(text) = settings.text {
+            self.providers.remove(&entity);
             self.set_text(&entity, text);
         }
 
-        if settings.ident.is_none() && settings.alignment.is_none() {
+        if settings.padding.is_none()
+            && settings.alignment.is_none()
+            && settings.vertical_alignment.is_none()
+        {
             return;
         }
 
-        // todo: might it's worth to be able to modify only ident/alignemt of a cell instead of the whole style
-        // an example when we have a global setting with ident and we only want to modify an alignment of a partical cell
-        // but Style::default() will override global ident...
-        let mut s = Style::default();
-        if let Some(ident) = settings.ident {
-            s.ident = ident;
+        let layer = self.style_layer_mut(entity);
+        if let Some(padding) = settings.padding {
+            layer.padding = Some(padding);
         }
         if let Some(alignment) = settings.alignment {
-            s.alignment = alignment;
+            layer.alignment = Some(alignment);
+        }
+        if let Some(alignment) = settings.vertical_alignment {
+            layer.vertical_alignment = Some(alignment);
+        }
+    }
+
+    /// Try_set is the same as [`Grid::set`] but it returns an error instead of panicking
+    /// if the entity is out of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Error, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     assert_eq!(
+    ///         grid.try_set(Entity::Row(1), Settings::new().text("asd")),
+    ///         Err(Error::RowOutOfRange { row: 1, count_rows: 1 }),
+    ///     );
+    /// ```
+    pub fn try_set(&mut self, entity: Entity, settings: Settings) -> Result<(), Error> {
+        match entity {
+            Entity::Global => {}
+            Entity::Row(row) => self.check_row(row)?,
+            Entity::Column(column) => self.check_column(column)?,
+            Entity::Cell(row, column) => {
+                self.check_row(row)?;
+                self.check_column(column)?;
+            }
+        }
+
+        self.set(entity, settings);
+        Ok(())
+    }
+
+    /// Set_provider backs a cell/row/column by a function which produces a content of each its cell.
+    ///
+    /// The function is called with a row and column index of a cell only when the grid is rendered,
+    /// so the content is never copied into the grid.
+    /// A provider takes precedence over a text set by [`Settings::text`],
+    /// and a provider of a more specific entity takes precedence over a less specific one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set_provider(Entity::Global, |row, column| format!("{}-{}", row, column));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |0-0|0-1|\n\
+    ///           +---+---+\n\
+    ///           |1-0|1-1|\n\
+    ///           +---+---+\n"
+    ///     )
+    /// ```
+    pub fn set_provider<F>(&mut self, entity: Entity, provider: F)
+    where
+        F: Fn(usize, usize) -> String + Send + Sync + 'static,
+    {
+        self.dimensions.lock().invalidate(&entity);
+        self.providers.insert(entity, Arc::new(provider));
+    }
+
+    /// Set_display backs a cell by a value which is formatted only when the grid is rendered.
+    ///
+    /// It's a shorthand for a provider of a cell,
+    /// so a value of a row which is removed before rendering is never formatted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::Grid;
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set_display(0, 0, 42).unwrap();
+    ///     grid.set_display(0, 1, 0.5).unwrap();
+    ///     assert_eq!(grid.to_string(), "+--+---+\n|42|0.5|\n+--+---+\n");
+    /// ```
+    pub fn set_display<D>(&mut self, row: usize, column: usize, value: D) -> Result<(), Error>
+    where
+        D: fmt::Display + Send + Sync + 'static,
+    {
+        self.check_row(row)?;
+        self.check_column(column)?;
+        self.set_provider(Entity::Cell(row, column), move |_, _| value.to_string());
+        Ok(())
+    }
+
+    /// Remove_provider removes a provider which was set for the entity.
+    pub fn remove_provider(&mut self, entity: &Entity) {
+        if self.providers.remove(entity).is_some() {
+            self.dimensions.lock().invalidate(entity);
         }
+    }
+
+    /// Set_sanitization sets a policy of handling control characters in a content of cells.
+    ///
+    /// By default escape sequences which could change a state of a terminal are removed,
+    /// only the ones setting colors and text styles are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, Sanitization};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("\u{1b}]0;title\u{7}asd"));
+    ///     assert_eq!(grid.to_string(), "+---+\n|asd|\n+---+\n");
+    ///
+    ///     grid.set_sanitization(Sanitization::Escape);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+----------------------+\n\
+    ///           |\\u{1b}]0;title\\u{7}asd|\n\
+    ///           +----------------------+\n"
+    ///     );
+    /// ```
+    pub fn set_sanitization(&mut self, sanitization: Sanitization) {
+        self.sanitization = sanitization;
+        self.dimensions.lock().invalidate(&Entity::Global);
+    }
+
+    /// Set_tab_width sets a distance between tab stops a tab in a content of cells is expanded to.
+    ///
+    /// By default a tab stop is each 4 columns.
+    /// A width of 0 leaves tabs as they are, in which case they break an alignment of a grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("a\tb"));
+    ///     assert_eq!(grid.to_string(), "+-----+\n|a   b|\n+-----+\n");
+    ///
+    ///     grid.set_tab_width(2);
+    ///     assert_eq!(grid.to_string(), "+---+\n|a b|\n+---+\n");
+    /// ```
+    pub fn set_tab_width(&mut self, width: usize) {
+        self.tab_width = width;
+        self.dimensions.lock().invalidate(&Entity::Global);
+    }
+
+    /// Set_margin sets a space around the whole grid, including its legend and details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Margin, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_margin(Margin::new(2, 1, 1, 0).fill('.'));
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "........\n\
+    ///           ..+---+.\n\
+    ///           ..|asd|.\n\
+    ///           ..+---+.\n"
+    ///     );
+    /// ```
+    pub fn set_margin(&mut self, margin: Margin) {
+        self.margin = margin;
+    }
+
+    /// Get_margin returns a space around the grid.
+    pub fn get_margin(&self) -> &Margin {
+        &self.margin
+    }
+
+    /// Set_placement aligns the whole grid, including its margin, within a given width,
+    /// like a width of a terminal, by indenting each line.
+    ///
+    /// A grid wider than the width is rendered as it is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Alignment, Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_placement(Alignment::Center, 9);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "  +---+\n\
+    ///           \u{20} |asd|\n\
+    ///           \u{20} +---+\n"
+    ///     );
+    /// ```
+    pub fn set_placement(&mut self, alignment: Alignment, width: usize) {
+        self.placement = Some((alignment, width));
+    }
+
+    /// Remove_placement renders a grid from the beginning of lines again.
+    pub fn remove_placement(&mut self) {
+        self.placement = None;
+    }
 
-        self.styles.insert(entity, s);
+    /// Set_line_ending sets a sequence which ends each line of a rendered grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, LineEnding, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_line_ending(LineEnding::CrLf);
+    ///     assert_eq!(grid.to_string(), "+---+\r\n|asd|\r\n+---+\r\n");
+    /// ```
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    /// Set_trailing_newline sets whether the last line of a rendered grid is ended by a line ending.
+    ///
+    /// It's on by default, turning it off makes it easier to embed a grid into other text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_trailing_newline(false);
+    ///     assert_eq!(grid.to_string(), "+---+\n|asd|\n+---+");
+    /// ```
+    pub fn set_trailing_newline(&mut self, trailing_newline: bool) {
+        self.trailing_newline = trailing_newline;
+    }
+
+    /// Set_header sets whether the first row is a header.
+    ///
+    /// It's on by default, when it's off a [`Theme`] draws a line under the first row
+    /// as any other line between rows instead of a header line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, Theme};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_theme(Theme::new().header('=', None, None, None));
+    ///     grid.set_header(false);
+    ///     assert_eq!(grid.to_string(), "asd\nasd\n");
+    /// ```
+    pub fn set_header(&mut self, header: bool) {
+        self.header = header;
+        self.dimensions.lock().invalidate_estimation();
+    }
+
+    /// Has_header returns whether the first row is a header, see [`Grid::set_header`].
+    pub fn has_header(&self) -> bool {
+        self.header
     }
 
     /// get_cell_content returns content without any style changes
+    ///
+    /// A content of a provider isn't taken into account.
+    /// The method panics if the cell is out of the grid, [`Grid::get_cell`] can be used instead.
     pub fn get_cell_content(&mut self, row: usize, column: usize) -> &str {
-        self.cells[row][column].as_str()
+        self.cells[row][column].as_ref()
+    }
+
+    /// Get_cell returns a content of a cell or `None` if the cell is out of the grid.
+    ///
+    /// A content of a provider isn't taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+    ///     assert_eq!(grid.get_cell(0, 0), Some("asd"));
+    ///     assert_eq!(grid.get_cell(1, 0), None);
+    /// ```
+    pub fn get_cell(&self, row: usize, column: usize) -> Option<&str> {
+        self.cells.get(row)?.get(column).map(|text| text.as_ref())
+    }
+
+    /// Get_content returns a content of a cell the way it's rendered, before any styling,
+    /// or `None` if the cell is out of the grid.
+    ///
+    /// Unlike [`Grid::get_cell`] a provider of the cell is evaluated,
+    /// so it gives a content of cells set by [`Grid::set_display`] as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+    ///     grid.set_display(0, 1, 42).unwrap();
+    ///     assert_eq!(grid.get_content(0, 0).as_deref(), Some("asd"));
+    ///     assert_eq!(grid.get_content(0, 1).as_deref(), Some("42"));
+    ///     assert_eq!(grid.get_cell(0, 1), Some(""));
+    /// ```
+    pub fn get_content(&self, row: usize, column: usize) -> Option<Cow<'_, str>> {
+        if row >= self.count_rows() || column >= self.count_columns() {
+            return None;
+        }
+
+        Some(self.raw_content(row, column))
+    }
+
+    /// Get_alignment returns a horizontal alignment of a cell, resolved from settings of its entities,
+    /// or `None` if the cell is out of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Alignment, Entity, Grid, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().alignment(Alignment::Center));
+    ///     grid.set(Entity::Column(1), Settings::new().alignment(Alignment::Right));
+    ///
+    ///     assert_eq!(grid.get_alignment(0, 0), Some(Alignment::Center));
+    ///     assert_eq!(grid.get_alignment(1, 1), Some(Alignment::Right));
+    ///     assert_eq!(grid.get_alignment(2, 1), None);
+    /// ```
+    pub fn get_alignment(&self, row: usize, column: usize) -> Option<Alignment> {
+        if row >= self.count_rows() || column >= self.count_columns() {
+            return None;
+        }
+
+        Some(self.style(row, column).alignment)
+    }
+
+    /// Get_cell_mut returns a content of a cell which can be modified in place
+    /// or `None` if the cell is out of the grid.
+    pub fn get_cell_mut(&mut self, row: usize, column: usize) -> Option<&mut String> {
+        let text = self.cells.get_mut(row)?.get_mut(column)?;
+        self.dimensions
+            .lock()
+            .invalidate(&Entity::Cell(row, column));
+
+        Some(text.to_mut())
+    }
+
+    /// Cells returns an iterator over all cells of the grid row by row.
+    ///
+    /// Each item is a row, a column and a content of a cell.
+    /// A content of a provider isn't taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Row(1), Settings::new().text("asd"));
+    ///
+    ///     let filled = grid.cells().filter(|(_, _, text)| !text.is_empty()).count();
+    ///     assert_eq!(filled, 2);
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize, &str)> {
+        self.rows().flatten()
+    }
+
+    /// Rows returns an iterator over rows of the grid,
+    /// each row is an iterator over its cells the same as in [`Grid::cells`].
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize, &str)>> {
+        self.cells.iter().enumerate().map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(column, text)| (row, column, text.as_ref()))
+        })
+    }
+
+    /// Columns returns an iterator over columns of the grid,
+    /// each column is an iterator over its cells the same as in [`Grid::cells`].
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize, &str)>> {
+        (0..self.count_columns()).map(move |column| {
+            self.cells
+                .iter()
+                .enumerate()
+                .map(move |(row, cells)| (row, column, cells[column].as_ref()))
+        })
     }
 
     /// Count_rows returns an amount of rows on the grid
@@ -143,6 +628,9 @@ impl Grid {
     /// Get_border_mut returns a border for a given row.
     /// The border can be modified.
     ///
+    /// If a [`Theme`] is set, borders of all rows are resolved from it first and the theme is dropped,
+    /// so borders are not updated by later changes of a structure of the grid.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -170,724 +658,6046 @@ impl Grid {
     /// ```
     pub fn get_border_mut(&mut self, row: usize) -> &mut Border {
         debug_assert!(row < self.count_rows());
+        if let Some(theme) = self.theme.take() {
+            let count_rows = self.count_rows();
+            for (i, border) in self.border_styles.iter_mut().enumerate() {
+                *border = Border {
+                    top_line: theme.line(i, count_rows, self.header).clone(),
+                    bottom_line: theme.line(i + 1, count_rows, self.header).clone(),
+                    inner: theme.inner.clone(),
+                };
+            }
+        }
+
+        // the border is changed through the reference and row spans are measured with its lines
+        self.dimensions.lock().invalidate_estimation();
         &mut self.border_styles[row]
     }
 
-    /// Remove_row removes a `row` from a grid.
+    /// Set_theme sets borders of the whole grid by their roles instead of borders of particular rows.
     ///
-    /// The row index must be started from 0
-    pub fn remove_row(&mut self, row: usize) {
-        self.cells.remove(row);
-        self.border_styles.remove(row);
-        self.size.0 -= 1;
-    }
-
-    /// Remove_row removes a `column` from a grid.
+    /// The theme is resolved by positions of rows at render time,
+    /// so it stays correct when rows are added, removed or hidden later.
     ///
-    /// The column index must be started from 0
-    pub fn remove_column(&mut self, column: usize) {
-        self.size.1 -= 1;
-        for row in 0..self.count_rows() {
-            self.cells[row].remove(column);
-        }
+    /// # Example
+    ///
+    /// ```rust
+    ///    use papergrid::{Grid, Entity, Settings, Theme};
+    ///    let mut grid = Grid::new(2, 1);
+    ///    grid.set(Entity::Global, Settings::new().text("asd"));
+    ///    grid.set_theme(
+    ///        Theme::new()
+    ///            .top('─', Some('┬'), Some('┌'), Some('┐'))
+    ///            .bottom('─', Some('┴'), Some('└'), Some('┘'))
+    ///            .header('═', Some('╪'), Some('╞'), Some('╡'))
+    ///            .inner(Some('│'), Some('│'), Some('│')),
+    ///    );
+    ///    grid.push_row();
+    ///    grid.set(Entity::Row(2), Settings::new().text("qwe"));
+    ///
+    ///    assert_eq!(
+    ///        grid.to_string(),
+    ///        "┌───┐\n\
+    ///         │asd│\n\
+    ///         ╞═══╡\n\
+    ///         │asd│\n\
+    ///         │qwe│\n\
+    ///         └───┘\n"
+    ///    )
+    /// ```
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = Some(theme);
+        self.dimensions.lock().invalidate_estimation();
     }
 
-    fn columns_width(&self) -> Vec<usize> {
-        (0..self.count_columns())
-            .map(|column| self.column_width(column))
-            .collect()
-    }
+    /// Set_cell_border overrides borders of a particular cell.
+    ///
+    /// Only characters which are set in the `border` are changed,
+    /// the ones set earlier stay in place.
+    /// An overridden character takes precedence over a border of a row;
+    /// when neighbour cells override the same character the one of a cell below or to the right wins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, CellBorder};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_cell_border(1, 1, CellBorder::full('*', '*', '*', '*', '#', '#', '#', '#')).unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +---#***#\n\
+    ///           |asd*asd*\n\
+    ///           +---#***#\n"
+    ///     )
+    /// ```
+    pub fn set_cell_border(
+        &mut self,
+        row: usize,
+        column: usize,
+        border: CellBorder,
+    ) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.check_column(column)?;
+        self.cell_borders
+            .entry((row, column))
+            .or_default()
+            .merge(border);
+        self.dimensions.lock().invalidate_estimation();
 
-    fn rows_height(&self) -> Vec<usize> {
-        (0..self.count_rows())
-            .map(|row| self.row_height(row))
-            .collect()
+        Ok(())
     }
 
-    fn set_text<S: Into<String>>(&mut self, entity: &Entity, text: S) {
-        let text = text.into();
-        match *entity {
-            Entity::Cell(row, column) => {
-                self.cells[row][column] = text;
-            }
-            Entity::Column(column) => {
-                for row in 0..self.count_rows() {
-                    self.cells[row][column] = text.clone();
-                }
-            }
-            Entity::Row(row) => {
-                for column in 0..self.count_columns() {
-                    self.cells[row][column] = text.clone();
-                }
-            }
-            Entity::Global => {
-                for row in 0..self.count_rows() {
-                    for column in 0..self.count_columns() {
-                        self.cells[row][column] = text.clone();
-                    }
-                }
-            }
-        }
+    /// Get_cell_border returns overridden borders of a cell.
+    ///
+    /// Borders set for a whole row or column are not taken into account.
+    pub fn get_cell_border(&self, row: usize, column: usize) -> Option<&CellBorder> {
+        self.cell_borders.get(&(row, column))
     }
 
-    // the function suppose you provide a correct column index
-    fn row_height(&self, row: usize) -> usize {
-        let mut height = 0;
-        for column in 0..self.count_columns() {
-            let style = self.style(row, column);
-            let cell = &self.cells[row][column];
-            let cell_height = cell.lines().count() + style.ident.top + style.ident.bottom;
-            height = max(height, cell_height);
-        }
-
-        height
+    /// Remove_cell_border removes all overridden borders of a cell.
+    pub fn remove_cell_border(&mut self, row: usize, column: usize) {
+        self.cell_borders.remove(&(row, column));
+        self.dimensions.lock().invalidate_estimation();
     }
 
-    // the function suppose you provide a correct column index
-    fn column_width(&self, column: usize) -> usize {
-        let mut width = 0;
-        for row in 0..self.count_rows() {
-            let style = self.style(row, column);
-            let cell = &self.cells[row][column];
-            let cell_width = string_width(cell) + style.ident.left + style.ident.right;
-            width = max(width, cell_width);
+    /// Set_horizontal_line overrides a horizontal line of a grid.
+    ///
+    /// The `line` is an index of a line which goes above a row with the same index,
+    /// the line under the last row has an index equal to the number of rows.
+    /// The override replaces a line derived from a [`Border`] of a row, [`HorizontalLine::empty`] hides it.
+    /// Borders of particular cells still take precedence over it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, HorizontalLine};
+    ///     let mut grid = Grid::new(3, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_horizontal_line(1, HorizontalLine::new('=', '+', Some('+'), Some('+'))).unwrap();
+    ///     grid.set_horizontal_line(2, HorizontalLine::empty()).unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+\n\
+    ///           |asd|\n\
+    ///           +===+\n\
+    ///           |asd|\n\
+    ///           |asd|\n\
+    ///           +---+\n"
+    ///     )
+    /// ```
+    pub fn set_horizontal_line(&mut self, line: usize, style: HorizontalLine) -> Result<(), Error> {
+        if line > self.count_rows() {
+            return Err(Error::LineOutOfRange {
+                line,
+                count_lines: self.count_rows() + 1,
+            });
         }
 
-        width
+        self.horizontal_lines.insert(line, style);
+        self.dimensions.lock().invalidate_estimation();
+        Ok(())
     }
 
-    fn style(&self, row: usize, column: usize) -> Style {
-        let v = [
-            self.styles.get(&Entity::Cell(row, column)),
-            self.styles.get(&Entity::Column(column)),
-            self.styles.get(&Entity::Row(row)),
-            self.styles.get(&Entity::Global),
-        ];
-
-        for styles in &v {
-            if let Some(style) = styles {
-                return (*style).clone();
-            }
-        }
-
-        unreachable!("there's a global settings guaranted in the map")
+    /// Remove_horizontal_line removes an override of a horizontal line,
+    /// so it's derived from a [`Border`] of a row again.
+    pub fn remove_horizontal_line(&mut self, line: usize) {
+        self.horizontal_lines.remove(&line);
+        self.dimensions.lock().invalidate_estimation();
     }
 
-    fn default_border() -> Border {
-        Border {
-            inner: LineStyle {
-                main: Some('-'),
-                intersection: Some('|'),
-                left_intersection: Some('|'),
-                right_intersection: Some('|'),
-            },
-            bottom_line: LineStyle {
-                main: Some('-'),
-                intersection: Some('+'),
-                left_intersection: Some('+'),
-                right_intersection: Some('+'),
-            },
-            top_line: LineStyle {
-                main: Some('-'),
-                intersection: Some('+'),
-                left_intersection: Some('+'),
-                right_intersection: Some('+'),
-            },
+    /// Override_horizontal_line_text puts a text into a horizontal line, like a title of a section.
+    ///
+    /// The text replaces characters of the line starting right after its left border,
+    /// it spans over intersections with vertical lines and it's cut so it doesn't cover the right border.
+    /// A hidden line is shown if it has a text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.override_horizontal_line_text(1, "- A -").unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +- A ---+\n\
+    ///           |asd|asd|\n\
+    ///           +---+---+\n"
+    ///     )
+    /// ```
+    pub fn override_horizontal_line_text<S: Into<String>>(
+        &mut self,
+        line: usize,
+        text: S,
+    ) -> Result<(), Error> {
+        if line > self.count_rows() {
+            return Err(Error::LineOutOfRange {
+                line,
+                count_lines: self.count_rows() + 1,
+            });
         }
-    }
 
-    fn build_cells<'a>(&'a self) -> Vec<Vec<Vec<String>>> {
-        let columns_widths = self.columns_width();
-        let rows_height = self.rows_height();
-
-        let count_rows = self.count_rows();
-        let count_columns = self.count_columns();
-        let mut rows = Vec::with_capacity(count_rows);
-        for row in 0..count_rows {
-            let mut cells = Vec::with_capacity(count_columns);
+        self.horizontal_line_texts.insert(line, text.into());
+        self.dimensions.lock().invalidate_estimation();
+        Ok(())
+    }
 
-            for column in 0..count_columns {
-                let style = self.style(row, column);
+    /// Remove_horizontal_line_text removes a text from a horizontal line.
+    pub fn remove_horizontal_line_text(&mut self, line: usize) {
+        self.horizontal_line_texts.remove(&line);
+        self.dimensions.lock().invalidate_estimation();
+    }
 
-                let text = build_cell(
-                    &self.cells[row][column],
-                    style,
-                    columns_widths[column],
-                    rows_height[row],
-                );
+    /// Set_vertical_line overrides a vertical line of a grid.
+    ///
+    /// The `line` is an index of a line which goes on the left of a column with the same index,
+    /// the line on the right of the last column has an index equal to the number of columns.
+    /// The override replaces a line derived from [`Border`]s of rows
+    /// together with its intersections with horizontal lines, [`VerticalLine::empty`] hides it.
+    /// Borders of particular cells still take precedence over it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, VerticalLine};
+    ///     let mut grid = Grid::new(2, 3);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_vertical_line(1, VerticalLine::new('#', '#', Some('#'), Some('#'))).unwrap();
+    ///     grid.set_vertical_line(2, VerticalLine::empty()).unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---#------+\n\
+    ///           |asd#asdasd|\n\
+    ///           +---#------+\n\
+    ///           |asd#asdasd|\n\
+    ///           +---#------+\n"
+    ///     )
+    /// ```
+    pub fn set_vertical_line(&mut self, line: usize, style: VerticalLine) -> Result<(), Error> {
+        if line > self.count_columns() {
+            return Err(Error::LineOutOfRange {
+                line,
+                count_lines: self.count_columns() + 1,
+            });
+        }
 
-                cells.push(text);
-            }
+        self.vertical_lines.insert(line, style);
+        self.dimensions.lock().invalidate_estimation();
+        Ok(())
+    }
 
-            rows.push(cells);
-        }
+    /// Remove_vertical_line removes an override of a vertical line,
+    /// so it's derived from [`Border`]s of rows again.
+    pub fn remove_vertical_line(&mut self, line: usize) {
+        self.vertical_lines.remove(&line);
+        self.dimensions.lock().invalidate_estimation();
+    }
 
-        rows
+    /// Set_row_border overrides borders of all cells of a row.
+    ///
+    /// It's stored once for the whole row, borders of a particular cell and a column take precedence over it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, CellBorder};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_row_border(1, CellBorder::new().bottom('=')).unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +===+===+\n"
+    ///     )
+    /// ```
+    pub fn set_row_border(&mut self, row: usize, border: CellBorder) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.row_borders.entry(row).or_default().merge(border);
+        self.dimensions.lock().invalidate_estimation();
+        Ok(())
     }
 
-    fn build_row(
-        &self,
-        f: &mut std::fmt::Formatter<'_>,
-        row: &[Vec<String>],
-        border: &LineStyle,
-    ) -> fmt::Result {
-        let height = row.iter().map(|cell| cell.len()).max().unwrap_or_else(|| 0);
-        for i in 0..height {
-            write_option(f, border.left_intersection)?;
+    /// Set_column_border overrides borders of all cells of a column.
+    ///
+    /// It's stored once for the whole column, a border of a particular cell takes precedence over it
+    /// and it takes precedence over a border of a row.
+    pub fn set_column_border(&mut self, column: usize, border: CellBorder) -> Result<(), Error> {
+        self.check_column(column)?;
+        self.column_borders.entry(column).or_default().merge(border);
+        self.dimensions.lock().invalidate_estimation();
+        Ok(())
+    }
 
-            for (y, cell) in row.iter().enumerate() {
-                if y != 0 {
-                    write_option(f, border.intersection)?;
-                }
+    /// Set_row_alignment sets an alignment of all cells of a row leaving their padding as it is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Alignment, Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd").ident(1, 1, 0, 0));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("qwerty"));
+    ///     grid.set_row_alignment(0, Alignment::Right).unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--------+\n\
+    ///           |    asd |\n\
+    ///           +--------+\n\
+    ///           | qwerty |\n\
+    ///           +--------+\n"
+    ///     )
+    /// ```
+    pub fn set_row_alignment(&mut self, row: usize, alignment: Alignment) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.style_layer_mut(Entity::Row(row)).alignment = Some(alignment);
+        Ok(())
+    }
 
-                write!(f, "{}", cell[i])?;
-            }
+    /// Set_column_alignment sets an alignment of all cells of a column leaving their padding as it is.
+    pub fn set_column_alignment(
+        &mut self,
+        column: usize,
+        alignment: Alignment,
+    ) -> Result<(), Error> {
+        self.check_column(column)?;
+        self.style_layer_mut(Entity::Column(column)).alignment = Some(alignment);
+        Ok(())
+    }
 
-            write_option(f, border.right_intersection)?;
+    /// Set_row_padding sets a padding of all cells of a row leaving their alignment as it is.
+    pub fn set_row_padding(&mut self, row: usize, padding: Padding) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.style_layer_mut(Entity::Row(row)).padding = Some(padding);
+        Ok(())
+    }
 
-            writeln!(f)?;
-        }
+    /// Set_column_padding sets a padding of all cells of a column leaving their alignment as it is.
+    pub fn set_column_padding(&mut self, column: usize, padding: Padding) -> Result<(), Error> {
+        self.check_column(column)?;
+        self.style_layer_mut(Entity::Column(column)).padding = Some(padding);
+        Ok(())
+    }
 
+    /// Set_row_metadata attaches a metadata to a whole row.
+    ///
+    /// The metadata isn't rendered by the grid itself,
+    /// it's a way to pass an information about a row to the code which renders it some other way.
+    pub fn set_row_metadata(&mut self, row: usize, metadata: RowMetadata) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.metadata.insert(row, metadata);
         Ok(())
     }
-}
 
-/// Settings represent setting of a particular cell
-#[derive(Debug, Clone, Default)]
-pub struct Settings {
-    text: Option<String>,
-    ident: Option<Ident>,
-    alignment: Option<Alignment>,
-}
+    /// Get_row_metadata returns a metadata attached to a row if there's any.
+    pub fn get_row_metadata(&self, row: usize) -> Option<&RowMetadata> {
+        self.metadata.get(&row)
+    }
 
-impl Settings {
-    /// New method constructs an instance of settings
-    pub fn new() -> Self {
-        Self::default()
+    /// Set_row_detail attaches a detail line to a row, like an error message of a failed test.
+    ///
+    /// A detail is rendered under the row across the whole width of the grid,
+    /// it's wrapped if it doesn't fit into it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_row_detail(0, "qwe").unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |asd|asd|\n\
+    ///           |qwe    |\n\
+    ///           +---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +---+---+\n"
+    ///     );
+    ///
+    ///     grid.show_details(false);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +---+---+\n"
+    ///     );
+    /// ```
+    pub fn set_row_detail<S: Into<String>>(&mut self, row: usize, detail: S) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.details.insert(row, detail.into());
+        Ok(())
     }
 
-    /// Text method sets content for a cell
-    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
-        self.text = Some(text.into());
-        self
+    /// Get_row_detail returns a detail line attached to a row if there's any.
+    pub fn get_row_detail(&self, row: usize) -> Option<&str> {
+        self.details.get(&row).map(|detail| detail.as_str())
     }
 
-    /// Ident method sets ident for a cell
-    pub fn ident(mut self, left: usize, right: usize, top: usize, bottom: usize) -> Self {
-        self.ident = Some(Ident {
-            left,
-            right,
-            top,
-            bottom,
-        });
-        self
+    /// Remove_row_detail removes a detail line attached to a row.
+    pub fn remove_row_detail(&mut self, row: usize) {
+        self.details.remove(&row);
     }
 
-    /// Alignment method sets alignment for a cell
-    pub fn alignment(mut self, alignment: Alignment) -> Self {
-        self.alignment = Some(alignment);
-        self
+    /// Show_details turns rendering of detail lines on and off.
+    ///
+    /// Details are kept in the grid when they are hidden. By default they are shown.
+    pub fn show_details(&mut self, show: bool) {
+        self.show_details = show;
     }
-}
 
-/// Border structure represent all borders of a row
-#[derive(Debug, Clone)]
-pub struct Border {
-    top_line: LineStyle,
-    bottom_line: LineStyle,
-    inner: LineStyle,
-}
+    /// Set_column_width forces a width of a column, including its padding.
+    ///
+    /// A content which doesn't fit is wrapped.
+    /// A column can't be narrower than its padding plus one character,
+    /// what happens with such a width depends on a [`SizePolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Error, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asdf").ident(1, 1, 0, 0));
+    ///
+    ///     grid.set_column_width(0, 4).unwrap();
+    ///     assert_eq!(grid.to_string(), "+----+\n| as |\n| df |\n+----+\n");
+    ///
+    ///     assert_eq!(
+    ///         grid.set_column_width(0, 2),
+    ///         Err(Error::WidthTooSmall { column: 0, width: 2, min_width: 3 }),
+    ///     );
+    /// ```
+    pub fn set_column_width(&mut self, column: usize, width: usize) -> Result<(), Error> {
+        self.check_column(column)?;
 
-impl Border {
-    /// empty border returns a border for a row with no frame and no internal separation
-    pub fn empty(&mut self) -> &mut Self {
-        *self = Self {
-            top_line: LineStyle::default(),
-            bottom_line: LineStyle::default(),
-            inner: LineStyle::default(),
+        let min_width = self.min_column_width(column);
+        let width = match self.size_policy {
+            SizePolicy::Strict if width < min_width => {
+                return Err(Error::WidthTooSmall {
+                    column,
+                    width,
+                    min_width,
+                })
+            }
+            _ => max(width, min_width),
         };
 
-        self
+        self.widths.insert(column, width);
+        self.dimensions.lock().invalidate_estimation();
+        Ok(())
     }
 
-    /// The method sets a top border line.
+    /// Remove_column_width removes a forced width of a column.
+    pub fn remove_column_width(&mut self, column: usize) {
+        self.widths.remove(&column);
+        self.dimensions.lock().invalidate_estimation();
+    }
+
+    /// Set_row_height forces a height of a row, including its padding.
     ///
-    /// * `main` - is a character which is used for building line.
-    /// * `intersection` - a character which is used for internal separation on the line.
-    /// * `left_intersection` - a left border character.
-    /// * `right_intersection` - a right border character.
-    pub fn top(
-        &mut self,
-        main: char,
-        intersection: char,
-        left_intersection: Option<char>,
-        right_intersection: Option<char>,
-    ) -> &mut Self {
-        self.top_line = LineStyle {
-            main: Some(main),
-            intersection: Some(intersection),
-            left_intersection,
-            right_intersection,
+    /// Lines of a content which don't fit are cut.
+    /// A row can't be lower than its padding plus one line,
+    /// what happens with such a height depends on a [`SizePolicy`].
+    pub fn set_row_height(&mut self, row: usize, height: usize) -> Result<(), Error> {
+        self.check_row(row)?;
+
+        let min_height = self.min_row_height(row);
+        let height = match self.size_policy {
+            SizePolicy::Strict if height < min_height => {
+                return Err(Error::HeightTooSmall {
+                    row,
+                    height,
+                    min_height,
+                })
+            }
+            _ => max(height, min_height),
         };
 
-        self
+        self.heights.insert(row, height);
+        self.dimensions.lock().invalidate_estimation();
+        Ok(())
     }
 
-    /// The method sets a bottom border line.
-    ///
-    /// * `main` - is a character which is used for building line.
-    /// * `intersection` - a character which is used for internal separation on the line.
-    /// * `left_intersection` - a left border character.
-    /// * `right_intersection` - a right border character.
-    pub fn bottom(
-        &mut self,
-        main: char,
-        intersection: char,
-        left_intersection: Option<char>,
-        right_intersection: Option<char>,
-    ) -> &mut Self {
-        self.bottom_line = LineStyle {
-            main: Some(main),
-            intersection: Some(intersection),
-            left_intersection,
-            right_intersection,
-        };
+    /// Remove_row_height removes a forced height of a row.
+    pub fn remove_row_height(&mut self, row: usize) {
+        self.heights.remove(&row);
+        self.dimensions.lock().invalidate_estimation();
+    }
 
-        self
+    /// Set_size_policy sets how too small widths and heights are handled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, SizePolicy};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///
+    ///     grid.set_size_policy(SizePolicy::Clamp);
+    ///     grid.set_column_width(0, 0).unwrap();
+    ///     assert_eq!(grid.to_string(), "+-+\n|a|\n|s|\n|d|\n+-+\n");
+    /// ```
+    pub fn set_size_policy(&mut self, policy: SizePolicy) {
+        self.size_policy = policy;
     }
 
-    /// The method sets an inner row symbols.
+    /// Set_estimator sets an [`Estimator`] which decides widths of columns
+    /// instead of measuring a whole content of a grid.
     ///
-    /// * `intersection` - a character which is used for internal separation on the line.
-    /// * `left_intersection` - a left border character.
-    /// * `right_intersection` - a right border character.
-    pub fn inner(
-        &mut self,
-        intersection: Option<char>,
-        left_intersection: Option<char>,
-        right_intersection: Option<char>,
-    ) -> &mut Self {
-        self.inner = LineStyle {
-            main: None,
-            intersection,
-            left_intersection,
-            right_intersection,
-        };
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, ExactWidths, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_estimator(ExactWidths(vec![5]));
+    ///     assert_eq!(grid.to_string(), "+-----+\n|asd  |\n+-----+\n");
+    /// ```
+    pub fn set_estimator<E: Estimator + 'static>(&mut self, estimator: E) {
+        self.estimator = Some(Arc::new(estimator));
+        self.dimensions.lock().invalidate_estimation();
+    }
 
-        self
+    /// Remove_estimator removes an estimator so all columns are measured again.
+    pub fn remove_estimator(&mut self) {
+        self.estimator = None;
+        self.dimensions.lock().invalidate_estimation();
     }
-}
 
-#[derive(Debug, Clone, Default)]
-struct LineStyle {
-    main: Option<char>,
-    intersection: Option<char>,
-    left_intersection: Option<char>,
-    right_intersection: Option<char>,
-}
+    /// Set_column_span makes a cell span over `span` columns starting from its own one.
+    ///
+    /// A content of the cells it covers is not rendered and a span of 1 removes a span of the cell.
+    /// A span which overlaps with another span, except the one of the same cell it replaces,
+    /// is an [`Error::SpanOverlap`].
+    /// If a content of a spanned cell doesn't fit into the columns
+    /// they are widened as a [`SpanDistribution`] says.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a spanned cell"));
+    ///     grid.set_column_span(0, 0, 2).unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--------------+\n\
+    ///           |a spanned cell|\n\
+    ///           +-------+------+\n\
+    ///           |asd    |asd   |\n\
+    ///           +-------+------+\n"
+    ///     )
+    /// ```
+    pub fn set_column_span(&mut self, row: usize, column: usize, span: usize) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.check_column(column)?;
+        if column + span > self.count_columns() {
+            return Err(Error::SpanOutOfRange {
+                column,
+                span,
+                count_columns: self.count_columns(),
+            });
+        }
 
-impl LineStyle {
-    fn is_empty(&self) -> bool {
-        self.left_intersection.is_none()
-            && self.right_intersection.is_none()
-            && self.intersection.is_none()
-            && self.main.is_none()
+        if span > 1 {
+            for c in column..column + span {
+                let covered = matches!(self.span_of(row, c), Some((start, _)) if start != column)
+                    || self.row_span_of(row, c).is_some();
+                if covered {
+                    return Err(Error::SpanOverlap { row, column: c });
+                }
+            }
+
+            self.spans.insert((row, column), span);
+        } else {
+            self.spans.remove(&(row, column));
+        }
+
+        self.dimensions.lock().invalidate(&Entity::Row(row));
+        Ok(())
     }
-}
 
-/// Entity a structure which represent a set of cells.
-#[derive(PartialEq, Eq, Debug, Hash)]
-pub enum Entity {
-    /// All cells on the grid.
-    Global,
-    /// All cells in a column on the grid.
-    Column(usize),
-    /// All cells in a row on the grid.
-    Row(usize),
-    /// A particular cell (row, column) on the grid.
-    Cell(usize, usize),
-}
+    /// Remove_column_span removes a span of a cell so the cells it covered are rendered again.
+    pub fn remove_column_span(&mut self, row: usize, column: usize) {
+        if self.spans.remove(&(row, column)).is_some() {
+            self.dimensions.lock().invalidate(&Entity::Row(row));
+        }
+    }
 
-#[derive(Debug, Clone)]
-struct Style {
-    ident: Ident,
-    alignment: Alignment,
-}
+    /// Get_column_span returns a number of columns a cell spans over,
+    /// it's 1 for a regular cell.
+    pub fn get_column_span(&self, row: usize, column: usize) -> usize {
+        self.spans.get(&(row, column)).copied().unwrap_or(1)
+    }
 
-impl Default for Style {
-    fn default() -> Self {
-        Self {
-            alignment: Alignment::Left,
-            ident: Ident {
-                bottom: 0,
-                left: 0,
-                right: 0,
-                top: 0,
-            },
+    /// Set_row_span makes a cell span over `span` rows starting from its own one.
+    ///
+    /// A content of the cells it covers is not rendered as well as horizontal lines inside of it.
+    /// The content is put at the top of the spanned cell,
+    /// if it doesn't fit the last row of the span is made higher.
+    /// A span of 1 removes a span of the cell.
+    /// A span which overlaps with another span, except the one of the same cell it replaces,
+    /// is an [`Error::SpanOverlap`], so a cell can't span over rows and columns at the same time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("qwe"));
+    ///     grid.set_row_span(0, 0, 2).unwrap();
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+---+\n\
+    ///           |qwe|asd|\n\
+    ///           |   +---+\n\
+    ///           |   |asd|\n\
+    ///           +---+---+\n"
+    ///     )
+    /// ```
+    pub fn set_row_span(&mut self, row: usize, column: usize, span: usize) -> Result<(), Error> {
+        self.check_row(row)?;
+        self.check_column(column)?;
+        if row + span > self.count_rows() {
+            return Err(Error::RowSpanOutOfRange {
+                row,
+                span,
+                count_rows: self.count_rows(),
+            });
+        }
+
+        if span > 1 {
+            for r in row..row + span {
+                let covered = matches!(self.row_span_of(r, column), Some((start, _)) if start != row)
+                    || self.span_of(r, column).is_some();
+                if covered {
+                    return Err(Error::SpanOverlap { row: r, column });
+                }
+            }
+
+            self.row_spans.insert((row, column), span);
+        } else {
+            self.row_spans.remove(&(row, column));
         }
+
+        self.dimensions.lock().invalidate(&Entity::Column(column));
+        Ok(())
     }
-}
 
-#[derive(Debug, Clone)]
-struct Ident {
-    top: usize,
-    bottom: usize,
-    left: usize,
-    right: usize,
-}
+    /// Remove_row_span removes a span of a cell so the cells it covered are rendered again.
+    pub fn remove_row_span(&mut self, row: usize, column: usize) {
+        if self.row_spans.remove(&(row, column)).is_some() {
+            self.dimensions.lock().invalidate(&Entity::Column(column));
+        }
+    }
 
-/// Alignment represents an horizontal aligment of a cell content.
-#[derive(Debug, Clone)]
-pub enum Alignment {
-    Center,
-    Left,
-    Right,
-}
+    /// Get_row_span returns a number of rows a cell spans over,
+    /// it's 1 for a regular cell.
+    pub fn get_row_span(&self, row: usize, column: usize) -> usize {
+        self.row_spans.get(&(row, column)).copied().unwrap_or(1)
+    }
 
-impl Alignment {
-    #[cfg(not(feature = "color"))]
-    fn align(&self, text: &str, length: usize) -> String {
-        match self {
-            Alignment::Center => format!("{: ^1$}", text, length),
-            Alignment::Left => format!("{: <1$}", text, length),
-            Alignment::Right => format!("{: >1$}", text, length),
+    /// Is_spanned returns true if a cell is a part of a column or a row span,
+    /// including the cell a span starts from.
+    pub fn is_spanned(&self, row: usize, column: usize) -> bool {
+        self.span_of(row, column).is_some() || self.row_span_of(row, column).is_some()
+    }
+
+    /// Set_span_distribution sets how an extra width of spanned cells
+    /// is distributed across the columns they span over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings, SpanDistribution};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a spanned cell"));
+    ///     grid.set_column_span(0, 0, 2).unwrap();
+    ///
+    ///     grid.set_span_distribution(SpanDistribution::Last);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+--------------+\n\
+    ///           |a spanned cell|\n\
+    ///           +---+----------+\n\
+    ///           |asd|asd       |\n\
+    ///           +---+----------+\n"
+    ///     )
+    /// ```
+    pub fn set_span_distribution(&mut self, distribution: SpanDistribution) {
+        self.span_distribution = distribution;
+        self.dimensions.lock().invalidate_estimation();
+    }
+
+    /// Push_legend_entry adds an explanation of a symbol or a color used in a grid.
+    ///
+    /// Entries are rendered as a legend block under the grid in the order they were added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("!"));
+    ///     grid.push_legend_entry("!", "failed");
+    ///     grid.push_legend_entry("~~", "flaky");
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+-+\n\
+    ///           |asd|!|\n\
+    ///           +---+-+\n\
+    ///           Legend:\n\
+    ///           \u{20} !  failed\n\
+    ///           \u{20} ~~ flaky\n"
+    ///     )
+    /// ```
+    pub fn push_legend_entry<S, M>(&mut self, symbol: S, meaning: M)
+    where
+        S: Into<String>,
+        M: Into<String>,
+    {
+        self.legend.push((symbol.into(), meaning.into()));
+    }
+
+    /// Get_legend returns a list of legend entries as pairs of a symbol and its meaning.
+    pub fn get_legend(&self) -> &[(String, String)] {
+        &self.legend
+    }
+
+    /// Set_caption sets a text which is rendered above or under the grid, aligned relative to its width.
+    ///
+    /// A text wider than the grid is rendered as it is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Alignment, CaptionPosition, Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     grid.set_caption(CaptionPosition::Top, "Title", Alignment::Center);
+    ///     grid.set_caption(CaptionPosition::Bottom, "total: 2", Alignment::Right);
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "  Title  \n\
+    ///           +---+---+\n\
+    ///           |asd|asd|\n\
+    ///           +---+---+\n\
+    ///           \u{20}total: 2\n"
+    ///     )
+    /// ```
+    pub fn set_caption<S: Into<String>>(
+        &mut self,
+        position: CaptionPosition,
+        text: S,
+        alignment: Alignment,
+    ) {
+        self.captions.insert(position, (text.into(), alignment));
+    }
+
+    /// Remove_caption removes a caption set by [`Grid::set_caption`].
+    pub fn remove_caption(&mut self, position: CaptionPosition) {
+        self.captions.remove(&position);
+    }
+
+    /// Get_caption returns a text of a caption.
+    pub fn get_caption(&self, position: CaptionPosition) -> Option<&str> {
+        self.captions.get(&position).map(|(text, _)| text.as_str())
+    }
+
+    /// Push_row appends an empty row to the end of a grid.
+    ///
+    /// The new row takes over borders of the last row.
+    /// Cached widths of columns are updated only by the new row
+    /// so appending rows to an already rendered grid doesn't cause measuring of all cells again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Row(0), Settings::new().text("asd"));
+    ///
+    ///     grid.push_row();
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("qwe"));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("zxcv"));
+    ///
+    ///     assert_eq!(
+    ///          grid.to_string(),
+    ///          "+---+----+\n\
+    ///           |asd|asd |\n\
+    ///           +---+----+\n\
+    ///           |qwe|zxcv|\n\
+    ///           +---+----+\n"
+    ///     )
+    /// ```
+    pub fn push_row(&mut self) {
+        let count_rows = self.count_rows();
+        let border = match self.border_styles.last() {
+            Some(border) => border.clone(),
+            None => Self::default_border(),
+        };
+        // the last row becomes a middle one, a single row has no middle line to take it from
+        if count_rows > 1 {
+            self.border_styles[count_rows - 1] = self.border_styles[count_rows - 2].clone();
         }
+
+        self.border_styles.push(border);
+        self.cells.push(vec![Text::default(); self.count_columns()]);
+        self.size.0 += 1;
+        self.shift_horizontal_lines(|line| Some(if line == count_rows { line + 1 } else { line }));
+
+        let widths = self.paddings_width(count_rows);
+        self.dimensions.lock().insert_row(count_rows, &widths);
     }
 
-    #[cfg(feature = "color")]
-    fn align(&self, text: &str, length: usize) -> String {
-        let mut diff = length - string_width(text);
-        match self {
-            Alignment::Left => format!("{text}{space}", space = " ".repeat(diff), text = text),
-            Alignment::Right => format!("{space}{text}", space = " ".repeat(diff), text = text),
-            Alignment::Center => {
-                let left = diff / 2;
-                let right = diff - left;
-                format!(
-                    "{left}{text}{right}",
-                    left = " ".repeat(left),
-                    right = " ".repeat(right),
-                    text = text,
-                )
-            }
+    /// Insert_row inserts an empty row before a given `row`,
+    /// the rows after it are shifted together with their settings.
+    ///
+    /// The new row takes over borders of a row in the middle of the grid.
+    /// If the `row` is equal to the number of rows the row is pushed as by [`Grid::push_row`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Row(0), Settings::new().text("1"));
+    ///     grid.set(Entity::Row(1), Settings::new().text("3"));
+    ///
+    ///     grid.insert_row(1).unwrap();
+    ///     grid.set(Entity::Row(1), Settings::new().text("2"));
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+\n|1|\n+-+\n|2|\n+-+\n|3|\n+-+\n");
+    /// ```
+    pub fn insert_row(&mut self, row: usize) -> Result<(), Error> {
+        if row == self.count_rows() {
+            self.push_row();
+            return Ok(());
+        }
+
+        self.check_row(row)?;
+
+        let mut border = self.middle_border(row);
+        if row == 0 {
+            border.top_line = self.border_styles[0].top_line.clone();
+        }
+
+        self.border_styles.insert(row, border);
+        self.cells
+            .insert(row, vec![Text::default(); self.count_columns()]);
+        self.size.0 += 1;
+        self.shift_rows(|r| Some(if r >= row { r + 1 } else { r }));
+        self.shift_horizontal_lines(|line| Some(if line > row { line + 1 } else { line }));
+        self.shift_row_spans(|r, n| match r {
+            r if r >= row => Some((r + 1, n)),
+            r if row < r + n => Some((r, n + 1)),
+            r => Some((r, n)),
+        });
+
+        let widths = self.paddings_width(row);
+        self.dimensions.lock().insert_row(row, &widths);
+
+        Ok(())
+    }
+
+    /// Remove_row removes a `row` from a grid.
+    ///
+    /// The row index must be started from 0.
+    /// The rows after it are shifted together with their settings.
+    ///
+    /// If the first row is removed after a [`Theme`] is set, the header line goes away with it,
+    /// so the next row isn't drawn as a header. A theme set afterwards draws the next row as a header.
+    pub fn remove_row(&mut self, row: usize) -> Result<(), Error> {
+        self.check_row(row)?;
+
+        if row == 0 && self.theme.is_some() {
+            self.header = false;
+        }
+
+        // the first and the last rows keep outer lines of a grid
+        let border = self.border_styles.remove(row);
+        if row == 0 && !self.border_styles.is_empty() {
+            self.border_styles[0].top_line = border.top_line;
+        } else if row > 0 && row == self.border_styles.len() {
+            self.border_styles[row - 1].bottom_line = border.bottom_line;
         }
+
+        self.cells.remove(row);
+        self.size.0 -= 1;
+        self.dimensions.lock().remove_row(row);
+        self.shift_row_spans(|r, n| match r {
+            r if r == row => None,
+            r if r > row => Some((r - 1, n)),
+            r if row < r + n => Some((r, n - 1)),
+            r => Some((r, n)),
+        });
+        self.shift_rows(|r| match r {
+            r if r == row => None,
+            r if r > row => Some(r - 1),
+            r => Some(r),
+        });
+
+        // a line under the removed row goes away unless it's the bottom line of the grid
+        let removed_line = if row == self.count_rows() {
+            row
+        } else {
+            row + 1
+        };
+        self.shift_horizontal_lines(|line| match line {
+            line if line == removed_line => None,
+            line if line > removed_line => Some(line - 1),
+            line => Some(line),
+        });
+
+        Ok(())
     }
-}
 
-// I like old solution with Full/Frame/Off
+    /// Swap_rows swaps two rows together with their settings.
+    ///
+    /// Horizontal lines and borders stay in place as they belong to positions in a grid rather than to rows.
+    /// A row span which covers only one of the rows is removed, as it can't be moved without covering other rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 1);
+    ///     grid.set(Entity::Row(0), Settings::new().text("1"));
+    ///     grid.set(Entity::Row(1), Settings::new().text("2"));
+    ///
+    ///     grid.swap_rows(0, 1).unwrap();
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+\n|2|\n+-+\n|1|\n+-+\n");
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), Error> {
+        self.check_row(a)?;
+        self.check_row(b)?;
+        if a == b {
+            return Ok(());
+        }
 
-impl std::fmt::Display for Grid {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // It may happen when all cells removed via `remove_row`, `remove_column` methods
-        if self.count_rows() == 0 || self.count_columns() == 0 {
+        let covers = |row: usize, start: usize, span: usize| (start..start + span).contains(&row);
+        self.row_spans
+            .retain(|&(start, _), &mut span| covers(a, start, span) == covers(b, start, span));
+
+        self.cells.swap(a, b);
+        self.dimensions.lock().swap_rows(a, b);
+        self.shift_rows(|r| match r {
+            r if r == a => Some(b),
+            r if r == b => Some(a),
+            r => Some(r),
+        });
+
+        Ok(())
+    }
+
+    /// Swap_columns swaps two columns together with their settings.
+    ///
+    /// Vertical lines and borders stay in place as they belong to positions in a grid rather than to columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Column(0), Settings::new().text("1"));
+    ///     grid.set(Entity::Column(1), Settings::new().text("2"));
+    ///
+    ///     grid.swap_columns(0, 1).unwrap();
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+-+\n|2|1|\n+-+-+\n");
+    /// ```
+    pub fn swap_columns(&mut self, a: usize, b: usize) -> Result<(), Error> {
+        self.check_column(a)?;
+        self.check_column(b)?;
+        if a == b {
             return Ok(());
         }
 
-        let columns_width = self.columns_width();
-        let rows = self.build_cells();
+        for row in self.cells.iter_mut() {
+            row.swap(a, b);
+        }
+        self.dimensions.lock().swap_columns(a, b);
+        self.shift_columns(|c| match c {
+            c if c == a => Some(b),
+            c if c == b => Some(a),
+            c => Some(c),
+        });
 
-        for (i, row) in rows.iter().enumerate() {
-            let border = self
-                .border_styles
-                .get(i)
-                .expect("it's expected that grid has N styles where N is an amount of rows");
+        Ok(())
+    }
 
-            if i == 0 {
-                build_line(f, &columns_width, &border.top_line)?;
-            }
+    /// Push_column appends an empty column to a grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+    ///
+    ///     grid.push_column();
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("qwe"));
+    ///
+    ///     assert_eq!(grid.to_string(), "+---+---+\n|asd|qwe|\n+---+---+\n");
+    /// ```
+    pub fn push_column(&mut self) {
+        for row in self.cells.iter_mut() {
+            row.push(Text::default());
+        }
+
+        let count_columns = self.count_columns();
+        shift_keys(&mut self.vertical_lines, |line| {
+            Some(if line == count_columns {
+                line + 1
+            } else {
+                line
+            })
+        });
+
+        self.size.1 += 1;
+        self.dimensions.lock().push_column();
+    }
+
+    /// Insert_column inserts an empty column before a given `column`,
+    /// the columns after it are shifted together with their settings.
+    ///
+    /// If the `column` is equal to the number of columns the column is pushed as by [`Grid::push_column`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Column(0), Settings::new().text("1"));
+    ///     grid.set(Entity::Column(1), Settings::new().text("3"));
+    ///
+    ///     grid.insert_column(1).unwrap();
+    ///     grid.set(Entity::Column(1), Settings::new().text("2"));
+    ///
+    ///     assert_eq!(grid.to_string(), "+-+-+-+\n|1|2|3|\n+-+-+-+\n");
+    /// ```
+    pub fn insert_column(&mut self, column: usize) -> Result<(), Error> {
+        if column == self.count_columns() {
+            self.push_column();
+            return Ok(());
+        }
 
-            self.build_row(f, &row, &border.inner)?;
-            build_line(f, &columns_width, &border.bottom_line)?;
+        self.check_column(column)?;
+        for row in self.cells.iter_mut() {
+            row.insert(column, Text::default());
         }
 
+        self.size.1 += 1;
+        self.dimensions.lock().insert_column(column);
+        self.shift_columns(|c| Some(if c >= column { c + 1 } else { c }));
+        self.shift_column_spans(|c, n| match c {
+            c if c >= column => Some((c + 1, n)),
+            c if column < c + n => Some((c, n + 1)),
+            c => Some((c, n)),
+        });
+        shift_keys(&mut self.vertical_lines, |line| {
+            Some(if line > column { line + 1 } else { line })
+        });
+
         Ok(())
     }
-}
 
-fn build_cell(text: &str, style: Style, column_w: usize, row_h: usize) -> Vec<String> {
-    let width = column_w - style.ident.left - style.ident.right;
-    let height = row_h - style.ident.top - style.ident.bottom;
-    let text = split_text(text, width, height);
+    /// Remove_row removes a `column` from a grid.
+    ///
+    /// The column index must be started from 0.
+    /// The columns after it are shifted together with their settings.
+    pub fn remove_column(&mut self, column: usize) -> Result<(), Error> {
+        self.check_column(column)?;
+        self.size.1 -= 1;
+        for row in 0..self.count_rows() {
+            self.cells[row].remove(column);
+        }
+        self.dimensions.lock().remove_column(column);
+        // a span which starts at the removed column is dropped, the ones which cover it are shrunk
+        self.shift_column_spans(|c, n| match c {
+            c if c == column => None,
+            c if c > column => Some((c - 1, n)),
+            c if column < c + n => Some((c, n - 1)),
+            c => Some((c, n)),
+        });
+        self.shift_columns(|c| match c {
+            c if c == column => None,
+            c if c > column => Some(c - 1),
+            c => Some(c),
+        });
+
+        // a line on the right of the removed column goes away unless it's the right line of the grid
+        let removed_line = if column == self.count_columns() {
+            column
+        } else {
+            column + 1
+        };
+        shift_keys(&mut self.vertical_lines, |line| match line {
+            line if line == removed_line => None,
+            line if line > removed_line => Some(line - 1),
+            line => Some(line),
+        });
+
+        Ok(())
+    }
+
+    /// Resize changes a size of a grid to a given number of rows and columns.
+    ///
+    /// Content and settings of the cells which stay on the grid are kept in place,
+    /// new cells are empty and rows and columns are added or removed at the end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+    ///
+    ///     grid.resize(1, 3);
+    ///     grid.set(Entity::Cell(0, 2), Settings::new().text("qwe"));
+    ///
+    ///     assert_eq!(grid.to_string(), "+---++---+\n|asd||qwe|\n+---++---+\n");
+    /// ```
+    pub fn resize(&mut self, rows: usize, columns: usize) {
+        while self.count_rows() > rows {
+            let _ = self.remove_row(self.count_rows() - 1);
+        }
+        while self.count_columns() > columns {
+            let _ = self.remove_column(self.count_columns() - 1);
+        }
+        while self.count_columns() < columns {
+            self.push_column();
+        }
+        while self.count_rows() < rows {
+            self.push_row();
+        }
+    }
+
+    // a border of a row in the middle is prefered as it has an inner split line
+    fn middle_border(&self, row: usize) -> Border {
+        let count_rows = self.count_rows();
+        let middle = if row > 0 && row + 1 < count_rows {
+            row
+        } else {
+            1
+        };
 
-    let aligned_text = text
-        .into_iter()
-        .map(|line| style.alignment.align(&line, width));
+        if middle + 1 < count_rows {
+            self.border_styles[middle].clone()
+        } else {
+            self.border_styles[0].clone()
+        }
+    }
 
-    let aligned_text = aligned_text.map(|line| {
-        format!(
-            "{}{}{}",
-            " ".repeat(style.ident.left),
-            line,
-            " ".repeat(style.ident.right)
+    // it's a width of empty cells of a row
+    fn paddings_width(&self, row: usize) -> Vec<usize> {
+        (0..self.count_columns())
+            .map(|column| self.style(row, column).padding)
+            .map(|padding| padding.left + padding.right)
+            .collect()
+    }
+
+    // settings bound to rows are moved to the rows returned by `f` or dropped on `None`
+    fn shift_rows<F>(&mut self, f: F)
+    where
+        F: Fn(usize) -> Option<usize>,
+    {
+        let shift_entity = |entity| match entity {
+            Entity::Row(row) => f(row).map(Entity::Row),
+            Entity::Cell(row, column) => f(row).map(|row| Entity::Cell(row, column)),
+            entity => Some(entity),
+        };
+
+        shift_keys(&mut self.metadata, &f);
+        shift_keys(&mut self.details, &f);
+        shift_keys(&mut self.heights, &f);
+        shift_keys(&mut self.row_borders, &f);
+        shift_keys(&mut self.cell_borders, |(row, column)| {
+            f(row).map(|row| (row, column))
+        });
+        shift_keys(&mut self.spans, |(row, column)| {
+            f(row).map(|row| (row, column))
+        });
+        shift_keys(&mut self.styles, shift_entity);
+        shift_keys(&mut self.providers, shift_entity);
+    }
+
+    // settings bound to columns are moved to the columns returned by `f` or dropped on `None`
+    fn shift_columns<F>(&mut self, f: F)
+    where
+        F: Fn(usize) -> Option<usize>,
+    {
+        let shift_entity = |entity| match entity {
+            Entity::Column(column) => f(column).map(Entity::Column),
+            Entity::Cell(row, column) => f(column).map(|column| Entity::Cell(row, column)),
+            entity => Some(entity),
+        };
+
+        shift_keys(&mut self.widths, &f);
+        shift_keys(&mut self.column_borders, &f);
+        shift_keys(&mut self.row_spans, |(row, column)| {
+            f(column).map(|column| (row, column))
+        });
+        shift_keys(&mut self.cell_borders, |(row, column)| {
+            f(column).map(|column| (row, column))
+        });
+        shift_keys(&mut self.styles, shift_entity);
+        shift_keys(&mut self.providers, shift_entity);
+    }
+
+    // row spans are moved or resized by `f` which gets a start row and a span
+    fn shift_row_spans<F>(&mut self, f: F)
+    where
+        F: Fn(usize, usize) -> Option<(usize, usize)>,
+    {
+        self.row_spans = self
+            .row_spans
+            .drain()
+            .filter_map(|((r, c), n)| f(r, n).map(|(r, n)| ((r, c), n)))
+            .filter(|&(_, n)| n > 1)
+            .collect();
+    }
+
+    // column spans are moved or resized by `f` which gets a start column and a span
+    fn shift_column_spans<F>(&mut self, f: F)
+    where
+        F: Fn(usize, usize) -> Option<(usize, usize)>,
+    {
+        self.spans = self
+            .spans
+            .drain()
+            .filter_map(|((r, c), n)| f(c, n).map(|(c, n)| ((r, c), n)))
+            .filter(|&(_, n)| n > 1)
+            .collect();
+    }
+
+    // a span is a start column and a number of columns of a span which covers a cell
+    fn span_of(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        if self.spans.is_empty() {
+            return None;
+        }
+
+        (0..=column)
+            .rev()
+            .find_map(|c| self.spans.get(&(row, c)).map(|&n| (c, n)))
+            .filter(|&(c, n)| column < c + n)
+    }
+
+    fn is_covered_by_span(&self, row: usize, column: usize) -> bool {
+        matches!(self.span_of(row, column), Some((start, _)) if start != column)
+    }
+
+    // a row span is a start row and a number of rows of a span which covers a cell
+    fn row_span_of(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        if self.row_spans.is_empty() {
+            return None;
+        }
+
+        (0..=row)
+            .rev()
+            .find_map(|r| self.row_spans.get(&(r, column)).map(|&n| (r, n)))
+            .filter(|&(r, n)| row < r + n)
+    }
+
+    fn is_covered_by_row_span(&self, row: usize, column: usize) -> bool {
+        matches!(self.row_span_of(row, column), Some((start, _)) if start != row)
+    }
+
+    // a height of a spanned cell includes horizontal lines between its rows,
+    // `heights` are heights of the rows it spans over
+    fn row_span_height(&self, row: usize, heights: &[usize]) -> usize {
+        let lines = (row + 1..row + heights.len())
+            .filter(|&line| self.is_horizontal_line_visible(line))
+            .count();
+        heights.iter().sum::<usize>() + lines
+    }
+
+    // a width of a spanned cell includes vertical lines between its columns
+    fn span_width(
+        &self,
+        column: usize,
+        span: usize,
+        columns_width: &[usize],
+        vertical_lines: &[bool],
+    ) -> usize {
+        let lines = vertical_lines[column + 1..column + span]
+            .iter()
+            .filter(|v| **v)
+            .count();
+        columns_width[column..column + span].iter().sum::<usize>() + lines
+    }
+
+    // settings bound to horizontal lines are moved to the lines returned by `f` or dropped on `None`
+    fn shift_horizontal_lines<F>(&mut self, f: F)
+    where
+        F: Fn(usize) -> Option<usize>,
+    {
+        shift_keys(&mut self.horizontal_lines, &f);
+        shift_keys(&mut self.horizontal_line_texts, &f);
+    }
+
+    fn check_row(&self, row: usize) -> Result<(), Error> {
+        if row < self.count_rows() {
+            Ok(())
+        } else {
+            Err(Error::RowOutOfRange {
+                row,
+                count_rows: self.count_rows(),
+            })
+        }
+    }
+
+    fn check_column(&self, column: usize) -> Result<(), Error> {
+        if column < self.count_columns() {
+            Ok(())
+        } else {
+            Err(Error::ColumnOutOfRange {
+                column,
+                count_columns: self.count_columns(),
+            })
+        }
+    }
+
+    /// Estimate_dimensions returns widths of columns and heights of rows of a rendered grid,
+    /// including their padding.
+    ///
+    /// The result is cached on the grid and reused by the next renders
+    /// until a content or a setting which affects it is changed,
+    /// so a grid which is rendered over and over again, like a frame of a TUI, is measured only once.
+    /// A grid with providers is measured on every render as their content may change.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd").ident(1, 1, 0, 0));
+    ///     grid.set(Entity::Cell(1, 1), Settings::new().text("a\nb"));
+    ///     assert_eq!(grid.estimate_dimensions(), (vec![5, 5], vec![1, 2]));
+    /// ```
+    pub fn estimate_dimensions(&self) -> (Vec<usize>, Vec<usize>) {
+        if let Some(estimation) = self.cached_estimation() {
+            return estimation;
+        }
+
+        let contents = self.collect_contents();
+        self.estimate(&contents)
+    }
+
+    fn estimate(&self, contents: &[Vec<Cow<str>>]) -> (Vec<usize>, Vec<usize>) {
+        if let Some(estimation) = self.cached_estimation() {
+            return estimation;
+        }
+
+        let columns_width = self.columns_width(contents);
+        let rows_height = self.rows_height(contents, &columns_width);
+        if self.providers.is_empty() {
+            self.dimensions.lock().estimation = Some((columns_width.clone(), rows_height.clone()));
+        }
+
+        (columns_width, rows_height)
+    }
+
+    // a content of providers may change between renders so it's never taken from a cache
+    fn cached_estimation(&self) -> Option<(Vec<usize>, Vec<usize>)> {
+        if !self.providers.is_empty() {
+            return None;
+        }
+
+        self.dimensions.lock().estimation.clone()
+    }
+
+    /// Total_width returns a width of the widest line of a rendered grid,
+    /// including its borders, captions, a legend, a margin and an indent of a placement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Alignment, CaptionPosition, Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     assert_eq!(grid.total_width(), 9);
+    ///
+    ///     grid.set_caption(CaptionPosition::Top, "A wide caption", Alignment::Left);
+    ///     assert_eq!(grid.total_width(), 14);
+    /// ```
+    pub fn total_width(&self) -> usize {
+        if self.count_rows() == 0 || self.count_columns() == 0 {
+            return 0;
+        }
+
+        // a margin is put around the widest of the lines
+        let width = self.body_width() + self.margin.left + self.margin.right;
+
+        self.placement_indent(width) + width
+    }
+
+    /// Table_width returns a width of a grid itself, its borders and a margin,
+    /// without captions, a legend and a placement which are laid out around it.
+    ///
+    /// It's the width which changes when columns get narrower, see [`Grid::total_width`]
+    /// for a width of the whole rendered grid.
+    pub fn table_width(&self) -> usize {
+        if self.count_rows() == 0 || self.count_columns() == 0 {
+            return 0;
+        }
+
+        self.grid_width() + self.margin.left + self.margin.right
+    }
+
+    // a width of columns and vertical lines between and around them
+    fn grid_width(&self) -> usize {
+        let columns_width = self.estimate_dimensions().0.iter().sum::<usize>();
+        let vertical_lines = self.vertical_lines().iter().filter(|v| **v).count();
+
+        columns_width + vertical_lines
+    }
+
+    // a width of the widest line of a grid, its captions and a legend
+    fn body_width(&self) -> usize {
+        let captions = self.captions.values().flat_map(|(text, _)| {
+            let text = self.prepare_content(Cow::Borrowed(text));
+            text.lines().map(string_width).collect::<Vec<_>>()
+        });
+        let symbol_width = self.legend.iter().map(|(symbol, _)| string_width(symbol));
+        let symbol_width = symbol_width.max().unwrap_or(0);
+        let legend = self
+            .legend
+            .iter()
+            .map(|(_, meaning)| 2 + symbol_width + 1 + string_width(meaning))
+            .chain(self.legend.first().map(|_| "Legend:".len()));
+
+        captions.chain(legend).fold(self.grid_width(), usize::max)
+    }
+
+    // an indent which places a grid of a given width within a width of a placement
+    fn placement_indent(&self, width: usize) -> usize {
+        match &self.placement {
+            Some((alignment, placement)) => {
+                let diff = placement.saturating_sub(width);
+                match alignment {
+                    Alignment::Left => 0,
+                    Alignment::Center => diff / 2,
+                    Alignment::Right => diff,
+                }
+            }
+            None => 0,
+        }
+    }
+
+    /// Total_height returns a number of lines of a rendered grid,
+    /// including its borders, details, captions, a legend and a margin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(2, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a\nb"));
+    ///     assert_eq!(grid.total_height(), 6);
+    /// ```
+    pub fn total_height(&self) -> usize {
+        if self.count_rows() == 0 || self.count_columns() == 0 {
+            return 0;
+        }
+
+        let contents = self.collect_contents();
+        let (columns_width, rows_height) = self.estimate(&contents);
+        let vertical_lines = self.vertical_lines();
+
+        let horizontal_lines = (0..=self.count_rows())
+            .filter(|&line| self.is_horizontal_line_visible(line))
+            .count();
+        let details = (0..self.count_rows())
+            .filter(|_| self.show_details)
+            .filter_map(|row| self.details.get(&row).map(|detail| (row, detail)))
+            .map(|(row, detail)| {
+                let detail = self.prepare_content(Cow::Borrowed(detail));
+                let width = self.detail_width(row, &columns_width, &vertical_lines);
+                textwrap::wrap(&detail, width.max(1)).len()
+            })
+            .sum::<usize>();
+        let captions = self
+            .captions
+            .values()
+            .map(|(text, _)| self.prepare_content(Cow::Borrowed(text)).lines().count())
+            .sum::<usize>();
+        let legend = if self.legend.is_empty() {
+            0
+        } else {
+            self.legend.len() + 1
+        };
+
+        rows_height.iter().sum::<usize>()
+            + horizontal_lines
+            + details
+            + captions
+            + legend
+            + self.margin.top
+            + self.margin.bottom
+    }
+
+    /// Write_to renders a grid straight into a writer, like a standard output or a file,
+    /// so a big grid doesn't have to be collected into a `String` first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///
+    ///     let mut output = Vec::new();
+    ///     grid.write_to(&mut output).unwrap();
+    ///     assert_eq!(output, b"+---+\n|asd|\n+---+\n");
+    /// ```
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Write_fmt_to is the same as [`Grid::write_to`] but for a [`fmt::Write`] writer.
+    pub fn write_fmt_to<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        write!(writer, "{}", self)
+    }
+
+    /// Rendered_len returns an exact length in bytes of a rendered grid.
+    ///
+    /// The length is computed from widths and heights of cells, borders and paddings
+    /// without rendering a grid into a string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     assert_eq!(grid.rendered_len(), grid.to_string().len());
+    /// ```
+    pub fn rendered_len(&self) -> usize {
+        // It may happen when all cells removed via `remove_row`, `remove_column` methods
+        if self.count_rows() == 0 || self.count_columns() == 0 {
+            return 0;
+        }
+
+        let rows = (0..self.count_rows()).collect::<Vec<_>>();
+        let mut counter = LineCounter::default();
+        // the counter never fails
+        let _ = self.build_grid(&mut counter, &rows);
+
+        let width = self.body_width();
+        let fill = self.margin.fill.len_utf8();
+        let mut length = 0;
+        for (bytes, line_width) in &counter.lines {
+            length += bytes;
+            if !self.margin.is_empty() {
+                length += (self.margin.left + self.margin.right) * fill
+                    + width.saturating_sub(*line_width);
+            }
+        }
+
+        let mut count_lines = counter.lines.len();
+        if !self.margin.is_empty() {
+            let count_fill_lines = self.margin.top + self.margin.bottom;
+            length += count_fill_lines * (self.margin.left + width + self.margin.right) * fill;
+            count_lines += count_fill_lines;
+        }
+
+        let line_ending = match self.line_ending {
+            LineEnding::Lf => 1,
+            LineEnding::CrLf => 2,
+        };
+        let indent = self.placement_indent(width + self.margin.left + self.margin.right);
+        length += count_lines * (indent + line_ending);
+
+        if !self.trailing_newline && count_lines > 0 {
+            length -= line_ending;
+        }
+
+        length
+    }
+
+    /// Render_into appends a rendered grid to a buffer.
+    ///
+    /// The buffer reserves exactly [`Grid::rendered_len`] bytes up front,
+    /// so a grid is rendered without reallocations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///
+    ///     let mut buf = String::from("Table:\n");
+    ///     grid.render_into(&mut buf);
+    ///     assert_eq!(buf, "Table:\n+---+\n|asd|\n+---+\n");
+    /// ```
+    pub fn render_into(&self, buf: &mut String) {
+        buf.reserve(self.rendered_len());
+        // writing to a string never fails
+        let _ = self.write_fmt_to(buf);
+    }
+
+    /// Render_rows renders only given rows of a grid in a given order.
+    ///
+    /// Widths of columns are measured over a whole grid,
+    /// so grids rendered from different rows line up with each other.
+    /// The last rendered row is closed by a bottom line of the grid.
+    /// Rows which are out of range are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(3, 1);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+    ///     grid.set(Entity::Cell(1, 0), Settings::new().text("asd"));
+    ///     grid.set(Entity::Cell(2, 0), Settings::new().text("b"));
+    ///     assert_eq!(grid.render_rows(&[0, 2]), "+---+\n|a  |\n+---+\n|b  |\n+---+\n");
+    /// ```
+    pub fn render_rows(&self, rows: &[usize]) -> String {
+        let rows = rows
+            .iter()
+            .copied()
+            .filter(|&row| row < self.count_rows())
+            .collect::<Vec<_>>();
+
+        GridRows(self, &rows).to_string()
+    }
+
+    /// Appender fixes widths of columns as they're measured now,
+    /// so rows appended to the grid later can be rendered one by one by [`Appender::render`].
+    ///
+    /// See [`Appender`].
+    pub fn appender(&self) -> Appender {
+        Appender {
+            columns_width: self.estimate_dimensions().0,
+        }
+    }
+
+    /// Render_with_limit renders a grid but stops as soon as the output exceeds `max_bytes`.
+    ///
+    /// In such case the output is cut at the last complete line
+    /// and a truncation notice is appended to it.
+    /// The notice is counted in `max_bytes` as well so the output never exceeds it,
+    /// if even the notice doesn't fit an empty string is returned.
+    /// It protects logs from a table which accidentally turned out to be gigantic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(10, 1);
+    ///     grid.set(Entity::Global, Settings::new().text("asd"));
+    ///     assert_eq!(
+    ///          grid.render_with_limit(70),
+    ///          "+---+\n\
+    ///           |asd|\n\
+    ///           +---+\n\
+    ///           ... output is truncated as it exceeds 70 bytes\n"
+    ///     )
+    /// ```
+    pub fn render_with_limit(&self, max_bytes: usize) -> String {
+        let mut writer = LimitedWriter {
+            buf: String::new(),
+            limit: max_bytes,
+        };
+
+        if self.write_fmt_to(&mut writer).is_ok() {
+            return writer.buf;
+        }
+
+        let notice = self.with_line_ending(format!(
+            "... output is truncated as it exceeds {} bytes\n",
+            max_bytes
+        ));
+        if notice.len() > max_bytes {
+            return String::new();
+        }
+
+        let mut buf = writer.buf;
+        let room = max_bytes - notice.len();
+        let complete_lines = buf.as_bytes()[..room.min(buf.len())]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        buf.truncate(complete_lines);
+        buf.push_str(&notice);
+
+        buf
+    }
+
+    // forced sizes are checked against the padding once again as it could be changed after they were set
+    fn columns_width(&self, contents: &[Vec<Cow<str>>]) -> Vec<usize> {
+        let mut widths = match &self.estimator {
+            Some(estimator) => self.estimated_columns_width(estimator.as_ref(), contents),
+            None => {
+                let mut widths = self.measured_columns_width(contents);
+                self.distribute_spans(contents, &mut widths);
+                widths
+            }
+        };
+        for (&column, &width) in &self.widths {
+            widths[column] = max(width, self.min_column_width(column));
+        }
+
+        widths
+    }
+
+    fn rows_height(&self, contents: &[Vec<Cow<str>>], columns_width: &[usize]) -> Vec<usize> {
+        let mut heights = self.measured_rows_height(contents);
+
+        // a content of columns with a forced or an estimated width may be wrapped so it takes more lines
+        let wrapped_columns = match self.estimator {
+            Some(_) => (0..self.count_columns()).collect(),
+            None => self.widths.keys().copied().collect::<Vec<_>>(),
+        };
+        for column in wrapped_columns {
+            for (row, height) in heights.iter_mut().enumerate() {
+                if self.span_of(row, column).is_some() || self.row_span_of(row, column).is_some() {
+                    continue;
+                }
+
+                let style = self.style(row, column);
+                let width = columns_width[column] - style.padding.left - style.padding.right;
+                let lines = textwrap::wrap(&contents[row][column], width).len();
+                *height = max(*height, lines + style.padding.top + style.padding.bottom);
+            }
+        }
+
+        // a content of spanned cells is wrapped by a width of all their columns
+        let vertical_lines = self.vertical_lines();
+        for (&(row, column), &span) in &self.spans {
+            let style = self.style(row, column);
+            let width = self.span_width(column, span, columns_width, &vertical_lines)
+                - style.padding.left
+                - style.padding.right;
+            let lines = textwrap::wrap(&contents[row][column], width).len();
+            heights[row] = max(
+                heights[row],
+                lines + style.padding.top + style.padding.bottom,
+            );
+        }
+
+        // the last row of a span is made higher if a content of a spanned cell doesn't fit
+        let mut row_spans = self.row_spans.iter().collect::<Vec<_>>();
+        row_spans.sort_by_key(|(&(row, column), &span)| (span, row, column));
+        for (&(row, column), &span) in row_spans {
+            let style = self.style(row, column);
+            let width = columns_width[column] - style.padding.left - style.padding.right;
+            let lines = textwrap::wrap(&contents[row][column], width).len();
+            let needed = lines + style.padding.top + style.padding.bottom;
+            let available = self.row_span_height(row, &heights[row..row + span]);
+            if needed > available {
+                heights[row + span - 1] += needed - available;
+            }
+        }
+
+        for (&row, &height) in &self.heights {
+            heights[row] = max(height, self.min_row_height(row));
+        }
+
+        heights
+    }
+
+    // columns are widened when a content of a spanned cell doesn't fit into them,
+    // shorter spans go first as wider ones may already fit after that
+    fn distribute_spans(&self, contents: &[Vec<Cow<str>>], widths: &mut [usize]) {
+        if self.spans.is_empty() {
+            return;
+        }
+
+        let mut spans = self.spans.iter().collect::<Vec<_>>();
+        spans.sort_by_key(|(&(row, column), &span)| (span, row, column));
+
+        let vertical_lines = self.vertical_lines();
+        for (&(row, column), &span) in spans {
+            let needed = self.cell_width(contents, row, column);
+            let available = self.span_width(column, span, widths, &vertical_lines);
+            if needed > available {
+                let columns = &mut widths[column..column + span];
+                self.span_distribution
+                    .distribute(columns, needed - available);
+            }
+        }
+    }
+
+    fn min_column_width(&self, column: usize) -> usize {
+        (0..self.count_rows())
+            .map(|row| self.style(row, column).padding)
+            .map(|padding| padding.left + padding.right + 1)
+            .max()
+            .unwrap_or(1)
+    }
+
+    fn min_row_height(&self, row: usize) -> usize {
+        (0..self.count_columns())
+            .map(|column| self.style(row, column).padding)
+            .map(|padding| padding.top + padding.bottom + 1)
+            .max()
+            .unwrap_or(1)
+    }
+
+    fn estimated_columns_width(
+        &self,
+        estimator: &dyn Estimator,
+        contents: &[Vec<Cow<str>>],
+    ) -> Vec<usize> {
+        (0..self.count_columns())
+            .map(|column| {
+                let column_ref = ColumnRef {
+                    grid: self,
+                    contents,
+                    column,
+                };
+                match estimator.column_width(&column_ref) {
+                    Some(width) => max(width, self.min_column_width(column)),
+                    None => self.column_width(contents, column),
+                }
+            })
+            .collect()
+    }
+
+    fn measured_columns_width(&self, contents: &[Vec<Cow<str>>]) -> Vec<usize> {
+        // a content of providers may change from render to render so it can't be cached
+        if !self.providers.is_empty() {
+            let columns = (0..self.count_columns()).collect::<Vec<_>>();
+            return self.columns_width_of(contents, &columns);
+        }
+
+        let mut dimensions = self.dimensions.lock();
+        let missing = (0..self.count_columns())
+            .filter(|&column| dimensions.widths[column].is_none())
+            .collect::<Vec<_>>();
+        let measured = self.columns_width_of(contents, &missing);
+        for (&column, width) in missing.iter().zip(measured) {
+            dimensions.widths[column] = Some(width);
+        }
+
+        dimensions.widths.iter().flatten().copied().collect()
+    }
+
+    fn measured_rows_height(&self, contents: &[Vec<Cow<str>>]) -> Vec<usize> {
+        if !self.providers.is_empty() {
+            let rows = (0..self.count_rows()).collect::<Vec<_>>();
+            return self.rows_height_of(contents, &rows);
+        }
+
+        let mut dimensions = self.dimensions.lock();
+        let missing = (0..self.count_rows())
+            .filter(|&row| dimensions.heights[row].is_none())
+            .collect::<Vec<_>>();
+        let measured = self.rows_height_of(contents, &missing);
+        for (&row, height) in missing.iter().zip(measured) {
+            dimensions.heights[row] = Some(height);
+        }
+
+        dimensions.heights.iter().flatten().copied().collect()
+    }
+
+    // providers are called only once per render
+    fn collect_contents(&self) -> Vec<Vec<Cow<'_, str>>> {
+        (0..self.count_rows())
+            .map(|row| {
+                (0..self.count_columns())
+                    .map(|column| self.cell_content(row, column))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn set_text(&mut self, entity: &Entity, text: Cow<'static, str>) {
+        let text = Text::from(text);
+        match *entity {
+            // a width of a spanned cell is not a part of a cached width of a column
+            Entity::Cell(row, column)
+                if self.span_of(row, column).is_some()
+                    || self.row_span_of(row, column).is_some() =>
+            {
+                self.dimensions.lock().invalidate(entity);
+                self.cells[row][column] = text;
+            }
+            Entity::Cell(row, column) => {
+                let old = self.cell_size(row, column, self.cells[row][column].as_ref());
+                let new = self.cell_size(row, column, text.as_ref());
+                self.dimensions.lock().update_cell(row, column, old, new);
+
+                self.cells[row][column] = text;
+            }
+            Entity::Column(column) => {
+                for row in 0..self.count_rows() {
+                    self.cells[row][column] = text.clone();
+                }
+                self.dimensions.lock().invalidate(entity);
+            }
+            Entity::Row(row) => {
+                for column in 0..self.count_columns() {
+                    self.cells[row][column] = text.clone();
+                }
+                self.dimensions.lock().invalidate(entity);
+            }
+            Entity::Global => {
+                for row in 0..self.count_rows() {
+                    for column in 0..self.count_columns() {
+                        self.cells[row][column] = text.clone();
+                    }
+                }
+                self.dimensions.lock().invalidate(entity);
+            }
+        }
+    }
+
+    fn cell_size(&self, row: usize, column: usize, text: &str) -> (usize, usize) {
+        let padding = self.style(row, column).padding;
+        let text = self.prepare_content(Cow::Borrowed(text));
+        let width = string_width(&text) + padding.left + padding.right;
+        let height = text.lines().count() + padding.top + padding.bottom;
+        (width, height)
+    }
+
+    // the function suppose you provide a correct column index
+    // texts are measured first as it's the most expensive part, it's done in parallel with a `rayon` feature
+    fn rows_height_of(&self, contents: &[Vec<Cow<str>>], rows: &[usize]) -> Vec<usize> {
+        let lines = measure(rows, |&row| {
+            contents[row]
+                .iter()
+                .map(|text| text.lines().count())
+                .collect::<Vec<_>>()
+        });
+
+        rows.iter()
+            .zip(lines)
+            .map(|(&row, lines)| {
+                let mut height = 0;
+                for (column, lines) in lines.into_iter().enumerate() {
+                    if self.is_covered_by_span(row, column)
+                        || self.row_span_of(row, column).is_some()
+                    {
+                        continue;
+                    }
+
+                    let style = self.style(row, column);
+                    height = max(height, lines + style.padding.top + style.padding.bottom);
+                }
+
+                // a row is rendered at least in one line even if all its cells are spanned
+                max(height, 1)
+            })
+            .collect()
+    }
+
+    // the function suppose you provide correct column indexes
+    fn columns_width_of(&self, contents: &[Vec<Cow<str>>], columns: &[usize]) -> Vec<usize> {
+        if columns.is_empty() {
+            return Vec::new();
+        }
+
+        let widths = measure(contents, |cells| {
+            columns
+                .iter()
+                .map(|&column| string_width(&cells[column]))
+                .collect::<Vec<_>>()
+        });
+
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, &column)| {
+                widths
+                    .iter()
+                    .enumerate()
+                    .filter(|&(row, _)| {
+                        self.span_of(row, column).is_none()
+                            && !self.is_covered_by_row_span(row, column)
+                    })
+                    .map(|(row, widths)| {
+                        let style = self.style(row, column);
+                        widths[i] + style.padding.left + style.padding.right
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    fn column_width(&self, contents: &[Vec<Cow<str>>], column: usize) -> usize {
+        self.columns_width_of(contents, &[column])[0]
+    }
+
+    fn cell_width(&self, contents: &[Vec<Cow<str>>], row: usize, column: usize) -> usize {
+        let style = self.style(row, column);
+        string_width(&contents[row][column]) + style.padding.left + style.padding.right
+    }
+
+    fn cell_content(&self, row: usize, column: usize) -> Cow<'_, str> {
+        self.prepare_content(self.raw_content(row, column))
+    }
+
+    // a content is sanitized before tabs are expanded as they are not considered unsafe
+    fn prepare_content<'a>(&self, text: Cow<'a, str>) -> Cow<'a, str> {
+        let text = self.sanitization.apply(text);
+        if self.tab_width == 0 || !text.contains('\t') {
+            return text;
+        }
+
+        Cow::Owned(expand_tabs(&text, self.tab_width))
+    }
+
+    // a content of a cell before sanitization
+    fn raw_content(&self, row: usize, column: usize) -> Cow<'_, str> {
+        let providers = [
+            self.providers.get(&Entity::Cell(row, column)),
+            self.providers.get(&Entity::Column(column)),
+            self.providers.get(&Entity::Row(row)),
+            self.providers.get(&Entity::Global),
+        ];
+
+        match providers.iter().flatten().next() {
+            Some(provider) => Cow::Owned(provider(row, column)),
+            None => Cow::Borrowed(self.cells[row][column].as_ref()),
+        }
+    }
+
+    // layers are applied from the least specific entity to the most specific one,
+    // so each property is taken from the most specific entity which has it set
+    fn style(&self, row: usize, column: usize) -> Style {
+        let layers = [
+            Entity::Global,
+            Entity::Row(row),
+            Entity::Column(column),
+            Entity::Cell(row, column),
+        ];
+
+        let mut style = Style::default();
+        for layer in layers.iter().filter_map(|entity| self.styles.get(entity)) {
+            if let Some(padding) = &layer.padding {
+                style.padding = padding.clone();
+            }
+            if let Some(alignment) = &layer.alignment {
+                style.alignment = alignment.clone();
+            }
+            if let Some(alignment) = &layer.vertical_alignment {
+                style.vertical_alignment = alignment.clone();
+            }
+        }
+
+        style
+    }
+
+    fn default_border() -> Border {
+        Border {
+            inner: LineStyle {
+                main: Some('-'),
+                intersection: Some('|'),
+                left_intersection: Some('|'),
+                right_intersection: Some('|'),
+            },
+            bottom_line: LineStyle {
+                main: Some('-'),
+                intersection: Some('+'),
+                left_intersection: Some('+'),
+                right_intersection: Some('+'),
+            },
+            top_line: LineStyle {
+                main: Some('-'),
+                intersection: Some('+'),
+                left_intersection: Some('+'),
+                right_intersection: Some('+'),
+            },
+        }
+    }
+
+    // a row is written line by line, every line takes a part of each cell
+    // cells which span over rows are rendered line by line through the rows and lines they cover,
+    // they are kept by their columns until the row where they end
+    // `rows_height` starts from a `first_row`,
+    // a span which goes beyond them is cut as rows after them are not measured
+    fn start_row_spans<'a>(
+        &self,
+        index: usize,
+        contents: &'a [Cow<str>],
+        columns_width: &[usize],
+        rows_height: (usize, &[usize]),
+        row_spans: &mut HashMap<usize, SpannedCell<'a>>,
+    ) {
+        let (first_row, rows_height) = rows_height;
+        row_spans.retain(|_, span| span.end > index);
+        for (&(row, column), &span) in &self.row_spans {
+            if row == index {
+                let heights = &rows_height[row - first_row..];
+                let height = self.row_span_height(row, &heights[..span.min(heights.len())]);
+                let style = self.style(row, column);
+                let cell = Cell::new(&contents[column], style, columns_width[column], height);
+                let cell = SpannedCell {
+                    cell,
+                    next_line: 0,
+                    end: row + span,
+                };
+                row_spans.insert(column, cell);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_row<W: Sink>(
+        &self,
+        f: &mut W,
+        index: usize,
+        contents: &[Cow<str>],
+        columns_width: &[usize],
+        height: usize,
+        vertical_lines: &[bool],
+        row_spans: &mut HashMap<usize, SpannedCell<'_>>,
+    ) -> fmt::Result {
+        let borders = (0..=self.count_columns())
+            .map(|line| self.vertical_char(index, line))
+            .collect::<Vec<_>>();
+
+        // cells covered by a span are not rendered as well as vertical lines inside of it
+        let cells = contents
+            .iter()
+            .enumerate()
+            .map(|(column, text)| match self.span_of(index, column) {
+                _ if row_spans.contains_key(&column) => None,
+                Some((start, _)) if start != column => None,
+                Some((_, span)) => {
+                    let width = self.span_width(column, span, columns_width, vertical_lines);
+                    Some(Cell::new(text, self.style(index, column), width, height))
+                }
+                None => {
+                    let width = columns_width[column];
+                    Some(Cell::new(text, self.style(index, column), width, height))
+                }
+            })
+            .collect::<Vec<_>>();
+        let height = cells
+            .iter()
+            .flatten()
+            .map(|cell| cell.height)
+            .max()
+            .unwrap_or(height);
+
+        for i in 0..height {
+            for (line, is_visible) in vertical_lines.iter().enumerate() {
+                if *is_visible && !self.is_covered_by_span(index, line) {
+                    f.put_char(borders[line].unwrap_or(' '), 1)?;
+                }
+
+                if let Some(Some(cell)) = cells.get(line) {
+                    cell.write_line(f, i)?;
+                } else if let Some(span) = row_spans.get(&line) {
+                    span.cell.write_line(f, span.next_line + i)?;
+                }
+            }
+
+            f.put_str("\n")?;
+        }
+
+        for span in row_spans.values_mut() {
+            span.next_line += height;
+        }
+
+        Ok(())
+    }
+
+    // a detail goes across all columns so only outer vertical lines are rendered
+    fn build_detail<W: Sink>(
+        &self,
+        f: &mut W,
+        index: usize,
+        columns_width: &[usize],
+        vertical_lines: &[bool],
+    ) -> fmt::Result {
+        let detail = match self.details.get(&index) {
+            Some(detail) if self.show_details => self.prepare_content(Cow::Borrowed(detail)),
+            _ => return Ok(()),
+        };
+
+        let count_columns = self.count_columns();
+        let left = self.style(index, 0).padding.left;
+        let right = self.style(index, count_columns - 1).padding.right;
+        let text_width = self.detail_width(index, columns_width, vertical_lines);
+
+        let left_border = self.vertical_char(index, 0).unwrap_or(' ');
+        let right_border = self.vertical_char(index, count_columns).unwrap_or(' ');
+        for line in textwrap::wrap(&detail, text_width.max(1)) {
+            if vertical_lines[0] {
+                f.put_char(left_border, 1)?;
+            }
+
+            // a line can be wider only when there's no space for a detail at all
+            f.put_char(' ', left)?;
+            Alignment::Left.write_aligned(f, &line, text_width)?;
+            f.put_char(' ', right)?;
+
+            if vertical_lines[count_columns] {
+                f.put_char(right_border, 1)?;
+            }
+
+            f.put_str("\n")?;
+        }
+
+        Ok(())
+    }
+
+    // a width of a detail text is a width of the grid inside its outer lines and a padding
+    fn detail_width(
+        &self,
+        index: usize,
+        columns_width: &[usize],
+        vertical_lines: &[bool],
+    ) -> usize {
+        let count_columns = self.count_columns();
+        let inner_lines = vertical_lines[1..count_columns]
+            .iter()
+            .filter(|is_visible| **is_visible)
+            .count();
+        let width = columns_width.iter().sum::<usize>() + inner_lines;
+        let left = self.style(index, 0).padding.left;
+        let right = self.style(index, count_columns - 1).padding.right;
+
+        width.saturating_sub(left + right)
+    }
+
+    // a grid is rendered line by line through the layers around it,
+    // so nothing is built as a whole before it's written
+    fn render<W: fmt::Write>(&self, f: &mut W, rows: &[usize]) -> fmt::Result {
+        if self.line_ending == LineEnding::Lf && self.trailing_newline {
+            return self.render_placed(f, rows);
+        }
+
+        let line_ending = match self.line_ending {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        };
+
+        // a line ending is written before a next line so the last one can be left out
+        let mut is_empty = true;
+        let mut lines = LinesWriter::new(f, |f: &mut W, line: &str| {
+            if !is_empty {
+                f.write_str(line_ending)?;
+            }
+
+            is_empty = false;
+            f.write_str(line)
+        });
+        self.render_placed(&mut lines, rows)?;
+
+        if self.trailing_newline && !is_empty {
+            f.write_str(line_ending)?;
+        }
+
+        Ok(())
+    }
+
+    fn render_placed<W: fmt::Write>(&self, f: &mut W, rows: &[usize]) -> fmt::Result {
+        // It may happen when all cells removed via `remove_row`, `remove_column` methods
+        if rows.is_empty() || self.count_columns() == 0 {
+            return Ok(());
+        }
+
+        if self.placement.is_none() {
+            return self.render_with_margin(f, rows);
+        }
+
+        let indent =
+            self.placement_indent(self.body_width() + self.margin.left + self.margin.right);
+        let mut lines = LinesWriter::new(f, |f: &mut W, line: &str| {
+            f.put_char(' ', indent)?;
+            writeln!(f, "{}", line)
+        });
+
+        self.render_with_margin(&mut lines, rows)
+    }
+
+    fn render_with_margin<W: fmt::Write>(&self, f: &mut W, rows: &[usize]) -> fmt::Result {
+        if self.margin.is_empty() {
+            return self.build_grid(f, rows);
+        }
+
+        // lines of a legend may be narrower than a table so all lines are filled up to the widest one
+        let width = self.body_width();
+        let fill = |width: usize| self.margin.fill.to_string().repeat(width);
+
+        for _ in 0..self.margin.top {
+            writeln!(f, "{}", fill(self.margin.left + width + self.margin.right))?;
+        }
+
+        let mut lines = LinesWriter::new(&mut *f, |f: &mut W, line: &str| {
+            writeln!(
+                f,
+                "{}{}{}{}",
+                fill(self.margin.left),
+                line,
+                " ".repeat(width.saturating_sub(string_width(line))),
+                fill(self.margin.right)
+            )
+        });
+        self.build_grid(&mut lines, rows)?;
+
+        for _ in 0..self.margin.bottom {
+            writeln!(f, "{}", fill(self.margin.left + width + self.margin.right))?;
+        }
+
+        Ok(())
+    }
+
+    fn build_grid<W: Sink>(&self, f: &mut W, rows: &[usize]) -> fmt::Result {
+        let contents = self.collect_contents();
+        let (columns_width, rows_height) = self.estimate(&contents);
+        let vertical_lines = self.vertical_lines();
+        let mut row_spans = HashMap::new();
+
+        self.build_caption(f, CaptionPosition::Top, &columns_width, &vertical_lines)?;
+
+        for (n, &i) in rows.iter().enumerate() {
+            if n == 0 {
+                self.build_line(f, 0, &columns_width, &vertical_lines, &mut row_spans)?;
+            }
+
+            self.start_row_spans(
+                i,
+                &contents[i],
+                &columns_width,
+                (0, &rows_height),
+                &mut row_spans,
+            );
+            self.build_row(
+                f,
+                i,
+                &contents[i],
+                &columns_width,
+                rows_height[i],
+                &vertical_lines,
+                &mut row_spans,
+            )?;
+            self.build_detail(f, i, &columns_width, &vertical_lines)?;
+
+            // when rows are skipped a span isn't continued and the last row is closed by a bottom line
+            let line = match rows.get(n + 1) {
+                Some(&next) if next == i + 1 => i + 1,
+                next => {
+                    row_spans.clear();
+                    if next.is_some() {
+                        i + 1
+                    } else {
+                        self.count_rows()
+                    }
+                }
+            };
+            self.build_line(f, line, &columns_width, &vertical_lines, &mut row_spans)?;
+        }
+
+        self.build_caption(f, CaptionPosition::Bottom, &columns_width, &vertical_lines)?;
+        self.build_legend(f)
+    }
+
+    // rows are preceded by lines above them, a line under the last row is rendered only when no row is left,
+    // only the rendered rows are measured as widths of columns are fixed
+    fn build_appended<W: Sink>(
+        &self,
+        f: &mut W,
+        from: usize,
+        columns_width: &[usize],
+    ) -> fmt::Result {
+        let vertical_lines = self.vertical_lines();
+        let mut row_spans = HashMap::new();
+
+        if from == self.count_rows() {
+            return self.build_line(f, from, columns_width, &vertical_lines, &mut row_spans);
+        }
+
+        let contents = (from..self.count_rows())
+            .map(|row| {
+                (0..self.count_columns())
+                    .map(|column| self.cell_content(row, column))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let rows_height = contents
+            .iter()
+            .enumerate()
+            .map(|(i, contents)| {
+                self.appended_row_height(from + i, contents, columns_width, &vertical_lines)
+            })
+            .collect::<Vec<_>>();
+
+        for (i, contents) in contents.iter().enumerate() {
+            let row = from + i;
+            self.build_line(f, row, columns_width, &vertical_lines, &mut row_spans)?;
+            self.start_row_spans(
+                row,
+                contents,
+                columns_width,
+                (from, &rows_height),
+                &mut row_spans,
+            );
+            self.build_row(
+                f,
+                row,
+                contents,
+                columns_width,
+                rows_height[i],
+                &vertical_lines,
+                &mut row_spans,
+            )?;
+            self.build_detail(f, row, columns_width, &vertical_lines)?;
+        }
+
+        Ok(())
+    }
+
+    // a content is wrapped by fixed widths of columns, like in case of an estimator
+    fn appended_row_height(
+        &self,
+        row: usize,
+        contents: &[Cow<str>],
+        columns_width: &[usize],
+        vertical_lines: &[bool],
+    ) -> usize {
+        if self.heights.contains_key(&row) {
+            return max(self.heights[&row], self.min_row_height(row));
+        }
+
+        (0..self.count_columns())
+            .filter(|&column| {
+                !self.is_covered_by_span(row, column) && self.row_span_of(row, column).is_none()
+            })
+            .map(|column| {
+                let style = self.style(row, column);
+                let span = self.get_column_span(row, column);
+                let width = self.span_width(column, span, columns_width, vertical_lines)
+                    - style.padding.left
+                    - style.padding.right;
+                let lines = textwrap::wrap(&contents[column], width).len();
+                lines + style.padding.top + style.padding.bottom
+            })
+            .fold(1, max)
+    }
+
+    fn with_line_ending(&self, text: String) -> String {
+        match self.line_ending {
+            LineEnding::Lf => text,
+            LineEnding::CrLf => text.replace('\n', "\r\n"),
+        }
+    }
+
+    fn build_caption<W: Sink>(
+        &self,
+        f: &mut W,
+        position: CaptionPosition,
+        columns_width: &[usize],
+        vertical_lines: &[bool],
+    ) -> fmt::Result {
+        let (text, alignment) = match self.captions.get(&position) {
+            Some(caption) => caption,
+            None => return Ok(()),
+        };
+
+        let text = self.prepare_content(Cow::Borrowed(text));
+        let width = columns_width.iter().sum::<usize>()
+            + vertical_lines
+                .iter()
+                .filter(|is_visible| **is_visible)
+                .count();
+        for line in text.lines() {
+            alignment.write_aligned(f, line, width)?;
+            f.put_str("\n")?;
+        }
+
+        Ok(())
+    }
+
+    fn build_legend<W: Sink>(&self, f: &mut W) -> fmt::Result {
+        if self.legend.is_empty() {
+            return Ok(());
+        }
+
+        let symbol_width = self
+            .legend
+            .iter()
+            .map(|(symbol, _)| string_width(symbol))
+            .max()
+            .unwrap_or(0);
+
+        f.put_str("Legend:\n")?;
+        for (symbol, meaning) in &self.legend {
+            f.put_str("  ")?;
+            f.put_str(symbol)?;
+            f.put_char(' ', symbol_width - string_width(symbol) + 1)?;
+            f.put_str(meaning)?;
+            f.put_str("\n")?;
+        }
+
+        Ok(())
+    }
+
+    // `line` is an index of a horizontal line which goes above a row with the same index
+    fn build_line<W: Sink>(
+        &self,
+        f: &mut W,
+        line: usize,
+        columns_width: &[usize],
+        vertical_lines: &[bool],
+        row_spans: &mut HashMap<usize, SpannedCell<'_>>,
+    ) -> fmt::Result {
+        if !self.is_horizontal_line_visible(line) {
+            return Ok(());
+        }
+
+        let text = match self.horizontal_line_texts.get(&line) {
+            Some(text) => self.prepare_content(Cow::Borrowed(text)),
+            None => {
+                self.write_line_chars(f, line, columns_width, vertical_lines, row_spans)?;
+                return f.put_str("\n");
+            }
+        };
+
+        // a text is put over the characters of the line so they are collected first
+        let mut chars = String::new();
+        self.write_line_chars(&mut chars, line, columns_width, vertical_lines, row_spans)?;
+        let chars = chars.chars().collect::<Vec<_>>();
+        let start = if vertical_lines[0] { 1 } else { 0 };
+        let end = chars.len()
+            - if vertical_lines[self.count_columns()] {
+                1
+            } else {
+                0
+            };
+
+        let chars = overlay_text(&chars, &text, start, end);
+        f.put_str(&chars.into_iter().collect::<String>())?;
+        f.put_str("\n")
+    }
+
+    fn write_line_chars<W: Sink>(
+        &self,
+        f: &mut W,
+        line: usize,
+        columns_width: &[usize],
+        vertical_lines: &[bool],
+        row_spans: &mut HashMap<usize, SpannedCell<'_>>,
+    ) -> fmt::Result {
+        // a line is interrupted by cells which span over rows above and below it
+        let is_row_spanned =
+            |column: usize| matches!(row_spans.get(&column), Some(span) if span.end > line);
+        let row_spanned = (0..self.count_columns())
+            .map(is_row_spanned)
+            .collect::<Vec<_>>();
+
+        for (column, is_visible) in vertical_lines.iter().enumerate() {
+            let left = column > 0 && row_spanned[column - 1];
+            let right = column < self.count_columns() && row_spanned[column];
+            let is_edge = column == 0 || column == self.count_columns();
+
+            // a vertical line which is inside of spans above and below the line is not crossed
+            let is_inside_span =
+                |row: usize| row >= self.count_rows() || self.is_covered_by_span(row, column);
+            if *is_visible && ((left && right) || (is_edge && (left || right))) {
+                f.put_char(self.vertical_char(line - 1, column).unwrap_or(' '), 1)?;
+            } else if *is_visible && (left || right) {
+                // a line starts or ends at a spanned cell like at an edge of a grid
+                let style = self.horizontal_line(line);
+                let c = if left {
+                    style.left_intersection
+                } else {
+                    style.right_intersection
+                };
+                f.put_char(
+                    c.or_else(|| self.intersection_char(line, column))
+                        .unwrap_or(' '),
+                    1,
+                )?;
+            } else if *is_visible && is_inside_span(line) && is_inside_span(line.wrapping_sub(1)) {
+                f.put_char(self.horizontal_char(line, column - 1).unwrap_or(' '), 1)?;
+            } else if *is_visible {
+                f.put_char(self.intersection_char(line, column).unwrap_or(' '), 1)?;
+            }
+
+            match (columns_width.get(column), row_spans.get_mut(&column)) {
+                (Some(_), Some(span)) if right => {
+                    span.cell.write_line(f, span.next_line)?;
+                    span.next_line += 1;
+                }
+                (Some(&width), _) => {
+                    let c = self.horizontal_char(line, column).unwrap_or(' ');
+                    f.put_char(c, width)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    // a vertical line is shown if any row or horizontal line has a character on it
+    fn vertical_lines(&self) -> Vec<bool> {
+        (0..=self.count_columns())
+            .map(|line| {
+                (0..self.count_rows()).any(|row| self.vertical_char(row, line).is_some())
+                    || (0..=self.count_rows())
+                        .filter(|&row| self.is_horizontal_line_visible(row))
+                        .any(|row| self.intersection_char(row, line).is_some())
+            })
+            .collect()
+    }
+
+    fn is_horizontal_line_visible(&self, line: usize) -> bool {
+        !self.horizontal_line(line).is_empty()
+            || self.horizontal_line_texts.contains_key(&line)
+            || (0..self.count_columns()).any(|column| {
+                self.cell_border_char(line, column, |b| b.top).is_some()
+                    || self
+                        .cell_border_char(line.wrapping_sub(1), column, |b| b.bottom)
+                        .is_some()
+            })
+            || (0..=self.count_columns())
+                .any(|column| self.intersection_override(line, column).is_some())
+    }
+
+    fn horizontal_line(&self, line: usize) -> &LineStyle {
+        if let Some(style) = self.horizontal_lines.get(&line) {
+            &style.line
+        } else if let Some(theme) = &self.theme {
+            theme.line(line, self.count_rows(), self.header)
+        } else if line == 0 {
+            &self.border_styles[0].top_line
+        } else {
+            &self.border_styles[line - 1].bottom_line
+        }
+    }
+
+    fn horizontal_char(&self, line: usize, column: usize) -> Option<char> {
+        self.cell_border_char(line, column, |b| b.top)
+            .or_else(|| self.cell_border_char(line.wrapping_sub(1), column, |b| b.bottom))
+            .or(self.horizontal_line(line).main)
+    }
+
+    // `line` is an index of a vertical line which goes on the left of a column with the same index
+    fn vertical_char(&self, row: usize, line: usize) -> Option<char> {
+        let border = self
+            .cell_border_char(row, line, |b| b.left)
+            .or_else(|| self.cell_border_char(row, line.wrapping_sub(1), |b| b.right));
+        if let Some(style) = self.vertical_lines.get(&line) {
+            return border.or(style.main);
+        }
+
+        border.or_else(|| {
+            let border = match &self.theme {
+                Some(theme) => &theme.inner,
+                None => &self.border_styles[row].inner,
+            };
+            if line == 0 {
+                border.left_intersection
+            } else if line == self.count_columns() {
+                border.right_intersection
+            } else {
+                border.intersection
+            }
+        })
+    }
+
+    fn intersection_char(&self, line: usize, column: usize) -> Option<char> {
+        let intersection = self.intersection_override(line, column);
+        if let Some(style) = self.vertical_lines.get(&column) {
+            return intersection.or(if line == 0 {
+                style.top_intersection
+            } else if line == self.count_rows() {
+                style.bottom_intersection
+            } else {
+                style.intersection
+            });
+        }
+
+        intersection.or_else(|| {
+            let border = self.horizontal_line(line);
+            if column == 0 {
+                border.left_intersection
+            } else if column == self.count_columns() {
+                border.right_intersection
+            } else {
+                border.intersection
+            }
+        })
+    }
+
+    // an intersection is a corner of up to 4 cells around it
+    fn intersection_override(&self, line: usize, column: usize) -> Option<char> {
+        let (above, left) = (line.wrapping_sub(1), column.wrapping_sub(1));
+        self.cell_border_char(line, column, |b| b.top_left_corner)
+            .or_else(|| self.cell_border_char(line, left, |b| b.top_right_corner))
+            .or_else(|| self.cell_border_char(above, column, |b| b.bottom_left_corner))
+            .or_else(|| self.cell_border_char(above, left, |b| b.bottom_right_corner))
+    }
+
+    // a row or a column may be out of the grid when a border on the edge is checked
+    fn cell_border_char<F>(&self, row: usize, column: usize, f: F) -> Option<char>
+    where
+        F: Fn(&CellBorder) -> Option<char>,
+    {
+        if row >= self.count_rows() || column >= self.count_columns() {
+            return None;
+        }
+
+        let borders = [
+            self.cell_borders.get(&(row, column)),
+            self.column_borders.get(&column),
+            self.row_borders.get(&row),
+        ];
+
+        borders.iter().flatten().find_map(|border| f(border))
+    }
+
+    fn style_layer_mut(&mut self, entity: Entity) -> &mut StyleLayer {
+        self.dimensions.lock().invalidate(&entity);
+        self.styles.entry(entity).or_default()
+    }
+}
+
+// measure maps items in parallel when a `rayon` feature is on
+#[cfg(feature = "rayon")]
+fn measure<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    items.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn measure<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    F: Fn(&T) -> R,
+{
+    items.iter().map(f).collect()
+}
+
+fn shift_keys<K, V, F>(map: &mut HashMap<K, V>, f: F)
+where
+    K: Eq + Hash,
+    F: Fn(K) -> Option<K>,
+{
+    *map = map
+        .drain()
+        .filter_map(|(key, value)| f(key).map(|key| (key, value)))
+        .collect();
+}
+
+/// Text is a content of a cell.
+///
+/// A static text is kept borrowed, it's copied only once it's accessed as a `String` by an index.
+#[derive(Clone, Default)]
+struct Text {
+    text: Cow<'static, str>,
+    owned: OnceLock<String>,
+}
+
+impl Text {
+    fn as_string(&self) -> &String {
+        match &self.text {
+            Cow::Owned(text) => text,
+            Cow::Borrowed(text) => self.owned.get_or_init(|| (*text).to_owned()),
+        }
+    }
+
+    fn to_mut(&mut self) -> &mut String {
+        self.owned = OnceLock::new();
+        self.text.to_mut()
+    }
+}
+
+impl From<Cow<'static, str>> for Text {
+    fn from(text: Cow<'static, str>) -> Self {
+        Self {
+            text,
+            owned: OnceLock::new(),
+        }
+    }
+}
+
+impl From<String> for Text {
+    fn from(text: String) -> Self {
+        Self::from(Cow::Owned(text))
+    }
+}
+
+impl AsRef<str> for Text {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl PartialEq for Text {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for Text {}
+
+impl fmt::Debug for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.text, f)
+    }
+}
+
+/// Dimensions is a cache of measured widths of columns and heights of rows.
+///
+/// `None` means that the value must be measured again.
+/// An estimation is the final widths and heights with forced sizes and wrapping taken into account,
+/// it's dropped on any change.
+#[derive(Debug, Clone, Default)]
+struct Dimensions {
+    widths: Vec<Option<usize>>,
+    heights: Vec<Option<usize>>,
+    estimation: Option<(Vec<usize>, Vec<usize>)>,
+}
+
+impl Dimensions {
+    fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            widths: vec![None; columns],
+            heights: vec![None; rows],
+            estimation: None,
+        }
+    }
+
+    fn invalidate_estimation(&mut self) {
+        self.estimation = None;
+    }
+
+    fn invalidate(&mut self, entity: &Entity) {
+        self.invalidate_estimation();
+        match *entity {
+            Entity::Global => {
+                self.widths.iter_mut().for_each(|w| *w = None);
+                self.heights.iter_mut().for_each(|h| *h = None);
+            }
+            Entity::Column(column) => {
+                self.widths[column] = None;
+                self.heights.iter_mut().for_each(|h| *h = None);
+            }
+            Entity::Row(row) => {
+                self.widths.iter_mut().for_each(|w| *w = None);
+                self.heights[row] = None;
+            }
+            Entity::Cell(row, column) => {
+                self.widths[column] = None;
+                self.heights[row] = None;
+            }
+        }
+    }
+
+    // old and new are (width, height) of a cell before and after a change
+    fn update_cell(&mut self, row: usize, column: usize, old: (usize, usize), new: (usize, usize)) {
+        self.invalidate_estimation();
+        self.widths[column] = update_max(self.widths[column], old.0, new.0);
+        self.heights[row] = update_max(self.heights[row], old.1, new.1);
+    }
+
+    // widths are widths of cells of the new row
+    fn insert_row(&mut self, row: usize, widths: &[usize]) {
+        self.invalidate_estimation();
+        for (cached, width) in self.widths.iter_mut().zip(widths) {
+            *cached = cached.map(|cached| max(cached, *width));
+        }
+
+        self.heights.insert(row, None);
+    }
+
+    fn push_column(&mut self) {
+        self.invalidate_estimation();
+        self.widths.push(None);
+        self.heights.iter_mut().for_each(|h| *h = None);
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        self.invalidate_estimation();
+        self.heights.swap(a, b);
+    }
+
+    fn swap_columns(&mut self, a: usize, b: usize) {
+        self.invalidate_estimation();
+        self.widths.swap(a, b);
+    }
+
+    fn insert_column(&mut self, column: usize) {
+        self.invalidate_estimation();
+        self.widths.insert(column, None);
+        self.heights.iter_mut().for_each(|h| *h = None);
+    }
+
+    fn remove_row(&mut self, row: usize) {
+        self.invalidate_estimation();
+        self.heights.remove(row);
+        self.widths.iter_mut().for_each(|w| *w = None);
+    }
+
+    fn remove_column(&mut self, column: usize) {
+        self.invalidate_estimation();
+        self.widths.remove(column);
+        self.heights.iter_mut().for_each(|h| *h = None);
+    }
+}
+
+// DimensionsCache keeps dimensions behind a mutex rather than a `RefCell`,
+// so a grid can be rendered from several threads at once.
+#[derive(Debug)]
+struct DimensionsCache(Mutex<Dimensions>);
+
+impl DimensionsCache {
+    fn new(dimensions: Dimensions) -> Self {
+        Self(Mutex::new(dimensions))
+    }
+
+    // a value is cached only once it's measured, so a cache left by a panic is still valid
+    fn lock(&self) -> MutexGuard<'_, Dimensions> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for DimensionsCache {
+    fn clone(&self) -> Self {
+        Self::new(self.lock().clone())
+    }
+}
+
+// the maximum stays valid unless the value which could be the maximum got smaller
+fn update_max(cached: Option<usize>, old: usize, new: usize) -> Option<usize> {
+    match cached {
+        Some(cached) if new >= cached => Some(new),
+        Some(cached) if old < cached => Some(cached),
+        _ => None,
+    }
+}
+
+/// Settings represent setting of a particular cell
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    text: Option<Cow<'static, str>>,
+    padding: Option<Padding>,
+    alignment: Option<Alignment>,
+    vertical_alignment: Option<AlignmentVertical>,
+}
+
+impl Settings {
+    /// New method constructs an instance of settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Text method sets content for a cell
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(Cow::Owned(text.into()));
+        self
+    }
+
+    /// Text_static sets a static content for a cell, it's kept borrowed so it's not copied.
+    ///
+    /// It's handy for grids with many cells set from literals.
+    pub fn text_static(mut self, text: &'static str) -> Self {
+        self.text = Some(Cow::Borrowed(text));
+        self
+    }
+
+    /// Ident method sets ident for a cell
+    ///
+    /// It's a shorthand for a [`Settings::padding`] filled with spaces.
+    pub fn ident(self, left: usize, right: usize, top: usize, bottom: usize) -> Self {
+        self.padding(Padding::new(left, right, top, bottom))
+    }
+
+    /// Padding method sets padding for a cell
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Alignment method sets alignment for a cell
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Vertical_alignment method sets a vertical alignment for a cell
+    ///
+    /// It matters only for cells which are lower than their row.
+    pub fn vertical_alignment(mut self, alignment: AlignmentVertical) -> Self {
+        self.vertical_alignment = Some(alignment);
+        self
+    }
+}
+
+/// Border structure represent all borders of a row
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct Border {
+    top_line: LineStyle,
+    bottom_line: LineStyle,
+    inner: LineStyle,
+}
+
+impl Border {
+    /// empty border returns a border for a row with no frame and no internal separation
+    pub fn empty(&mut self) -> &mut Self {
+        *self = Self {
+            top_line: LineStyle::default(),
+            bottom_line: LineStyle::default(),
+            inner: LineStyle::default(),
+        };
+
+        self
+    }
+
+    /// The method sets a top border line.
+    ///
+    /// * `main` - is a character which is used for building line.
+    /// * `intersection` - a character which is used for internal separation on the line.
+    /// * `left_intersection` - a left border character.
+    /// * `right_intersection` - a right border character.
+    pub fn top(
+        &mut self,
+        main: char,
+        intersection: char,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.top_line(
+            main,
+            Some(intersection),
+            left_intersection,
+            right_intersection,
+        )
+    }
+
+    /// The method sets a top border line which may have no internal separation,
+    /// the line goes on through columns then.
+    pub fn top_line(
+        &mut self,
+        main: char,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.top_line = LineStyle {
+            main: Some(main),
+            intersection,
+            left_intersection,
+            right_intersection,
+        };
+
+        self
+    }
+
+    /// The method sets a bottom border line.
+    ///
+    /// * `main` - is a character which is used for building line.
+    /// * `intersection` - a character which is used for internal separation on the line.
+    /// * `left_intersection` - a left border character.
+    /// * `right_intersection` - a right border character.
+    pub fn bottom(
+        &mut self,
+        main: char,
+        intersection: char,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.bottom_line(
+            main,
+            Some(intersection),
+            left_intersection,
+            right_intersection,
+        )
+    }
+
+    /// The method sets a bottom border line which may have no internal separation,
+    /// the line goes on through columns then.
+    pub fn bottom_line(
+        &mut self,
+        main: char,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.bottom_line = LineStyle {
+            main: Some(main),
+            intersection,
+            left_intersection,
+            right_intersection,
+        };
+
+        self
+    }
+
+    /// The method sets an inner row symbols.
+    ///
+    /// * `intersection` - a character which is used for internal separation on the line.
+    /// * `left_intersection` - a left border character.
+    /// * `right_intersection` - a right border character.
+    pub fn inner(
+        &mut self,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> &mut Self {
+        self.inner = LineStyle {
+            main: None,
+            intersection,
+            left_intersection,
+            right_intersection,
+        };
+
+        self
+    }
+}
+
+/// Appender renders rows of a grid as a continuation of a grid rendered before,
+/// so long-running tools can print rows as they arrive.
+///
+/// It's created by [`Grid::appender`] which fixes widths of columns,
+/// so appended rows line up with the ones rendered before
+/// and only the rows being rendered are measured, a content which doesn't fit is wrapped.
+/// A margin, a placement and captions are not rendered.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Grid, Entity, Settings};
+///     let mut grid = Grid::new(1, 1);
+///     grid.set(Entity::Cell(0, 0), Settings::new().text("id"));
+///     let appender = grid.appender();
+///     assert_eq!(appender.render(&grid, 0), "+--+\n|id|\n");
+///
+///     grid.push_row();
+///     grid.set(Entity::Cell(1, 0), Settings::new().text("123"));
+///     assert_eq!(appender.render(&grid, 1), "+--+\n|12|\n|3 |\n");
+///     assert_eq!(appender.render_bottom_line(&grid), "+--+\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Appender {
+    columns_width: Vec<usize>,
+}
+
+impl Appender {
+    /// Render renders rows of a grid starting from `row`.
+    ///
+    /// Each row is preceded by a line above it and the grid isn't closed,
+    /// [`Appender::render_bottom_line`] closes it when no more rows are going to be appended.
+    /// Nothing is rendered if the grid has a different number of columns than when the appender was made.
+    pub fn render(&self, grid: &Grid, row: usize) -> String {
+        if row >= grid.count_rows() || !self.fits(grid) {
+            return String::new();
+        }
+
+        grid.with_line_ending(GridAppended(grid, row, &self.columns_width).to_string())
+    }
+
+    /// Render_bottom_line renders a line under the last row of a grid.
+    pub fn render_bottom_line(&self, grid: &Grid) -> String {
+        if grid.count_rows() == 0 || !self.fits(grid) {
+            return String::new();
+        }
+
+        let from = grid.count_rows();
+        grid.with_line_ending(GridAppended(grid, from, &self.columns_width).to_string())
+    }
+
+    fn fits(&self, grid: &Grid) -> bool {
+        grid.count_columns() > 0 && grid.count_columns() == self.columns_width.len()
+    }
+}
+
+/// Theme describes borders of a grid by their roles, like a line under a header,
+/// it's set by [`Grid::set_theme`].
+///
+/// A line which is not set is not rendered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    top: LineStyle,
+    bottom: LineStyle,
+    header: LineStyle,
+    split: LineStyle,
+    inner: LineStyle,
+}
+
+impl Theme {
+    /// New creates a theme without any lines.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Top sets a line above a grid.
+    pub fn top(
+        mut self,
+        main: char,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> Self {
+        self.top = LineStyle::new(main, intersection, left_intersection, right_intersection);
+        self
+    }
+
+    /// Bottom sets a line under a grid.
+    pub fn bottom(
+        mut self,
+        main: char,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> Self {
+        self.bottom = LineStyle::new(main, intersection, left_intersection, right_intersection);
+        self
+    }
+
+    /// Header sets a line under the first row.
+    pub fn header(
+        mut self,
+        main: char,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> Self {
+        self.header = LineStyle::new(main, intersection, left_intersection, right_intersection);
+        self
+    }
+
+    /// Split sets a line between the rest of rows.
+    pub fn split(
+        mut self,
+        main: char,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> Self {
+        self.split = LineStyle::new(main, intersection, left_intersection, right_intersection);
+        self
+    }
+
+    /// Inner sets vertical lines, between columns and on the sides of a grid.
+    pub fn inner(
+        mut self,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> Self {
+        self.inner = LineStyle {
+            main: None,
+            intersection,
+            left_intersection,
+            right_intersection,
+        };
+        self
+    }
+
+    // a line under the first row is a header line unless a grid has no header
+    fn line(&self, line: usize, count_rows: usize, header: bool) -> &LineStyle {
+        match line {
+            0 => &self.top,
+            1 if header => &self.header,
+            line if line == count_rows => &self.bottom,
+            _ => &self.split,
+        }
+    }
+}
+
+/// CellBorder overrides borders of a particular cell.
+///
+/// A character which is not set is taken from a [`Border`] of a row.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellBorder {
+    top: Option<char>,
+    bottom: Option<char>,
+    left: Option<char>,
+    right: Option<char>,
+    top_left_corner: Option<char>,
+    top_right_corner: Option<char>,
+    bottom_left_corner: Option<char>,
+    bottom_right_corner: Option<char>,
+}
+
+impl CellBorder {
+    /// New method constructs a border which doesn't override anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Full method constructs a border which overrides all sides and corners of a cell.
+    #[allow(clippy::too_many_arguments)]
+    pub fn full(
+        top: char,
+        bottom: char,
+        left: char,
+        right: char,
+        top_left_corner: char,
+        top_right_corner: char,
+        bottom_left_corner: char,
+        bottom_right_corner: char,
+    ) -> Self {
+        Self {
+            top: Some(top),
+            bottom: Some(bottom),
+            left: Some(left),
+            right: Some(right),
+            top_left_corner: Some(top_left_corner),
+            top_right_corner: Some(top_right_corner),
+            bottom_left_corner: Some(bottom_left_corner),
+            bottom_right_corner: Some(bottom_right_corner),
+        }
+    }
+
+    /// Top method sets a character of a top side.
+    pub fn top(mut self, c: char) -> Self {
+        self.top = Some(c);
+        self
+    }
+
+    /// Bottom method sets a character of a bottom side.
+    pub fn bottom(mut self, c: char) -> Self {
+        self.bottom = Some(c);
+        self
+    }
+
+    /// Left method sets a character of a left side.
+    pub fn left(mut self, c: char) -> Self {
+        self.left = Some(c);
+        self
+    }
+
+    /// Right method sets a character of a right side.
+    pub fn right(mut self, c: char) -> Self {
+        self.right = Some(c);
+        self
+    }
+
+    /// Top_left_corner method sets a character of a top left corner.
+    pub fn top_left_corner(mut self, c: char) -> Self {
+        self.top_left_corner = Some(c);
+        self
+    }
+
+    /// Top_right_corner method sets a character of a top right corner.
+    pub fn top_right_corner(mut self, c: char) -> Self {
+        self.top_right_corner = Some(c);
+        self
+    }
+
+    /// Bottom_left_corner method sets a character of a bottom left corner.
+    pub fn bottom_left_corner(mut self, c: char) -> Self {
+        self.bottom_left_corner = Some(c);
+        self
+    }
+
+    /// Bottom_right_corner method sets a character of a bottom right corner.
+    pub fn bottom_right_corner(mut self, c: char) -> Self {
+        self.bottom_right_corner = Some(c);
+        self
+    }
+
+    /// Get_top returns a character of a top side if it's overridden.
+    pub fn get_top(&self) -> Option<char> {
+        self.top
+    }
+
+    /// Get_bottom returns a character of a bottom side if it's overridden.
+    pub fn get_bottom(&self) -> Option<char> {
+        self.bottom
+    }
+
+    /// Get_left returns a character of a left side if it's overridden.
+    pub fn get_left(&self) -> Option<char> {
+        self.left
+    }
+
+    /// Get_right returns a character of a right side if it's overridden.
+    pub fn get_right(&self) -> Option<char> {
+        self.right
+    }
+
+    /// Get_top_left_corner returns a character of a top left corner if it's overridden.
+    pub fn get_top_left_corner(&self) -> Option<char> {
+        self.top_left_corner
+    }
+
+    /// Get_top_right_corner returns a character of a top right corner if it's overridden.
+    pub fn get_top_right_corner(&self) -> Option<char> {
+        self.top_right_corner
+    }
+
+    /// Get_bottom_left_corner returns a character of a bottom left corner if it's overridden.
+    pub fn get_bottom_left_corner(&self) -> Option<char> {
+        self.bottom_left_corner
+    }
+
+    /// Get_bottom_right_corner returns a character of a bottom right corner if it's overridden.
+    pub fn get_bottom_right_corner(&self) -> Option<char> {
+        self.bottom_right_corner
+    }
+
+    fn merge(&mut self, other: CellBorder) {
+        self.top = other.top.or(self.top);
+        self.bottom = other.bottom.or(self.bottom);
+        self.left = other.left.or(self.left);
+        self.right = other.right.or(self.right);
+        self.top_left_corner = other.top_left_corner.or(self.top_left_corner);
+        self.top_right_corner = other.top_right_corner.or(self.top_right_corner);
+        self.bottom_left_corner = other.bottom_left_corner.or(self.bottom_left_corner);
+        self.bottom_right_corner = other.bottom_right_corner.or(self.bottom_right_corner);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+struct LineStyle {
+    main: Option<char>,
+    intersection: Option<char>,
+    left_intersection: Option<char>,
+    right_intersection: Option<char>,
+}
+
+impl LineStyle {
+    fn new(
+        main: char,
+        intersection: Option<char>,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> Self {
+        Self {
+            main: Some(main),
+            intersection,
+            left_intersection,
+            right_intersection,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.left_intersection.is_none()
+            && self.right_intersection.is_none()
+            && self.intersection.is_none()
+            && self.main.is_none()
+    }
+}
+
+/// HorizontalLine overrides a horizontal line of a grid.
+///
+/// See [`Grid::set_horizontal_line`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct HorizontalLine {
+    line: LineStyle,
+}
+
+impl HorizontalLine {
+    /// New creates a line.
+    ///
+    /// * `main` - is a character which is used for building line.
+    /// * `intersection` - a character which is used for internal separation on the line.
+    /// * `left_intersection` - a left border character.
+    /// * `right_intersection` - a right border character.
+    pub fn new(
+        main: char,
+        intersection: char,
+        left_intersection: Option<char>,
+        right_intersection: Option<char>,
+    ) -> Self {
+        Self {
+            line: LineStyle {
+                main: Some(main),
+                intersection: Some(intersection),
+                left_intersection,
+                right_intersection,
+            },
+        }
+    }
+
+    /// Empty creates a line which is not shown.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+}
+
+/// VerticalLine overrides a vertical line of a grid.
+///
+/// See [`Grid::set_vertical_line`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerticalLine {
+    main: Option<char>,
+    intersection: Option<char>,
+    top_intersection: Option<char>,
+    bottom_intersection: Option<char>,
+}
+
+impl VerticalLine {
+    /// New creates a line.
+    ///
+    /// * `main` - is a character which is used for building line.
+    /// * `intersection` - a character which is used on inner horizontal lines.
+    /// * `top_intersection` - a character which is used on the top line.
+    /// * `bottom_intersection` - a character which is used on the bottom line.
+    pub fn new(
+        main: char,
+        intersection: char,
+        top_intersection: Option<char>,
+        bottom_intersection: Option<char>,
+    ) -> Self {
+        Self {
+            main: Some(main),
+            intersection: Some(intersection),
+            top_intersection,
+            bottom_intersection,
+        }
+    }
+
+    /// Empty creates a line which is not shown.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+}
+
+/// RowMetadata is an information about a row which isn't a part of its content.
+///
+/// It can be used by renderers other than a text one, for example to set a class of a HTML row.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Grid, RowMetadata, Severity};
+///     let mut grid = Grid::new(2, 2);
+///     grid.set_row_metadata(1, RowMetadata::new().severity(Severity::Error).id("42")).unwrap();
+///
+///     let metadata = grid.get_row_metadata(1).unwrap();
+///     assert_eq!(metadata.get_severity(), Some(Severity::Error));
+///     assert_eq!(metadata.get_id(), Some("42"));
+///     assert_eq!(metadata.get_url(), None);
+///     assert!(grid.get_row_metadata(0).is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct RowMetadata {
+    severity: Option<Severity>,
+    id: Option<String>,
+    url: Option<String>,
+}
+
+impl RowMetadata {
+    /// New method constructs an empty metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Severity method sets a severity of a row.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Id method sets an identifier of a row.
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Url method sets a link associated with a row.
+    pub fn url<S: Into<String>>(mut self, url: S) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Get_severity returns a severity of a row.
+    pub fn get_severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    /// Get_id returns an identifier of a row.
+    pub fn get_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Get_url returns a link associated with a row.
+    pub fn get_url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+}
+
+/// Severity is an importance of a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+/// SizePolicy defines what happens when a forced width or height is too small to render a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum SizePolicy {
+    /// An error is returned.
+    #[default]
+    Strict,
+    /// A size is raised to the minimum.
+    Clamp,
+}
+
+/// CaptionPosition defines where a caption of a grid is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaptionPosition {
+    /// A caption is rendered above a grid.
+    Top,
+    /// A caption is rendered under a grid, before a legend.
+    Bottom,
+}
+
+/// SpanDistribution defines how an extra width of a spanned cell,
+/// which doesn't fit into the columns it spans over, is distributed across them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpanDistribution {
+    /// Columns are widened proportionally to their widths.
+    #[default]
+    Proportional,
+    /// Columns are widened evenly.
+    Even,
+    /// The last column is widened.
+    Last,
+}
+
+impl SpanDistribution {
+    // a remainder of a division goes to the first columns
+    fn distribute(&self, widths: &mut [usize], extra: usize) {
+        let total = widths.iter().sum::<usize>();
+        let shares = match self {
+            SpanDistribution::Proportional if total > 0 => widths
+                .iter()
+                .map(|width| extra * width / total)
+                .collect::<Vec<_>>(),
+            SpanDistribution::Proportional | SpanDistribution::Even => {
+                vec![extra / widths.len(); widths.len()]
+            }
+            SpanDistribution::Last => {
+                let mut shares = vec![0; widths.len()];
+                shares[widths.len() - 1] = extra;
+                shares
+            }
+        };
+
+        let remainder = extra - shares.iter().sum::<usize>();
+        for (i, (width, share)) in widths.iter_mut().zip(shares).enumerate() {
+            *width += share + if i < remainder { 1 } else { 0 };
+        }
+    }
+}
+
+/// Sanitization is a policy of handling control characters in a content of cells.
+///
+/// A content of a table often comes from an untrusted source,
+/// so it may contain escape sequences which change a title of a terminal, move a cursor and so on.
+/// SGR sequences, the ones which set colors and text styles, are always kept.
+///
+/// Bidirectional formatting characters are treated as control ones,
+/// as they can reorder a content of a cell as well as the rest of a line.
+/// `\r\n` line endings are replaced by `\n` unless sanitization is turned off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sanitization {
+    /// Control characters are removed together with escape sequences they start.
+    #[default]
+    Strip,
+    /// Control characters are replaced by their escaped form, e.g. `\u{1b}`.
+    Escape,
+    /// Control characters are replaced by a given character together with escape sequences they start.
+    Replace(char),
+    /// A content is printed as it is.
+    Off,
+}
+
+/// LineEnding is a sequence which ends each line of a rendered grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl Sanitization {
+    fn apply<'a>(&self, text: Cow<'a, str>) -> Cow<'a, str> {
+        if *self == Sanitization::Off || !text.chars().any(is_unsafe_char) {
+            return text;
+        }
+
+        let text = if text.contains("\r\n") {
+            Cow::Owned(text.replace("\r\n", "\n"))
+        } else {
+            text
+        };
+
+        let mut sanitized = String::with_capacity(text.len());
+        let mut i = 0;
+        while let Some(c) = text[i..].chars().next() {
+            if !is_unsafe_char(c) {
+                sanitized.push(c);
+                i += c.len_utf8();
+                continue;
+            }
+
+            let length = escape_sequence_len(&text[i..]);
+            let sequence = &text[i..i + length];
+            if is_sgr_sequence(sequence) {
+                sanitized.push_str(sequence);
+                i += length;
+                continue;
+            }
+
+            match self {
+                Sanitization::Strip => i += length,
+                Sanitization::Replace(c) => {
+                    sanitized.push(*c);
+                    i += length;
+                }
+                _ => {
+                    sanitized.extend(c.escape_default());
+                    i += c.len_utf8();
+                }
+            }
+        }
+
+        Cow::Owned(sanitized)
+    }
+}
+
+// a tab is replaced by spaces up to the next tab stop, each line starts at a tab stop
+fn expand_tabs(text: &str, tab_width: usize) -> String {
+    let mut expanded = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            expanded.push('\n');
+        }
+
+        let mut width = 0;
+        for (j, part) in line.split('\t').enumerate() {
+            if j > 0 {
+                let spaces = tab_width - width % tab_width;
+                expanded.push_str(&" ".repeat(spaces));
+                width += spaces;
+            }
+
+            expanded.push_str(part);
+            width += string_width(part);
+        }
+    }
+
+    expanded
+}
+
+fn is_unsafe_char(c: char) -> bool {
+    (c.is_control() && c != '\n' && c != '\t') || is_bidi_char(c)
+}
+
+// embeddings, overrides and isolates of the unicode bidirectional algorithm
+fn is_bidi_char(c: char) -> bool {
+    ('\u{202a}'..='\u{202e}').contains(&c) || ('\u{2066}'..='\u{2069}').contains(&c)
+}
+
+// returns a length in bytes of an escape sequence or a control character the text starts with
+fn escape_sequence_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    let first = match chars.next() {
+        Some((_, c)) => c,
+        None => return 0,
+    };
+
+    let is_csi = match first {
+        '\u{9b}' => true,
+        '\u{1b}' => match chars.next() {
+            Some((_, '[')) => true,
+            Some((_, ']')) => return osc_len(text, 2),
+            Some((i, c)) => return i + c.len_utf8(),
+            None => return text.len(),
+        },
+        '\u{9d}' => return osc_len(text, first.len_utf8()),
+        c => return c.len_utf8(),
+    };
+
+    debug_assert!(is_csi);
+    for (i, c) in chars {
+        if ('\u{40}'..='\u{7e}').contains(&c) {
+            return i + c.len_utf8();
+        }
+
+        if !('\u{20}'..='\u{3f}').contains(&c) {
+            return i;
+        }
+    }
+
+    text.len()
+}
+
+// an operating system command is terminated by BEL or ST
+fn osc_len(text: &str, start: usize) -> usize {
+    let mut chars = text[start..].char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\u{7}' | '\u{9c}' => return start + i + c.len_utf8(),
+            '\u{1b}' if chars.peek().map(|(_, c)| *c) == Some('\\') => return start + i + 2,
+            _ => {}
+        }
+    }
+
+    text.len()
+}
+
+fn is_sgr_sequence(sequence: &str) -> bool {
+    let params = if let Some(params) = sequence.strip_prefix("\u{1b}[") {
+        params
+    } else if let Some(params) = sequence.strip_prefix('\u{9b}') {
+        params
+    } else {
+        return false;
+    };
+
+    match params.strip_suffix('m') {
+        Some(params) => params
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ';' || c == ':'),
+        None => false,
+    }
+}
+
+/// Error is returned by methods of a grid which are given an entity out of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// A row index is not less than a number of rows
+    RowOutOfRange { row: usize, count_rows: usize },
+    /// A column index is not less than a number of columns
+    ColumnOutOfRange { column: usize, count_columns: usize },
+    /// A horizontal line index is greater than a number of rows
+    LineOutOfRange { line: usize, count_lines: usize },
+    /// A span of a cell goes beyond the last column
+    SpanOutOfRange {
+        column: usize,
+        span: usize,
+        count_columns: usize,
+    },
+    /// A span of a cell goes beyond the last row
+    RowSpanOutOfRange {
+        row: usize,
+        span: usize,
+        count_rows: usize,
+    },
+    /// A span of a cell covers a cell which is already a part of another span
+    SpanOverlap { row: usize, column: usize },
+    /// A forced width of a column is less than its padding plus one character
+    WidthTooSmall {
+        column: usize,
+        width: usize,
+        min_width: usize,
+    },
+    /// A forced height of a row is less than its padding plus one line
+    HeightTooSmall {
+        row: usize,
+        height: usize,
+        min_height: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::RowOutOfRange { row, count_rows } => write!(
+                f,
+                "row {} is out of range, the grid has {} rows",
+                row, count_rows
+            ),
+            Error::ColumnOutOfRange {
+                column,
+                count_columns,
+            } => write!(
+                f,
+                "column {} is out of range, the grid has {} columns",
+                column, count_columns
+            ),
+            Error::WidthTooSmall {
+                column,
+                width,
+                min_width,
+            } => write!(
+                f,
+                "width {} of column {} is too small, the minimum is {}",
+                width, column, min_width
+            ),
+            Error::LineOutOfRange { line, count_lines } => write!(
+                f,
+                "line {} is out of range, the grid has {} horizontal lines",
+                line, count_lines
+            ),
+            Error::SpanOutOfRange {
+                column,
+                span,
+                count_columns,
+            } => write!(
+                f,
+                "span {} of column {} is out of range, the grid has {} columns",
+                span, column, count_columns
+            ),
+            Error::RowSpanOutOfRange {
+                row,
+                span,
+                count_rows,
+            } => write!(
+                f,
+                "span {} of row {} is out of range, the grid has {} rows",
+                span, row, count_rows
+            ),
+            Error::SpanOverlap { row, column } => write!(
+                f,
+                "cell ({}, {}) is already a part of another span",
+                row, column
+            ),
+            Error::HeightTooSmall {
+                row,
+                height,
+                min_height,
+            } => write!(
+                f,
+                "height {} of row {} is too small, the minimum is {}",
+                height, row, min_height
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Entity a structure which represent a set of cells.
+#[derive(PartialEq, Eq, Debug, Hash, Clone)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum Entity {
+    /// All cells on the grid.
+    Global,
+    /// All cells in a column on the grid.
+    Column(usize),
+    /// All cells in a row on the grid.
+    Row(usize),
+    /// A particular cell (row, column) on the grid.
+    Cell(usize, usize),
+}
+
+#[derive(Debug, Clone)]
+struct Style {
+    padding: Padding,
+    alignment: Alignment,
+    vertical_alignment: AlignmentVertical,
+}
+
+// StyleLayer is a part of a style set for an entity,
+// properties which are not set are taken from less specific entities.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+struct StyleLayer {
+    padding: Option<Padding>,
+    alignment: Option<Alignment>,
+    vertical_alignment: Option<AlignmentVertical>,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            alignment: Alignment::Left,
+            vertical_alignment: AlignmentVertical::Top,
+            padding: Padding::default(),
+        }
+    }
+}
+
+/// Padding represents an empty space around a cell content.
+///
+/// The space is filled with a [`Padding::fill`] character,
+/// which is expected to take a single column like a space does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct Padding {
+    pub left: usize,
+    pub right: usize,
+    pub top: usize,
+    pub bottom: usize,
+    pub fill: char,
+}
+
+impl Padding {
+    /// New creates a padding filled with spaces.
+    pub fn new(left: usize, right: usize, top: usize, bottom: usize) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+            fill: ' ',
+        }
+    }
+
+    /// Fill sets a character the padding is filled with.
+    pub fn fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+}
+
+impl Default for Padding {
+    fn default() -> Self {
+        Self::new(0, 0, 0, 0)
+    }
+}
+
+/// Margin represents a space around a whole grid.
+///
+/// The space is filled with a [`Margin::fill`] character,
+/// which is expected to take a single column like a space does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub struct Margin {
+    pub left: usize,
+    pub right: usize,
+    pub top: usize,
+    pub bottom: usize,
+    pub fill: char,
+}
+
+impl Margin {
+    /// New creates a margin filled with spaces.
+    pub fn new(left: usize, right: usize, top: usize, bottom: usize) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+            fill: ' ',
+        }
+    }
+
+    /// Fill sets a character the margin is filled with.
+    pub fn fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    fn is_empty(&self) -> bool {
+        self.left == 0 && self.right == 0 && self.top == 0 && self.bottom == 0
+    }
+}
+
+impl Default for Margin {
+    fn default() -> Self {
+        Self::new(0, 0, 0, 0)
+    }
+}
+
+/// Alignment represents an horizontal aligment of a cell content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum Alignment {
+    Center,
+    Left,
+    Right,
+}
+
+impl Alignment {
+    // a text wider than the length is written as it is
+    fn write_aligned<W: Sink>(&self, f: &mut W, text: &str, length: usize) -> fmt::Result {
+        let diff = length.saturating_sub(string_width(text));
+        let (left, right) = match self {
+            Alignment::Left => (0, diff),
+            Alignment::Right => (diff, 0),
+            Alignment::Center => (diff / 2, diff - diff / 2),
+        };
+
+        f.put_char(' ', left)?;
+        f.put_str(text)?;
+        f.put_char(' ', right)
+    }
+}
+
+/// AlignmentVertical represents a vertical alignment of a cell content
+/// in a row which is higher than the content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlignmentVertical {
+    Top,
+    Center,
+    Bottom,
+}
+
+impl AlignmentVertical {
+    // it returns a number of empty lines put above a content
+    fn offset(&self, height: usize, lines: usize) -> usize {
+        let diff = height.saturating_sub(lines);
+        match self {
+            AlignmentVertical::Top => 0,
+            AlignmentVertical::Bottom => diff,
+            AlignmentVertical::Center => diff / 2,
+        }
+    }
+}
+
+// LimitedWriter fails as soon as a limit of bytes is reached.
+struct LimitedWriter {
+    buf: String,
+    limit: usize,
+}
+
+impl fmt::Write for LimitedWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.buf.len() + s.len() <= self.limit {
+            self.buf.push_str(s);
+            return Ok(());
+        }
+
+        let mut end = self.limit - self.buf.len();
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.buf.push_str(&s[..end]);
+
+        Err(fmt::Error)
+    }
+}
+
+// LinesWriter passes a text to a writer line by line without line endings,
+// so each line can be changed on the way.
+// A text is expected to end with a line ending, otherwise its last line is not passed.
+struct LinesWriter<'a, W, F> {
+    writer: &'a mut W,
+    line: String,
+    write_line: F,
+}
+
+impl<'a, W, F> LinesWriter<'a, W, F>
+where
+    W: fmt::Write,
+    F: FnMut(&mut W, &str) -> fmt::Result,
+{
+    fn new(writer: &'a mut W, write_line: F) -> Self {
+        Self {
+            writer,
+            line: String::new(),
+            write_line,
+        }
+    }
+}
+
+impl<W, F> fmt::Write for LinesWriter<'_, W, F>
+where
+    W: fmt::Write,
+    F: FnMut(&mut W, &str) -> fmt::Result,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut lines = s.split('\n');
+        self.line.push_str(lines.next().unwrap_or_default());
+
+        for line in lines {
+            (self.write_line)(self.writer, &self.line)?;
+            self.line.clear();
+            self.line.push_str(line);
+        }
+
+        Ok(())
+    }
+}
+
+// Sink is where a grid is built into,
+// it's either a writer or a counter of lengths of lines.
+trait Sink {
+    fn put_str(&mut self, text: &str) -> fmt::Result;
+
+    fn put_char(&mut self, c: char, count: usize) -> fmt::Result;
+}
+
+impl<W: fmt::Write> Sink for W {
+    fn put_str(&mut self, text: &str) -> fmt::Result {
+        self.write_str(text)
+    }
+
+    fn put_char(&mut self, c: char, count: usize) -> fmt::Result {
+        for _ in 0..count {
+            self.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+// LineCounter keeps a length in bytes and a width of each built line.
+#[derive(Default)]
+struct LineCounter {
+    lines: Vec<(usize, usize)>,
+    bytes: usize,
+    width: usize,
+}
+
+impl Sink for LineCounter {
+    fn put_str(&mut self, text: &str) -> fmt::Result {
+        let mut parts = text.split('\n');
+        if let Some(part) = parts.next() {
+            self.bytes += part.len();
+            self.width += string_width(part);
+        }
+
+        for part in parts {
+            self.lines.push((self.bytes, self.width));
+            self.bytes = part.len();
+            self.width = string_width(part);
+        }
+
+        Ok(())
+    }
+
+    fn put_char(&mut self, c: char, count: usize) -> fmt::Result {
+        if c == '\n' {
+            for _ in 0..count {
+                self.put_str("\n")?;
+            }
+
+            return Ok(());
+        }
+
+        self.bytes += c.len_utf8() * count;
+        self.width += string_width(c.encode_utf8(&mut [0; 4])) * count;
+
+        Ok(())
+    }
+}
+
+impl From<Vec<Vec<String>>> for Grid {
+    fn from(rows: Vec<Vec<String>>) -> Self {
+        Self::from_rows(rows)
+    }
+}
+
+/// A content of a cell can be accessed by a `(row, column)` index.
+///
+/// The index panics if the cell is out of the grid, [`Grid::get_cell`] can be used instead.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::Grid;
+///     let mut grid = Grid::new(2, 2);
+///     grid[(0, 1)] = String::from("asd");
+///     grid[(0, 1)].push('f');
+///     assert_eq!(grid[(0, 1)], "asdf");
+/// ```
+impl Index<(usize, usize)> for Grid {
+    type Output = String;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        self.cells[row][column].as_string()
+    }
+}
+
+impl IndexMut<(usize, usize)> for Grid {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        self.dimensions
+            .lock()
+            .invalidate(&Entity::Cell(row, column));
+        self.cells[row][column].to_mut()
+    }
+}
+
+/// Providers are listed by their entities and an estimator only by its presence,
+/// as functions can't be formatted.
+impl fmt::Debug for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Grid")
+            .field("size", &self.size)
+            .field("cells", &self.cells)
+            .field("border_styles", &self.border_styles)
+            .field("theme", &self.theme)
+            .field("styles", &self.styles)
+            .field("providers", &self.providers.keys().collect::<Vec<_>>())
+            .field("cell_borders", &self.cell_borders)
+            .field("row_borders", &self.row_borders)
+            .field("column_borders", &self.column_borders)
+            .field("horizontal_lines", &self.horizontal_lines)
+            .field("horizontal_line_texts", &self.horizontal_line_texts)
+            .field("vertical_lines", &self.vertical_lines)
+            .field("sanitization", &self.sanitization)
+            .field("tab_width", &self.tab_width)
+            .field("metadata", &self.metadata)
+            .field("details", &self.details)
+            .field("show_details", &self.show_details)
+            .field("widths", &self.widths)
+            .field("heights", &self.heights)
+            .field("size_policy", &self.size_policy)
+            .field("legend", &self.legend)
+            .field("captions", &self.captions)
+            .field("margin", &self.margin)
+            .field("placement", &self.placement)
+            .field("line_ending", &self.line_ending)
+            .field("trailing_newline", &self.trailing_newline)
+            .field("header", &self.header)
+            .field("estimator", &self.estimator.is_some())
+            .field("spans", &self.spans)
+            .field("row_spans", &self.row_spans)
+            .field("span_distribution", &self.span_distribution)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Grids are equal when they have the same content and settings.
+///
+/// Providers and estimators can't be compared by their output,
+/// so they're equal only when they're shared, e.g. by a clone of a grid.
+///
+/// # Example
+///
+/// ```rust
+///     use papergrid::{Grid, Entity, Settings};
+///     let mut grid = Grid::new(1, 1);
+///     grid.set(Entity::Global, Settings::new().text("asd"));
+///
+///     let mut copy = grid.clone();
+///     assert_eq!(grid, copy);
+///
+///     copy.set(Entity::Global, Settings::new().text("qwe"));
+///     assert_ne!(grid, copy);
+/// ```
+impl PartialEq for Grid {
+    fn eq(&self, other: &Self) -> bool {
+        let same_providers = self.providers.len() == other.providers.len()
+            && self.providers.iter().all(|(entity, provider)| {
+                other
+                    .providers
+                    .get(entity)
+                    .is_some_and(|other| Arc::ptr_eq(provider, other))
+            });
+        let same_estimator = match (&self.estimator, &other.estimator) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_providers
+            && same_estimator
+            && self.size == other.size
+            && self.cells == other.cells
+            && self.border_styles == other.border_styles
+            && self.theme == other.theme
+            && self.styles == other.styles
+            && self.cell_borders == other.cell_borders
+            && self.row_borders == other.row_borders
+            && self.column_borders == other.column_borders
+            && self.horizontal_lines == other.horizontal_lines
+            && self.horizontal_line_texts == other.horizontal_line_texts
+            && self.vertical_lines == other.vertical_lines
+            && self.sanitization == other.sanitization
+            && self.tab_width == other.tab_width
+            && self.metadata == other.metadata
+            && self.details == other.details
+            && self.show_details == other.show_details
+            && self.widths == other.widths
+            && self.heights == other.heights
+            && self.size_policy == other.size_policy
+            && self.legend == other.legend
+            && self.captions == other.captions
+            && self.margin == other.margin
+            && self.placement == other.placement
+            && self.line_ending == other.line_ending
+            && self.trailing_newline == other.trailing_newline
+            && self.header == other.header
+            && self.spans == other.spans
+            && self.row_spans == other.row_spans
+            && self.span_distribution == other.span_distribution
+    }
+}
+
+// I like old solution with Full/Frame/Off
+
+impl std::fmt::Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = (0..self.count_rows()).collect::<Vec<_>>();
+        self.render(f, &rows)
+    }
+}
+
+// GridAppended renders rows starting from a given one by fixed widths without closing a grid.
+struct GridAppended<'a>(&'a Grid, usize, &'a [usize]);
+
+impl fmt::Display for GridAppended<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.build_appended(f, self.1, self.2)
+    }
+}
+
+// GridRows renders given rows of a grid with a margin, a placement and line endings.
+struct GridRows<'a>(&'a Grid, &'a [usize]);
+
+impl fmt::Display for GridRows<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.render(f, self.1)
+    }
+}
+
+// Cell is a content of a cell split into lines which fit into its width.
+struct SpannedCell<'a> {
+    cell: Cell<'a>,
+    next_line: usize,
+    end: usize,
+}
+
+struct Cell<'a> {
+    lines: Vec<Cow<'a, str>>,
+    style: Style,
+    width: usize,
+    height: usize,
+}
+
+impl<'a> Cell<'a> {
+    fn new(text: &'a str, style: Style, width: usize, height: usize) -> Self {
+        let content_width = width - style.padding.left - style.padding.right;
+        let content_height = height - style.padding.top - style.padding.bottom;
+        let lines = split_text(
+            text,
+            content_width,
+            content_height,
+            &style.vertical_alignment,
+        );
+        // an empty content still takes a line
+        let height = max(
+            height,
+            lines.len() + style.padding.top + style.padding.bottom,
+        );
+
+        Self {
+            lines,
+            style,
+            width,
+            height,
+        }
+    }
+
+    fn write_line<W: Sink>(&self, f: &mut W, i: usize) -> fmt::Result {
+        let padding = &self.style.padding;
+        if i >= self.height {
+            return f.put_char(' ', self.width);
+        }
+
+        if i < padding.top || i >= self.height - padding.bottom {
+            return f.put_char(padding.fill, self.width);
+        }
+
+        // a wrapping may disagree with a measured width when a content has raw control characters
+        // so cells of a row may have a different number of lines
+        let text = self.lines.get(i - padding.top).map_or("", |line| line);
+        let width = self.width - padding.left - padding.right;
+
+        f.put_char(padding.fill, padding.left)?;
+        self.style.alignment.write_aligned(f, text, width)?;
+        f.put_char(padding.fill, padding.right)
+    }
+}
+
+// It replaces characters of a line in `start..end` by a text,
+// a wide character of the text takes place of 2 characters of the line.
+fn overlay_text(line: &[char], text: &str, start: usize, end: usize) -> Vec<char> {
+    let mut chars = line[..start].to_vec();
+    let mut position = start;
+    for c in text.chars().filter(|c| *c != '\n') {
+        let width = string_width(c.encode_utf8(&mut [0; 4]));
+        if position + width > end {
+            break;
+        }
+
+        chars.push(c);
+        position += width;
+    }
+
+    chars.extend_from_slice(&line[position..]);
+    chars
+}
+
+fn split_text<'a>(
+    text: &'a str,
+    width: usize,
+    height: usize,
+    alignment: &AlignmentVertical,
+) -> Vec<Cow<'a, str>> {
+    let mut lines = textwrap::wrap(text, width);
+    // there may be more lines when a height of a row is forced,
+    // but an empty text is always wrapped into a single line
+    lines.truncate(max(height, 1));
+
+    let offset = alignment.offset(height, lines.len());
+    let empty = || Cow::Owned(str::repeat(" ", width));
+    lines.splice(0..0, std::iter::repeat_with(empty).take(offset));
+    while lines.len() < height {
+        lines.push(empty())
+    }
+
+    lines
+}
+
+/// String_width returns a width of the widest line of a text as it's measured by a grid.
+///
+/// When a `color` feature is on ANSI escape sequences are not taken into account.
+#[cfg(not(feature = "color"))]
+pub fn string_width(text: &str) -> usize {
+    real_string_width(text)
+}
+
+/// String_width returns a width of the widest line of a text as it's measured by a grid.
+///
+/// When a `color` feature is on ANSI escape sequences are not taken into account.
+#[cfg(feature = "color")]
+pub fn string_width(text: &str) -> usize {
+    let b = strip_ansi_escapes::strip(text.as_bytes()).unwrap();
+    let s = std::str::from_utf8(&b).unwrap();
+    real_string_width(s)
+}
+
+#[cfg(feature = "unicode-width")]
+fn real_string_width(text: &str) -> usize {
+    text.lines()
+        .map(textwrap::core::display_width)
+        .max()
+        .unwrap_or(0)
+}
+
+// without a `unicode-width` feature each character is considered to take one column,
+// escape sequences are skipped the same way as `textwrap` does it
+#[cfg(not(feature = "unicode-width"))]
+fn real_string_width(text: &str) -> usize {
+    text.lines().map(line_width).max().unwrap_or(0)
+}
+
+#[cfg(not(feature = "unicode-width"))]
+fn line_width(mut line: &str) -> usize {
+    let mut width = 0;
+    while let Some(c) = line.chars().next() {
+        let len = if c == '\u{1b}' {
+            escape_sequence_len(line)
+        } else {
+            width += 1;
+            c.len_utf8()
+        };
+
+        line = &line[len..];
+    }
+
+    width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_1x1_test() {
+        let mut grid = Grid::new(1, 1);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+\n\
+             |asd|\n\
+             +---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_entity_settings_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Column(0), Settings::new().text("zxc"));
+        grid.set(Entity::Row(0), Settings::new().text("qwe"));
+        grid.set(Entity::Cell(1, 1), Settings::new().text("iop"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |qwe|qwe|\n\
+             +---+---+\n\
+             |zxc|iop|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_alignment_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd    "));
+        grid.set(
+            Entity::Column(0),
+            Settings::new().alignment(Alignment::Left),
+        );
+        grid.set(
+            Entity::Column(1),
+            Settings::new().alignment(Alignment::Right),
+        );
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+-------+-------+\n\
+             |asd    |    asd|\n\
+             +-------+-------+\n\
+             |asd    |    asd|\n\
+             +-------+-------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_ident_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new().text("asd").ident(1, 1, 1, 1),
+        );
+        grid.set(Entity::Column(0), Settings::new().ident(0, 0, 0, 0));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+-----+\n\
+             |asd|     |\n\
+             |   | asd |\n\
+             |   |     |\n\
+             +---+-----+\n\
+             |asd|     |\n\
+             |   | asd |\n\
+             |   |     |\n\
+             +---+-----+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_padding_fill_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(
+            Entity::Column(1),
+            Settings::new().padding(Padding::new(2, 1, 1, 0).fill('.')),
+        );
+        assert_eq!(
+            grid.to_string(),
+            "+---+------+\n\
+             |asd|......|\n\
+             |   |..asd.|\n\
+             +---+------+\n\
+             |asd|......|\n\
+             |   |..asd.|\n\
+             +---+------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_vertical_resize_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Cell(1, 1), Settings::new().text("asd     "));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+--------+\n\
+             |asd|asd     |\n\
+             +---+--------+\n\
+             |asd|asd     |\n\
+             +---+--------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_without_frame_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.get_border_mut(0).empty().inner(Some(' '), None, None);
+        grid.get_border_mut(1).empty().inner(Some(' '), None, None);
+
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "asd asd\n\
+             asd asd\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_custom_border_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+
+        grid.get_border_mut(0)
+            .top('*', ' ', Some(' '), Some(' '))
+            .inner(Some('@'), Some('$'), Some('%'));
+        grid.get_border_mut(1)
+            .top('*', ' ', Some(' '), Some(' '))
+            .bottom('*', ' ', Some(' '), Some(' '))
+            .inner(Some('^'), Some('#'), Some('!'));
+
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            " *** *** \n\
+             $asd@asd%\n\
+             +---+---+\n\
+             #asd^asd!\n\
+             \u{0020}*** *** \n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_remove_row_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.remove_row(0).unwrap();
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_remove_column_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.remove_column(0).unwrap();
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+\n\
+             |asd|\n\
+             +---+\n\
+             |asd|\n\
+             +---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_3x2_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_provider_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set_provider(Entity::Column(1), |row, column| {
+            format!("{}:{}", row, column)
+        });
+        grid.set_provider(Entity::Cell(1, 1), |_, _| "cell".to_owned());
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+----+\n\
+             |asd|0:1 |\n\
+             +---+----+\n\
+             |asd|cell|\n\
+             +---+----+\n"
+        )
+    }
+
+    #[test]
+    fn grid_2x2_provider_overridden_by_text_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set_provider(Entity::Row(0), |_, _| "provided".to_owned());
+        grid.set(Entity::Row(0), Settings::new().text("asd"));
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "+---+---+\n\
+             |asd|asd|\n\
+             +---+---+\n\
+             |   |   |\n\
+             +---+---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_push_row_keeps_cached_widths_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.to_string();
+
+        grid.push_row();
+        grid.set(Entity::Cell(1, 1), Settings::new().text("qwerty"));
+
+        assert_eq!(grid.dimensions.lock().widths, vec![Some(3), Some(6)]);
+        assert_eq!(
+            grid.to_string(),
+            "+---+------+\n\
+             |asd|asd   |\n\
+             +---+------+\n\
+             |   |qwerty|\n\
+             +---+------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_span_even_distribution_test() {
+        let mut grid = Grid::new(2, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(Entity::Cell(1, 0), Settings::new().text("qwertyuio"));
+        grid.set_column_span(1, 0, 3).unwrap();
+        grid.set_span_distribution(SpanDistribution::Even);
+
+        assert_eq!(
+            grid.to_string(),
+            "+---+--+--+\n\
+             |a  |a |a |\n\
+             +---+--+--+\n\
+             |qwertyuio|\n\
+             +---------+\n"
+        );
+    }
+
+    #[test]
+    fn grid_span_is_shrunk_on_remove_column_test() {
+        let mut grid = Grid::new(1, 3);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set_column_span(0, 0, 3).unwrap();
+        grid.remove_column(1).unwrap();
+
+        assert_eq!(grid.get_column_span(0, 0), 2);
+        assert_eq!(grid.to_string(), "+---+\n|asd|\n+---+\n");
+
+        grid.remove_column(0).unwrap();
+        assert_eq!(grid.get_column_span(0, 0), 1);
+    }
+
+    #[test]
+    fn grid_row_span_makes_last_row_higher_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(Entity::Cell(0, 1), Settings::new().text("1\n2\n3\n4\n5"));
+        grid.set_row_span(0, 1, 2).unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            "+-+-+\n\
+             |a|1|\n\
+             +-+2|\n\
+             |a|3|\n\
+             | |4|\n\
+             | |5|\n\
+             +-+-+\n\
+             |a|a|\n\
+             +-+-+\n"
+        );
+    }
+
+    #[test]
+    fn grid_row_span_is_measured_again_when_a_line_is_hidden_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(Entity::Cell(0, 0), Settings::new().text("a\nb\nc"));
+        grid.set_row_span(0, 0, 2).unwrap();
+        grid.to_string();
+
+        grid.set_horizontal_line(1, HorizontalLine::empty())
+            .unwrap();
+
+        let mut expected = Grid::new(2, 2);
+        expected.set(Entity::Global, Settings::new().text("a"));
+        expected.set(Entity::Cell(0, 0), Settings::new().text("a\nb\nc"));
+        expected.set_row_span(0, 0, 2).unwrap();
+        expected
+            .set_horizontal_line(1, HorizontalLine::empty())
+            .unwrap();
+
+        assert_eq!(grid.to_string(), expected.to_string());
+        assert!(grid.to_string().contains("|c|"));
+    }
+
+    #[test]
+    fn grid_swap_rows_removes_split_row_span_test() {
+        let mut grid = Grid::new(3, 2);
+        for row in 0..3 {
+            for column in 0..2 {
+                let text = format!("r{}c{}", row, column);
+                grid.set(Entity::Cell(row, column), Settings::new().text(text));
+            }
+        }
+        grid.set_row_span(0, 0, 2).unwrap();
+        grid.set_row_span(0, 1, 3).unwrap();
+
+        grid.swap_rows(0, 2).unwrap();
+
+        assert_eq!(grid.get_row_span(0, 0), 1);
+        assert_eq!(grid.get_row_span(2, 0), 1);
+        assert_eq!(grid.get_row_span(0, 1), 3);
+        assert_eq!(
+            grid.to_string(),
+            "+----+----+\n\
+             |r2c0|r2c1|\n\
+             +----+    |\n\
+             |r1c0|    |\n\
+             +----+    |\n\
+             |r0c0|    |\n\
+             +----+----+\n"
+        );
+    }
+
+    #[test]
+    fn grid_row_span_is_resized_with_rows_test() {
+        let mut grid = Grid::new(3, 1);
+        grid.set_row_span(0, 0, 2).unwrap();
+
+        grid.insert_row(1).unwrap();
+        assert_eq!(grid.get_row_span(0, 0), 3);
+
+        grid.remove_row(0).unwrap();
+        assert_eq!(grid.get_row_span(0, 0), 1);
+
+        grid.set_row_span(1, 0, 2).unwrap();
+        grid.remove_row(0).unwrap();
+        assert_eq!(grid.get_row_span(0, 0), 2);
+    }
+
+    #[test]
+    fn grid_span_out_of_range_test() {
+        let mut grid = Grid::new(1, 3);
+        assert_eq!(
+            grid.set_column_span(0, 1, 3),
+            Err(Error::SpanOutOfRange {
+                column: 1,
+                span: 3,
+                count_columns: 3
+            })
+        );
+    }
+
+    #[test]
+    fn grid_border_line_without_intersection_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.get_border_mut(0)
+            .empty()
+            .top_line('-', None, Some('+'), Some('+'))
+            .bottom_line('=', None, Some('+'), Some('+'))
+            .inner(None, Some('|'), Some('|'));
+
+        assert_eq!(grid.to_string(), "+------+\n|asdasd|\n+======+\n");
+    }
+
+    #[test]
+    fn grid_appender_measures_only_appended_rows_test() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("id"));
+        let counter = calls.clone();
+        grid.set_provider(Entity::Cell(0, 1), move |_, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            String::from("name")
+        });
+
+        let appender = grid.appender();
+        assert_eq!(appender.render(&grid, 0), "+--+----+\n|id|name|\n");
+        calls.store(0, Ordering::SeqCst);
+
+        grid.push_row();
+        grid.set(Entity::Cell(1, 0), Settings::new().text("1"));
+        grid.set(Entity::Cell(1, 1), Settings::new().text("a long name"));
+        assert_eq!(
+            appender.render(&grid, 1),
+            "+--+----+\n\
+             |1 |a   |\n\
+             |  |long|\n\
+             |  |name|\n"
+        );
+        assert_eq!(appender.render_bottom_line(&grid), "+--+----+\n");
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        grid.push_column();
+        assert_eq!(appender.render(&grid, 1), "");
+    }
+
+    #[test]
+    fn grid_span_overlap_test() {
+        let mut grid = Grid::new(3, 3);
+        grid.set_row_span(0, 0, 2).unwrap();
+        grid.set_column_span(2, 0, 2).unwrap();
+
+        assert_eq!(
+            grid.set_column_span(1, 0, 2),
+            Err(Error::SpanOverlap { row: 1, column: 0 })
+        );
+        assert_eq!(
+            grid.set_column_span(0, 0, 2),
+            Err(Error::SpanOverlap { row: 0, column: 0 })
+        );
+        assert_eq!(
+            grid.set_row_span(1, 1, 2),
+            Err(Error::SpanOverlap { row: 2, column: 1 })
+        );
+        assert_eq!(
+            grid.set_column_span(2, 1, 2),
+            Err(Error::SpanOverlap { row: 2, column: 1 })
+        );
+
+        grid.set_row_span(0, 0, 3).unwrap_err();
+        grid.set_column_span(2, 0, 3).unwrap();
+        assert_eq!(grid.get_column_span(2, 0), 3);
+        assert_eq!(grid.get_row_span(0, 0), 2);
+        assert!(grid.is_spanned(1, 0));
+        assert!(!grid.is_spanned(1, 1));
+    }
+
+    #[test]
+    fn grid_estimation_is_invalidated_on_change_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.to_string();
+
+        assert_eq!(
+            grid.dimensions.lock().estimation,
+            Some((vec![3, 3], vec![1]))
+        );
+
+        grid.set(Entity::Cell(0, 1), Settings::new().text("qwerty"));
+        assert_eq!(grid.dimensions.lock().estimation, None);
+        assert_eq!(grid.estimate_dimensions(), (vec![3, 6], vec![1]));
+
+        grid.set_column_width(1, 2).unwrap();
+        assert_eq!(grid.dimensions.lock().estimation, None);
+        assert_eq!(grid.estimate_dimensions(), (vec![3, 2], vec![3]));
+    }
+
+    #[test]
+    fn grid_estimation_is_invalidated_on_provider_change_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        assert_eq!(grid.to_string(), "+-+-+\n|a|a|\n+-+-+\n");
+
+        grid.set_display(0, 1, "xyz").unwrap();
+        assert_eq!(grid.to_string(), "+-+---+\n|a|xyz|\n+-+---+\n");
+
+        grid.remove_provider(&Entity::Cell(0, 1));
+        assert_eq!(grid.to_string(), "+-+-+\n|a|a|\n+-+-+\n");
+    }
+
+    #[test]
+    fn grid_insert_row_shifts_settings_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(
+            Entity::Row(1),
+            Settings::new().text("qwe").alignment(Alignment::Right),
+        );
+        grid.set_provider(Entity::Cell(1, 0), |row, _| row.to_string());
+        grid.set_row_metadata(1, RowMetadata::new().id("1"))
+            .unwrap();
+        grid.to_string();
+
+        grid.insert_row(1).unwrap();
+        grid.set(Entity::Cell(1, 1), Settings::new().text("zxcvbn"));
+
+        assert_eq!(grid.get_row_metadata(1), None);
+        assert_eq!(grid.get_row_metadata(2).and_then(|m| m.get_id()), Some("1"));
+        assert_eq!(
+            grid.to_string(),
+            "+---+------+\n\
+             |asd|asd   |\n\
+             +---+------+\n\
+             |   |zxcvbn|\n\
+             +---+------+\n\
+             |  2|   qwe|\n\
+             +---+------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_insert_column_shifts_settings_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(
+            Entity::Column(0),
+            Settings::new().text("qwe").alignment(Alignment::Right),
+        );
+        grid.set(Entity::Cell(0, 0), Settings::new().text("qwerty"));
+        grid.set_column_span(1, 0, 2).unwrap();
+        grid.to_string();
+
+        grid.insert_column(0).unwrap();
+        grid.set(Entity::Column(0), Settings::new().text("#"));
+
+        assert_eq!(grid.get_column_span(1, 1), 2);
+        assert_eq!(
+            grid.to_string(),
+            "+-+------+---+\n\
+             |#|qwerty|asd|\n\
+             +-+------+---+\n\
+             |#|       qwe|\n\
+             +-+----------+\n"
+        )
+    }
+
+    #[test]
+    fn grid_swap_rows_moves_settings_test() {
+        let mut grid = Grid::new(3, 1);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(Entity::Row(2), Settings::new().text("qwe"));
+        grid.set_row_metadata(2, RowMetadata::new().id("2"))
+            .unwrap();
+        grid.to_string();
+
+        grid.swap_rows(2, 0).unwrap();
+
+        assert_eq!(grid.get_row_metadata(0).and_then(|m| m.get_id()), Some("2"));
+        assert_eq!(grid.get_row_metadata(2), None);
+        assert_eq!(
+            grid.to_string(),
+            "+---+\n\
+             |qwe|\n\
+             +---+\n\
+             |a  |\n\
+             +---+\n\
+             |a  |\n\
+             +---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_clone_and_eq_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Row(1), Settings::new().alignment(Alignment::Right));
+        grid.set_provider(Entity::Cell(0, 0), |_, _| String::from("qwe"));
+        grid.set_estimator(ExactWidths(vec![4, 4]));
+
+        let copy = grid.clone();
+        assert_eq!(copy, grid);
+        assert_eq!(copy.to_string(), grid.to_string());
+
+        // a provider with the same output is a different function
+        let mut other = grid.clone();
+        other.set_provider(Entity::Cell(0, 0), |_, _| String::from("qwe"));
+        assert_ne!(other, grid);
+
+        let mut other = grid.clone();
+        other.set(Entity::Row(1), Settings::new().alignment(Alignment::Left));
+        assert_ne!(other, grid);
+
+        let debug = format!("{:?}", grid);
+        assert!(debug.starts_with("Grid { size: (2, 2), cells: "));
+        assert!(debug.contains("providers: [Cell(0, 0)]"));
+        assert!(debug.contains("estimator: true"));
+    }
+
+    #[test]
+    fn grid_swap_columns_moves_settings_test() {
+        let mut grid = Grid::new(1, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(
+            Entity::Column(2),
+            Settings::new().text("qwe").alignment(Alignment::Right),
+        );
+        grid.set_column_width(0, 4).unwrap();
+        grid.to_string();
+
+        grid.swap_columns(2, 0).unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            "+---+-+----+\n|qwe|a|a   |\n+---+-+----+\n"
+        )
+    }
+
+    #[test]
+    fn grid_remove_row_shifts_settings_test() {
+        let mut grid = Grid::new(3, 1);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(
+            Entity::Row(2),
+            Settings::new().text("q").alignment(Alignment::Right),
+        );
+        grid.set_cell_border(2, 0, CellBorder::new().left('*'))
+            .unwrap();
+
+        grid.remove_row(1).unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            "+---+\n\
+             |asd|\n\
+             +---+\n\
+             *  q|\n\
+             +---+\n"
+        )
+    }
+
+    #[test]
+    fn grid_remove_last_row_keeps_bottom_line_test() {
+        let mut grid = Grid::new(3, 1);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.get_border_mut(1)
+            .bottom('-', '+', Some('+'), Some('+'));
+        grid.get_border_mut(2)
+            .bottom('=', '+', Some('+'), Some('+'));
+
+        grid.remove_row(2).unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            "+---+\n\
+             |asd|\n\
+             +---+\n\
+             |asd|\n\
+             +===+\n"
+        )
+    }
+
+    #[test]
+    fn grid_push_row_moves_bottom_line_test() {
+        let mut grid = Grid::new(2, 1);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.get_border_mut(1)
+            .bottom('=', '+', Some('+'), Some('+'));
+
+        grid.push_row();
+
+        assert_eq!(
+            grid.to_string(),
+            "+---+\n\
+             |asd|\n\
+             +---+\n\
+             |asd|\n\
+             +---+\n\
+             |   |\n\
+             +===+\n"
+        )
+    }
+
+    #[test]
+    fn grid_remove_last_of_two_rows_keeps_bottom_line_test() {
+        let mut grid = Grid::new(2, 1);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.get_border_mut(1)
+            .bottom('=', '+', Some('+'), Some('+'));
+
+        grid.remove_row(1).unwrap();
+
+        assert_eq!(grid.to_string(), "+---+\n|asd|\n+===+\n")
+    }
+
+    #[test]
+    fn grid_push_and_remove_column_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(
+            Entity::Column(1),
+            Settings::new().text("qwe").alignment(Alignment::Right),
+        );
+        grid.to_string();
+
+        grid.push_column();
+        grid.set(Entity::Cell(0, 2), Settings::new().text("zx"));
+        assert_eq!(grid.to_string(), "+-+---+--+\n|a|qwe|zx|\n+-+---+--+\n");
+
+        grid.remove_column(0).unwrap();
+        grid.set(Entity::Cell(0, 1), Settings::new().text("z"));
+        assert_eq!(grid.to_string(), "+---+-+\n|qwe|z|\n+---+-+\n");
+    }
+
+    #[test]
+    fn grid_resize_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set(
+            Entity::Cell(0, 1),
+            Settings::new().text("qwe").alignment(Alignment::Right),
+        );
+        grid.set_row_metadata(1, RowMetadata::new().id("1"))
+            .unwrap();
+
+        grid.resize(1, 3);
+        assert_eq!(grid.get_row_metadata(1), None);
+        assert_eq!(grid.to_string(), "+-+---++\n|a|qwe||\n+-+---++\n");
+
+        grid.resize(2, 1);
+        grid.set(Entity::Cell(1, 0), Settings::new().text("zxc"));
+        assert_eq!(
+            grid.to_string(),
+            "+---+\n\
+             |a  |\n\
+             +---+\n\
+             |zxc|\n\
+             +---+\n"
+        );
+
+        grid.resize(0, 0);
+        assert_eq!(grid.to_string(), "");
+        assert_eq!((grid.count_rows(), grid.count_columns()), (0, 0));
+    }
+
+    #[test]
+    fn grid_from_rows_test() {
+        let rows = vec![
+            vec![String::from("asd"), String::from("qwe")],
+            vec![String::from("zxcvb")],
+        ];
+        let mut grid = Grid::from(rows);
+        assert_eq!((grid.count_rows(), grid.count_columns()), (2, 2));
+        assert_eq!(
+            grid.to_string(),
+            "+-----+---+\n\
+             |asd  |qwe|\n\
+             +-----+---+\n\
+             |zxcvb|   |\n\
+             +-----+---+\n"
+        );
+
+        grid.set(Entity::Cell(1, 1), Settings::new().text("1"));
+        assert_eq!(grid[(1, 1)], "1");
+
+        let grid = Grid::from_rows(Vec::<Vec<&str>>::new());
+        assert_eq!((grid.count_rows(), grid.count_columns()), (0, 0));
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn grid_forced_width_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set_column_width(1, 6).unwrap();
+
+        assert_eq!(grid.total_width(), 12);
+        assert_eq!(
+            grid.to_string(),
+            "+---+------+\n\
+             |asd|asd   |\n\
+             +---+------+\n\
+             |asd|asd   |\n\
+             +---+------+\n"
+        );
+
+        grid.remove_column_width(1);
+        assert_eq!(grid.total_width(), 9);
+    }
+
+    #[test]
+    fn grid_forced_height_test() {
+        let mut grid = Grid::new(2, 1);
+        grid.set(Entity::Global, Settings::new().text("a\nb\nc"));
+        grid.set_row_height(0, 2).unwrap();
+        grid.set_row_height(1, 4).unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            "+-+\n\
+             |a|\n\
+             |b|\n\
+             +-+\n\
+             |a|\n\
+             |b|\n\
+             |c|\n\
+             | |\n\
+             +-+\n"
+        );
+    }
+
+    #[test]
+    fn grid_too_small_size_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new().text("asd").ident(1, 2, 1, 0),
+        );
+
+        assert_eq!(
+            grid.set_column_width(0, 3),
+            Err(Error::WidthTooSmall {
+                column: 0,
+                width: 3,
+                min_width: 4
+            })
+        );
+        assert_eq!(
+            grid.set_row_height(1, 0),
+            Err(Error::HeightTooSmall {
+                row: 1,
+                height: 0,
+                min_height: 2
+            })
+        );
+        assert_eq!(
+            grid.set_column_width(2, 10),
+            Err(Error::ColumnOutOfRange {
+                column: 2,
+                count_columns: 2
+            })
+        );
+
+        grid.set_size_policy(SizePolicy::Clamp);
+        grid.set_column_width(0, 0).unwrap();
+        grid.set_row_height(0, 0).unwrap();
+        grid.remove_row(1).unwrap();
+        assert_eq!(
+            grid.to_string(),
+            "+----+------+\n\
+             |    |      |\n\
+             | a  | asd  |\n\
+             +----+------+\n"
+        );
+    }
+
+    #[test]
+    fn grid_forced_width_follows_padding_test() {
+        let mut grid = Grid::new(1, 1);
+        grid.set(Entity::Global, Settings::new().text("as"));
+        grid.set_column_width(0, 1).unwrap();
+
+        // the padding is set after the width so the width is raised at rendering
+        grid.set(Entity::Global, Settings::new().ident(1, 1, 0, 0));
+        assert_eq!(grid.to_string(), "+---+\n| a |\n| s |\n+---+\n");
+    }
+
+    #[test]
+    fn grid_bulk_settings_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new().text("asd").ident(1, 1, 0, 0),
+        );
+        grid.set(Entity::Cell(0, 1), Settings::new().text("qwerty"));
+        grid.set_column_alignment(1, Alignment::Right).unwrap();
+        // a setting of a column takes precedence over a setting of a row
+        grid.set_row_padding(2, Padding::new(0, 0, 1, 0)).unwrap();
+        grid.set_row_border(1, CellBorder::new().left('*')).unwrap();
+        grid.set_column_border(1, CellBorder::new().left('#'))
+            .unwrap();
+        grid.set_cell_border(2, 1, CellBorder::new().left('@'))
+            .unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            "+-----+--------+\n\
+             | asd # qwerty |\n\
+             +-----+--------+\n\
+             * asd #    asd |\n\
+             +-----+--------+\n\
+             |     @        |\n\
+             |asd  @     asd|\n\
+             +-----+--------+\n"
+        );
+    }
+
+    #[test]
+    fn grid_column_setting_takes_precedence_over_row_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Cell(0, 0), Settings::new().text("qwerty"));
+        grid.set_row_alignment(1, Alignment::Center).unwrap();
+        grid.set_column_alignment(0, Alignment::Right).unwrap();
+        grid.set_row_padding(1, Padding::new(1, 0, 0, 0)).unwrap();
+        grid.set_column_padding(0, Padding::new(0, 1, 0, 0))
+            .unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            "+-------+----+\n\
+             |qwerty |asd |\n\
+             +-------+----+\n\
+             |   asd | asd|\n\
+             +-------+----+\n"
+        );
+    }
+
+    #[test]
+    fn grid_layered_settings_test() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(
+            Entity::Global,
+            Settings::new().text("asd").ident(1, 1, 0, 0),
+        );
+        grid.set(Entity::Cell(0, 0), Settings::new().text("qwerty"));
+        grid.set(
+            Entity::Column(0),
+            Settings::new().alignment(Alignment::Right),
+        );
+        grid.set(Entity::Row(1), Settings::new().alignment(Alignment::Center));
+
+        // a later global change doesn't reset a padding, and an alignment of a column wins over a row
+        grid.set(Entity::Global, Settings::new().alignment(Alignment::Right));
+
+        assert_eq!(
+            grid.to_string(),
+            "+--------+-----+\n\
+             | qwerty | asd |\n\
+             +--------+-----+\n\
+             |    asd | asd |\n\
+             +--------+-----+\n"
+        );
+    }
+
+    #[test]
+    fn grid_vertical_alignment_test() {
+        let mut grid = Grid::new(1, 3);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("1\n2\n3\n4"));
+        grid.set(
+            Entity::Cell(0, 1),
+            Settings::new()
+                .text("c")
+                .vertical_alignment(AlignmentVertical::Center),
+        );
+        grid.set(
+            Entity::Cell(0, 2),
+            Settings::new()
+                .text("b")
+                .vertical_alignment(AlignmentVertical::Bottom),
+        );
+
+        assert_eq!(
+            grid.to_string(),
+            "+-+-+-+\n\
+             |1| | |\n\
+             |2|c| |\n\
+             |3| | |\n\
+             |4| |b|\n\
+             +-+-+-+\n"
+        );
+    }
+
+    #[test]
+    fn grid_bulk_settings_out_of_range_test() {
+        let mut grid = Grid::new(1, 1);
+        let row_error = Error::RowOutOfRange {
+            row: 1,
+            count_rows: 1,
+        };
+        let column_error = Error::ColumnOutOfRange {
+            column: 1,
+            count_columns: 1,
+        };
+
+        assert_eq!(grid.set_row_alignment(1, Alignment::Left), Err(row_error));
+        assert_eq!(grid.set_row_padding(1, Padding::default()), Err(row_error));
+        assert_eq!(grid.set_row_border(1, CellBorder::new()), Err(row_error));
+        assert_eq!(
+            grid.set_column_alignment(1, Alignment::Left),
+            Err(column_error)
+        );
+        assert_eq!(
+            grid.set_column_padding(1, Padding::default()),
+            Err(column_error)
+        );
+        assert_eq!(
+            grid.set_column_border(1, CellBorder::new()),
+            Err(column_error)
+        );
+    }
+
+    #[test]
+    fn grid_shrink_widest_cell_test() {
+        let mut grid = Grid::new(2, 1);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set(Entity::Cell(1, 0), Settings::new().text("qwerty\nzxc"));
+        grid.to_string();
+
+        grid.set(Entity::Cell(1, 0), Settings::new().text("qw"));
+        grid.set(Entity::Cell(0, 0), Settings::new().text("a"));
+
+        assert_eq!(
+            grid.to_string(),
+            "+--+\n\
+             |a |\n\
+             +--+\n\
+             |qw|\n\
+             +--+\n"
+        )
+    }
+
+    #[test]
+    fn grid_cell_border_test() {
+        let mut grid = Grid::new(3, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set_cell_border(1, 0, CellBorder::new().top('*').left('!'))
+            .unwrap();
+        grid.set_cell_border(1, 0, CellBorder::new().top_left_corner('#'))
+            .unwrap();
+        grid.set_cell_border(2, 1, CellBorder::new().bottom('='))
+            .unwrap();
+        grid.remove_row(0).unwrap();
+        let str = grid.to_string();
+        assert_eq!(
+            str,
+            "#***+---+\n\
+             !asd|asd|\n\
+             +---+---+\n\
+             |asd|asd|\n\
+             +---+===+\n"
         )
-    });
-
-    let mut complete_text =
-        Vec::with_capacity(aligned_text.len() + style.ident.top + style.ident.bottom);
-    complete_text.extend(iter::repeat(" ".repeat(column_w)).take(style.ident.top));
-    complete_text.extend(aligned_text);
-    complete_text.extend(iter::repeat(" ".repeat(column_w)).take(style.ident.bottom));
-
-    complete_text
-}
-
-fn build_line(
-    f: &mut std::fmt::Formatter<'_>,
-    cells_width: &[usize],
-    border: &LineStyle,
-) -> fmt::Result {
-    if border.is_empty() {
-        return Ok(());
     }
 
-    write_option(f, border.left_intersection)?;
+    #[test]
+    fn grid_total_size_matches_rendered_grid_test() {
+        let decorations: Vec<fn(&mut Grid)> = vec![
+            |_| {},
+            |grid| grid.set_caption(CaptionPosition::Top, "a wide caption", Alignment::Center),
+            |grid| grid.set_caption(CaptionPosition::Bottom, "a\nwide caption", Alignment::Right),
+            |grid| grid.set_caption(CaptionPosition::Top, "ok", Alignment::Left),
+            |grid| grid.push_legend_entry("**", "a wide legend entry"),
+            |grid| grid.push_legend_entry("*", "x"),
+            |grid| grid.set_margin(Margin::new(2, 1, 1, 1)),
+            |grid| grid.set_placement(Alignment::Left, 30),
+            |grid| grid.set_placement(Alignment::Center, 31),
+            |grid| grid.set_placement(Alignment::Right, 30),
+            |grid| grid.set_placement(Alignment::Right, 3),
+            |grid| {
+                grid.set_caption(CaptionPosition::Top, "a wide caption", Alignment::Left);
+                grid.push_legend_entry("*", "a wide legend entry");
+                grid.set_margin(Margin::new(1, 2, 1, 0));
+                grid.set_placement(Alignment::Center, 40);
+            },
+        ];
 
-    for (i, w) in cells_width.iter().enumerate() {
-        write_option(f, border.main.map(|m| m.to_string().repeat(*w)))?;
+        for (i, decorate) in decorations.iter().enumerate() {
+            let mut grid = Grid::new(2, 2);
+            grid.set(Entity::Global, Settings::new().text("asd"));
+            decorate(&mut grid);
 
-        if i != cells_width.len() - 1 {
-            write_option(f, border.intersection)?;
+            let table = grid.to_string();
+            let width = table.lines().map(string_width).max().unwrap_or(0);
+            assert_eq!(grid.total_width(), width, "decoration {}", i);
+            assert_eq!(
+                grid.total_height(),
+                table.lines().count(),
+                "decoration {}",
+                i
+            );
+            assert_eq!(
+                grid.table_width(),
+                9 + grid.get_margin().left + grid.get_margin().right
+            );
         }
     }
 
-    write_option(f, border.right_intersection)?;
-
-    writeln!(f)?;
-
-    Ok(())
-}
-
-fn write_option<D: Display>(f: &mut std::fmt::Formatter<'_>, text: Option<D>) -> fmt::Result {
-    match text {
-        Some(text) => write!(f, "{}", text),
-        None => Ok(()),
+    #[test]
+    fn grid_margin_with_legend_test() {
+        let mut grid = Grid::new(1, 2);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.push_legend_entry("*", "x");
+        grid.set_margin(Margin::new(1, 1, 0, 1));
+        assert_eq!(grid.total_width(), 11);
+        assert_eq!(
+            grid.to_string(),
+            " +---+---+ \n\
+             \x20|asd|asd| \n\
+             \x20+---+---+ \n\
+             \x20Legend:   \n\
+             \x20  * x     \n\
+             \x20          \n"
+        )
     }
-}
 
-fn split_text(text: &str, width: usize, height: usize) -> Vec<Cow<str>> {
-    let mut lines = textwrap::wrap(text, width);
-    while lines.len() < height {
-        lines.push(str::repeat(" ", width).into())
-    }
+    #[test]
+    fn grid_horizontal_lines_shift_test() {
+        let mut grid = Grid::new(3, 1);
+        grid.set(Entity::Global, Settings::new().text("asd"));
+        grid.set_horizontal_line(1, HorizontalLine::new('=', '+', Some('+'), Some('+')))
+            .unwrap();
+        grid.set_horizontal_line(2, HorizontalLine::empty())
+            .unwrap();
+        grid.set_horizontal_line(3, HorizontalLine::new('~', '+', Some('+'), Some('+')))
+            .unwrap();
 
-    lines
-}
+        grid.insert_row(1).unwrap();
+        grid.set(Entity::Row(1), Settings::new().text("qwe"));
+        assert_eq!(
+            grid.to_string(),
+            "+---+\n\
+             |asd|\n\
+             +===+\n\
+             |qwe|\n\
+             +---+\n\
+             |asd|\n\
+             |asd|\n\
+             +~~~+\n"
+        );
 
-#[cfg(not(feature = "color"))]
-fn string_width(text: &str) -> usize {
-    real_string_width(text)
-}
+        grid.remove_row(3).unwrap();
+        grid.push_row();
+        assert_eq!(
+            grid.to_string(),
+            "+---+\n\
+             |asd|\n\
+             +===+\n\
+             |qwe|\n\
+             +---+\n\
+             |asd|\n\
+             +---+\n\
+             |   |\n\
+             +~~~+\n"
+        );
 
-#[cfg(feature = "color")]
-fn string_width(text: &str) -> usize {
-    let b = strip_ansi_escapes::strip(text.as_bytes()).unwrap();
-    let s = std::str::from_utf8(&b).unwrap();
-    real_string_width(&s)
-}
+        assert_eq!(
+            grid.set_horizontal_line(5, HorizontalLine::empty()),
+            Err(Error::LineOutOfRange {
+                line: 5,
+                count_lines: 5
+            })
+        );
+    }
 
-fn real_string_width(text: &str) -> usize {
-    text.lines()
-        .map(|line| textwrap::core::display_width(line))
-        .max()
-        .unwrap_or_else(|| 0)
-}
+    #[test]
+    fn grid_vertical_lines_shift_test() {
+        let mut grid = Grid::new(1, 3);
+        grid.set(Entity::Global, Settings::new().text("a"));
+        grid.set_vertical_line(1, VerticalLine::empty()).unwrap();
+        grid.set_vertical_line(3, VerticalLine::new('#', '#', Some('#'), Some('#')))
+            .unwrap();
+        assert_eq!(grid.to_string(), "+--+-#\n|aa|a#\n+--+-#\n");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        grid.remove_column(2).unwrap();
+        grid.push_column();
+        grid.set(Entity::Column(2), Settings::new().text("b"));
+        assert_eq!(grid.to_string(), "+--+-#\n|aa|b#\n+--+-#\n");
 
-    #[test]
-    fn grid_1x1_test() {
-        let mut grid = Grid::new(1, 1);
-        grid.set(Entity::Cell(0, 0), Settings::new().text("asd"));
-        let str = grid.to_string();
         assert_eq!(
-            str,
-            "+---+\n\
-             |asd|\n\
-             +---+\n"
-        )
+            grid.set_vertical_line(4, VerticalLine::empty()),
+            Err(Error::LineOutOfRange {
+                line: 4,
+                count_lines: 4
+            })
+        );
     }
 
     #[test]
-    fn grid_2x2_test() {
+    fn grid_horizontal_line_text_test() {
         let mut grid = Grid::new(2, 2);
         grid.set(Entity::Global, Settings::new().text("asd"));
-        let str = grid.to_string();
+        grid.get_border_mut(0).empty().inner(Some('|'), None, None);
+        grid.get_border_mut(1).empty().inner(Some('|'), None, None);
+        grid.override_horizontal_line_text(1, "Section A is long")
+            .unwrap();
+        grid.override_horizontal_line_text(2, "B").unwrap();
         assert_eq!(
-            str,
-            "+---+---+\n\
-             |asd|asd|\n\
-             +---+---+\n\
-             |asd|asd|\n\
-             +---+---+\n"
-        )
+            grid.to_string(),
+            "asd|asd\n\
+             Section\n\
+             asd|asd\n\
+             B      \n"
+        );
+
+        grid.remove_horizontal_line_text(2);
+        grid.remove_row(0).unwrap();
+        assert_eq!(grid.to_string(), "asd|asd\n");
     }
 
     #[test]
-    fn grid_2x2_entity_settings_test() {
+    fn grid_write_to_test() {
         let mut grid = Grid::new(2, 2);
         grid.set(Entity::Global, Settings::new().text("asd"));
-        grid.set(Entity::Column(0), Settings::new().text("zxc"));
-        grid.set(Entity::Row(0), Settings::new().text("qwe"));
-        grid.set(Entity::Cell(1, 1), Settings::new().text("iop"));
-        let str = grid.to_string();
-        assert_eq!(
-            str,
-            "+---+---+\n\
-             |qwe|qwe|\n\
-             +---+---+\n\
-             |zxc|iop|\n\
-             +---+---+\n"
-        )
+        grid.push_legend_entry("*", "x");
+        grid.set_margin(Margin::new(1, 1, 1, 1));
+
+        let mut output = Vec::new();
+        grid.write_to(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), grid.to_string());
+
+        let mut output = String::new();
+        grid.write_fmt_to(&mut output).unwrap();
+        assert_eq!(output, grid.to_string());
+
+        let mut output = [0; 4];
+        let result = grid.write_to(&mut &mut output[..]);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::WriteZero);
     }
 
     #[test]
-    fn grid_2x2_alignment_test() {
-        let mut grid = Grid::new(2, 2);
-        grid.set(Entity::Global, Settings::new().text("asd    "));
+    fn grid_sanitization_test() {
+        let mut grid = Grid::new(1, 2);
         grid.set(
-            Entity::Column(0),
-            Settings::new().alignment(Alignment::Left),
+            Entity::Cell(0, 0),
+            Settings::new().text("\u{1b}[2J\u{1b}[1;1Hasd\r\u{8}"),
         );
         grid.set(
-            Entity::Column(1),
-            Settings::new().alignment(Alignment::Right),
+            Entity::Cell(0, 1),
+            Settings::new().text("\u{1b}[31mqwe\u{1b}[0m\u{1b}]2;title\u{1b}\\"),
         );
         let str = grid.to_string();
-        assert_eq!(
-            str,
-            "+-------+-------+\n\
-             |asd    |    asd|\n\
-             +-------+-------+\n\
-             |asd    |    asd|\n\
-             +-------+-------+\n"
-        )
+        assert_eq!(str, "+---+---+\n|asd|\u{1b}[31mqwe\u{1b}[0m|\n+---+---+\n");
+
+        grid.set_sanitization(Sanitization::Off);
+        assert!(grid.to_string().contains("\u{1b}[2J"));
     }
 
     #[test]
-    fn grid_2x2_ident_test() {
-        let mut grid = Grid::new(2, 2);
-        grid.set(
-            Entity::Global,
-            Settings::new().text("asd").ident(1, 1, 1, 1),
-        );
-        grid.set(Entity::Column(0), Settings::new().ident(0, 0, 0, 0));
-        let str = grid.to_string();
+    fn grid_sanitization_line_endings_test() {
+        let mut grid = Grid::new(1, 1);
+        grid.set(Entity::Global, Settings::new().text("asd\r\nqw\u{202e}e\r"));
+        assert_eq!(grid.to_string(), "+---+\n|asd|\n|qwe|\n+---+\n");
+
+        grid.set_sanitization(Sanitization::Replace('?'));
+        assert_eq!(grid.to_string(), "+-----+\n|asd  |\n|qw?e?|\n+-----+\n");
+
+        grid.set_sanitization(Sanitization::Escape);
         assert_eq!(
-            str,
-            "+---+-----+\n\
-             |asd|     |\n\
-             |   | asd |\n\
-             |   |     |\n\
-             +---+-----+\n\
-             |asd|     |\n\
-             |   | asd |\n\
-             |   |     |\n\
-             +---+-----+\n"
-        )
+            grid.to_string(),
+            "+-------------+\n\
+             |asd          |\n\
+             |qw\\u{202e}e\\r|\n\
+             +-------------+\n"
+        );
     }
 
     #[test]
-    fn grid_2x2_vertical_resize_test() {
-        let mut grid = Grid::new(2, 2);
-        grid.set(Entity::Global, Settings::new().text("asd"));
-        grid.set(Entity::Cell(1, 1), Settings::new().text("asd     "));
-        let str = grid.to_string();
+    fn grid_tab_expansion_test() {
+        let mut grid = Grid::new(2, 1);
+        grid.set(Entity::Cell(0, 0), Settings::new().text("ab\tc\n\td"));
+        grid.set(Entity::Cell(1, 0), Settings::new().text("abcd\te"));
         assert_eq!(
-            str,
-            "+---+--------+\n\
-             |asd|asd     |\n\
-             +---+--------+\n\
-             |asd|asd     |\n\
-             +---+--------+\n"
-        )
+            grid.to_string(),
+            "+---------+\n\
+             |ab  c    |\n\
+             |    d    |\n\
+             +---------+\n\
+             |abcd    e|\n\
+             +---------+\n"
+        );
+
+        grid.set_tab_width(0);
+        assert!(grid.to_string().contains("|ab\tc"));
     }
 
     #[test]
-    fn grid_2x2_without_frame_test() {
+    fn escape_sequence_len_test() {
+        assert_eq!(escape_sequence_len("\u{1b}[1;31mA"), 7);
+        assert_eq!(escape_sequence_len("\u{1b}]0;t\u{7}A"), 6);
+        assert_eq!(escape_sequence_len("\u{1b}[1;3"), 5);
+        assert_eq!(escape_sequence_len("\u{1b}cA"), 2);
+        assert_eq!(escape_sequence_len("\rA"), 1);
+    }
+
+    #[test]
+    fn string_width_test() {
+        assert_eq!(string_width("asd\nqwerty"), 6);
+        assert_eq!(string_width("\u{1b}[31mдж─\u{1b}[0m"), 3);
+    }
+
+    #[test]
+    fn grid_index_test() {
         let mut grid = Grid::new(2, 2);
         grid.set(Entity::Global, Settings::new().text("asd"));
-        grid.get_border_mut(0).empty().inner(Some(' '), None, None);
-        grid.get_border_mut(1).empty().inner(Some(' '), None, None);
+        assert_eq!(
+            grid.to_string(),
+            "+---+---+\n|asd|asd|\n+---+---+\n|asd|asd|\n+---+---+\n"
+        );
 
-        let str = grid.to_string();
+        grid[(1, 0)] = String::from("qwerty");
+        assert_eq!(grid[(1, 0)], "qwerty");
         assert_eq!(
-            str,
-            "asd asd\n\
-             asd asd\n"
-        )
+            grid.to_string(),
+            "+------+---+\n\
+             |asd   |asd|\n\
+             +------+---+\n\
+             |qwerty|asd|\n\
+             +------+---+\n"
+        );
     }
 
     #[test]
-    fn grid_2x2_custom_border_test() {
-        let mut grid = Grid::new(2, 2);
-        grid.set(Entity::Global, Settings::new().text("asd"));
+    fn grid_static_text_is_borrowed_test() {
+        let mut grid = Grid::new(1, 3);
+        grid.set(Entity::Cell(0, 0), Settings::new().text_static("asd"));
+        let text = String::from("qwe");
+        grid.set(Entity::Cell(0, 1), Settings::new().text(&text));
+        grid.set(Entity::Cell(0, 2), Settings::new().text_static("zxc"));
 
-        grid.get_border_mut(0)
-            .top('*', ' ', Some(' '), Some(' '))
-            .inner(Some('@'), Some('$'), Some('%'));
-        grid.get_border_mut(1)
-            .top('*', ' ', Some(' '), Some(' '))
-            .bottom('*', ' ', Some(' '), Some(' '))
-            .inner(Some('^'), Some('#'), Some('!'));
+        assert!(matches!(grid.cells[0][0].text, Cow::Borrowed("asd")));
+        assert!(matches!(grid.cells[0][1].text, Cow::Owned(_)));
 
-        let str = grid.to_string();
+        // an index gives a `String` while the content stays borrowed
+        assert_eq!(grid[(0, 0)], "asd");
+        assert!(matches!(grid.cells[0][0].text, Cow::Borrowed("asd")));
+
+        grid.get_cell_mut(0, 0).unwrap().push('f');
+        grid[(0, 2)].push('v');
+        assert_eq!(grid[(0, 0)], "asdf");
         assert_eq!(
-            str,
-            " *** *** \n\
-             $asd@asd%\n\
-             +---+---+\n\
-             #asd^asd!\n\
-             \u{0020}*** *** \n"
-        )
+            grid.to_string(),
+            "+----+---+----+\n|asdf|qwe|zxcv|\n+----+---+----+\n"
+        );
     }
 
     #[test]
-    fn grid_2x2_remove_row_test() {
-        let mut grid = Grid::new(2, 2);
-        grid.set(Entity::Global, Settings::new().text("asd"));
-        grid.remove_row(0);
-        let str = grid.to_string();
+    fn grid_is_send_and_sync_test() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Grid>();
+    }
+
+    #[test]
+    fn grid_display_is_formatted_lazily_test() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct Expensive(Arc<AtomicUsize>);
+
+        impl fmt::Display for Expensive {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                write!(f, "asd")
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut grid = Grid::new(2, 1);
+        grid.set_display(0, 0, Expensive(calls.clone())).unwrap();
+        grid.set_display(1, 0, Expensive(calls.clone())).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        grid.remove_row(1).unwrap();
+        assert_eq!(grid.to_string(), "+---+\n|asd|\n+---+\n");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert!(grid.set_display(1, 0, 1).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_index_out_of_range_test() {
+        let grid = Grid::new(2, 2);
+        let _ = &grid[(2, 0)];
+    }
+
+    #[test]
+    fn grid_iterators_test() {
+        let mut grid = Grid::new(2, 3);
+        for row in 0..2 {
+            for column in 0..3 {
+                grid[(row, column)] = format!("{}-{}", row, column);
+            }
+        }
+
+        let cells = grid.cells().collect::<Vec<_>>();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[4], (1, 1, "1-1"));
+
+        let rows = grid
+            .rows()
+            .map(|row| row.map(|(_, _, text)| text).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
         assert_eq!(
-            str,
-            "+---+---+\n\
-             |asd|asd|\n\
-             +---+---+\n"
-        )
+            rows,
+            vec![vec!["0-0", "0-1", "0-2"], vec!["1-0", "1-1", "1-2"]]
+        );
+
+        let columns = grid
+            .columns()
+            .map(|column| column.map(|(_, _, text)| text).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            vec![vec!["0-0", "1-0"], vec!["0-1", "1-1"], vec!["0-2", "1-2"]]
+        );
     }
 
     #[test]
-    fn grid_2x2_remove_column_test() {
+    fn grid_out_of_range_test() {
         let mut grid = Grid::new(2, 2);
-        grid.set(Entity::Global, Settings::new().text("asd"));
-        grid.remove_column(0);
-        let str = grid.to_string();
+        let row_error = Error::RowOutOfRange {
+            row: 2,
+            count_rows: 2,
+        };
+        let column_error = Error::ColumnOutOfRange {
+            column: 3,
+            count_columns: 2,
+        };
+
+        assert_eq!(grid.get_cell(2, 0), None);
+        assert_eq!(grid.get_cell_mut(0, 3), None);
         assert_eq!(
-            str,
-            "+---+\n\
-             |asd|\n\
-             +---+\n\
-             |asd|\n\
-             +---+\n"
-        )
+            grid.try_set(Entity::Row(2), Settings::new()),
+            Err(row_error)
+        );
+        assert_eq!(
+            grid.try_set(Entity::Cell(0, 3), Settings::new()),
+            Err(column_error)
+        );
+        assert_eq!(grid.remove_row(2), Err(row_error));
+        assert_eq!(grid.remove_column(3), Err(column_error));
+        assert_eq!(
+            grid.set_cell_border(2, 0, CellBorder::new()),
+            Err(row_error)
+        );
+        assert_eq!(grid.set_row_metadata(2, RowMetadata::new()), Err(row_error));
+
+        assert_eq!(
+            grid.try_set(Entity::Cell(1, 1), Settings::new().text("asd")),
+            Ok(())
+        );
+        grid.get_cell_mut(0, 0).unwrap().push_str("qwerty");
+        assert_eq!(grid.get_cell(0, 0), Some("qwerty"));
+        assert_eq!(
+            grid.to_string(),
+            "+------+---+\n|qwerty|   |\n+------+---+\n|      |asd|\n+------+---+\n"
+        );
     }
 
     #[test]
-    fn grid_3x2_test() {
-        let mut grid = Grid::new(3, 2);
-        grid.set(Entity::Global, Settings::new().text("asd"));
-        let str = grid.to_string();
-        assert_eq!(
-            str,
-            "+---+---+\n\
-             |asd|asd|\n\
-             +---+---+\n\
-             |asd|asd|\n\
-             +---+---+\n\
-             |asd|asd|\n\
-             +---+---+\n"
-        )
+    fn grid_row_metadata_shift_test() {
+        let mut grid = Grid::new(3, 1);
+        grid.set_row_metadata(0, RowMetadata::new().id("0"))
+            .unwrap();
+        grid.set_row_metadata(2, RowMetadata::new().id("2"))
+            .unwrap();
+        grid.remove_row(1).unwrap();
+        assert_eq!(grid.get_row_metadata(0), Some(&RowMetadata::new().id("0")));
+        assert_eq!(grid.get_row_metadata(1), Some(&RowMetadata::new().id("2")));
+        assert_eq!(grid.get_row_metadata(2), None);
     }
 
     #[test]
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Alignment, Border, CaptionPosition, CellBorder, Dimensions, DimensionsCache, Entity, Grid,
+    HorizontalLine, LineEnding, Margin, RowMetadata, Sanitization, SizePolicy, SpanDistribution,
+    StyleLayer, Theme, VerticalLine,
+};
+
+// Snapshot is a serializable part of a grid.
+//
+// Content of providers is stored as a plain text as functions can't be serialized,
+// an estimator is not stored for the same reason.
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    size: (usize, usize),
+    border_styles: Vec<Border>,
+    theme: Option<Theme>,
+    styles: Vec<(Entity, StyleLayer)>,
+    cells: Vec<Vec<String>>,
+    cell_borders: Vec<((usize, usize), CellBorder)>,
+    row_borders: Vec<(usize, CellBorder)>,
+    column_borders: Vec<(usize, CellBorder)>,
+    horizontal_lines: Vec<(usize, HorizontalLine)>,
+    horizontal_line_texts: Vec<(usize, String)>,
+    vertical_lines: Vec<(usize, VerticalLine)>,
+    sanitization: Sanitization,
+    tab_width: usize,
+    metadata: Vec<(usize, RowMetadata)>,
+    details: Vec<(usize, String)>,
+    show_details: bool,
+    widths: Vec<(usize, usize)>,
+    heights: Vec<(usize, usize)>,
+    size_policy: SizePolicy,
+    legend: Vec<(String, String)>,
+    captions: Vec<(CaptionPosition, (String, Alignment))>,
+    margin: Margin,
+    placement: Option<(Alignment, usize)>,
+    line_ending: LineEnding,
+    trailing_newline: bool,
+    header: bool,
+    spans: Vec<((usize, usize), usize)>,
+    row_spans: Vec<((usize, usize), usize)>,
+    span_distribution: SpanDistribution,
+}
+
+impl Grid {
+    /// To_snapshot serializes a grid, its content and settings, to a compact binary format.
+    ///
+    /// Providers are called once and their output is stored as a content of cells.
+    /// The method is available only with a `snapshot` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use papergrid::{Grid, Entity, Settings};
+    ///     let mut grid = Grid::new(1, 2);
+    ///     grid.set(Entity::Cell(0, 0), Settings::new().text("Hello"));
+    ///     grid.set(Entity::Cell(0, 1), Settings::new().text("World"));
+    ///
+    ///     let snapshot = grid.to_snapshot().unwrap();
+    ///     let restored = Grid::from_snapshot(&snapshot).unwrap();
+    ///
+    ///     assert_eq!(grid.to_string(), restored.to_string());
+    /// ```
+    pub fn to_snapshot(&self) -> bincode::Result<Vec<u8>> {
+        let cells = (0..self.count_rows())
+            .map(|row| {
+                (0..self.count_columns())
+                    .map(|column| self.raw_content(row, column).into_owned())
+                    .collect()
+            })
+            .collect();
+
+        let snapshot = Snapshot {
+            size: self.size,
+            border_styles: self.border_styles.clone(),
+            theme: self.theme.clone(),
+            styles: self
+                .styles
+                .iter()
+                .map(|(entity, style)| (entity.clone(), style.clone()))
+                .collect(),
+            cells,
+            cell_borders: self
+                .cell_borders
+                .iter()
+                .map(|(&cell, border)| (cell, border.clone()))
+                .collect(),
+            row_borders: self
+                .row_borders
+                .iter()
+                .map(|(&row, border)| (row, border.clone()))
+                .collect(),
+            column_borders: self
+                .column_borders
+                .iter()
+                .map(|(&column, border)| (column, border.clone()))
+                .collect(),
+            horizontal_lines: self
+                .horizontal_lines
+                .iter()
+                .map(|(&line, style)| (line, style.clone()))
+                .collect(),
+            horizontal_line_texts: self
+                .horizontal_line_texts
+                .iter()
+                .map(|(&line, text)| (line, text.clone()))
+                .collect(),
+            vertical_lines: self
+                .vertical_lines
+                .iter()
+                .map(|(&line, style)| (line, style.clone()))
+                .collect(),
+            sanitization: self.sanitization,
+            tab_width: self.tab_width,
+            metadata: self
+                .metadata
+                .iter()
+                .map(|(&row, metadata)| (row, metadata.clone()))
+                .collect(),
+            details: self
+                .details
+                .iter()
+                .map(|(&row, detail)| (row, detail.clone()))
+                .collect(),
+            show_details: self.show_details,
+            widths: self.widths.iter().map(|(&c, &w)| (c, w)).collect(),
+            heights: self.heights.iter().map(|(&r, &h)| (r, h)).collect(),
+            size_policy: self.size_policy,
+            legend: self.legend.clone(),
+            captions: self
+                .captions
+                .iter()
+                .map(|(&position, caption)| (position, caption.clone()))
+                .collect(),
+            margin: self.margin.clone(),
+            placement: self.placement.clone(),
+            line_ending: self.line_ending,
+            trailing_newline: self.trailing_newline,
+            header: self.header,
+            spans: self
+                .spans
+                .iter()
+                .map(|(&cell, &span)| (cell, span))
+                .collect(),
+            row_spans: self
+                .row_spans
+                .iter()
+                .map(|(&cell, &span)| (cell, span))
+                .collect(),
+            span_distribution: self.span_distribution,
+        };
+
+        bincode::serialize(&snapshot)
+    }
+
+    /// From_snapshot restores a grid serialized by [`Grid::to_snapshot`].
+    ///
+    /// An error is returned if data is corrupted or if it doesn't describe a consistent grid,
+    /// e.g. spans go out of the grid or overlap each other.
+    /// The method is available only with a `snapshot` feature.
+    pub fn from_snapshot(bytes: &[u8]) -> bincode::Result<Self> {
+        let snapshot: Snapshot = bincode::deserialize(bytes)?;
+
+        let (rows, columns) = snapshot.size;
+        let is_consistent = snapshot.cells.len() == rows
+            && snapshot.cells.iter().all(|row| row.len() == columns)
+            && snapshot.border_styles.len() == rows
+            && snapshot.widths.iter().all(|&(column, _)| column < columns)
+            && snapshot.heights.iter().all(|&(row, _)| row < rows)
+            && snapshot
+                .horizontal_lines
+                .iter()
+                .all(|&(line, _)| line <= rows)
+            && snapshot
+                .spans
+                .iter()
+                .chain(&snapshot.row_spans)
+                .all(|&(_, span)| span >= 2);
+        if !is_consistent {
+            return Err(inconsistency("a snapshot doesn't match a size of the grid"));
+        }
+
+        let mut grid = Self {
+            size: snapshot.size,
+            border_styles: snapshot.border_styles,
+            theme: snapshot.theme,
+            styles: snapshot.styles.into_iter().collect(),
+            cells: snapshot
+                .cells
+                .into_iter()
+                .map(|row| row.into_iter().map(Into::into).collect())
+                .collect(),
+            providers: HashMap::new(),
+            cell_borders: snapshot.cell_borders.into_iter().collect(),
+            row_borders: snapshot.row_borders.into_iter().collect(),
+            column_borders: snapshot.column_borders.into_iter().collect(),
+            horizontal_lines: snapshot.horizontal_lines.into_iter().collect(),
+            horizontal_line_texts: snapshot.horizontal_line_texts.into_iter().collect(),
+            vertical_lines: snapshot.vertical_lines.into_iter().collect(),
+            sanitization: snapshot.sanitization,
+            tab_width: snapshot.tab_width,
+            metadata: snapshot.metadata.into_iter().collect(),
+            details: snapshot.details.into_iter().collect(),
+            show_details: snapshot.show_details,
+            widths: snapshot.widths.into_iter().collect(),
+            heights: snapshot.heights.into_iter().collect(),
+            size_policy: snapshot.size_policy,
+            legend: snapshot.legend,
+            captions: snapshot.captions.into_iter().collect(),
+            margin: snapshot.margin,
+            placement: snapshot.placement,
+            line_ending: snapshot.line_ending,
+            trailing_newline: snapshot.trailing_newline,
+            header: snapshot.header,
+            estimator: None,
+            spans: HashMap::new(),
+            row_spans: HashMap::new(),
+            span_distribution: snapshot.span_distribution,
+            dimensions: DimensionsCache::new(Dimensions::new(rows, columns)),
+        };
+
+        // spans are set one by one so the ones out of the grid or overlapping each other are rejected
+        for ((row, column), span) in snapshot.spans {
+            grid.set_column_span(row, column, span)
+                .map_err(|err| inconsistency(&err.to_string()))?;
+        }
+        for ((row, column), span) in snapshot.row_spans {
+            grid.set_row_span(row, column, span)
+                .map_err(|err| inconsistency(&err.to_string()))?;
+        }
+
+        Ok(grid)
+    }
+}
+
+fn inconsistency(message: &str) -> bincode::Error {
+    Box::new(bincode::ErrorKind::Custom(message.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corrupted(f: impl Fn(&mut Snapshot)) -> Vec<u8> {
+        let snapshot = Grid::new(3, 3).to_snapshot().unwrap();
+        let mut snapshot: Snapshot = bincode::deserialize(&snapshot).unwrap();
+        f(&mut snapshot);
+        bincode::serialize(&snapshot).unwrap()
+    }
+
+    #[test]
+    fn snapshot_with_inconsistent_spans_test() {
+        let snapshots = [
+            corrupted(|s| s.spans = vec![((0, 0), 0)]),
+            corrupted(|s| s.spans = vec![((0, 0), 1)]),
+            corrupted(|s| s.row_spans = vec![((0, 0), 0)]),
+            corrupted(|s| s.spans = vec![((0, 2), 2)]),
+            corrupted(|s| s.row_spans = vec![((2, 0), 2)]),
+            corrupted(|s| s.spans = vec![((0, 0), 2), ((0, 1), 2)]),
+            corrupted(|s| {
+                s.spans = vec![((1, 0), 2)];
+                s.row_spans = vec![((0, 1), 2)];
+            }),
+        ];
+
+        for snapshot in &snapshots {
+            assert!(Grid::from_snapshot(snapshot).is_err());
+        }
+
+        let snapshot = corrupted(|s| {
+            s.spans = vec![((0, 0), 2)];
+            s.row_spans = vec![((1, 2), 2)];
+        });
+        let grid = Grid::from_snapshot(&snapshot).unwrap();
+        assert_eq!(grid.get_column_span(0, 0), 2);
+        assert_eq!(grid.get_row_span(1, 2), 2);
+    }
+}
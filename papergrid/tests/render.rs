@@ -10,7 +10,9 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-use papergrid::{Alignment, Entity, Grid, Settings};
+use papergrid::{
+    Alignment, CaptionPosition, Entity, Grid, LineEnding, Margin, Padding, Settings, Theme,
+};
 
 #[test]
 fn render() {
@@ -84,8 +86,7 @@ fn render_multilane_alignment() {
             .alignment(Alignment::Right),
     );
 
-    let expected = concat!(
-        "+----------------------------------------+---------+\n\
+    let expected = "+----------------------------------------+---------+\n\
          |                  left                  |right one|\n\
          |                  cell                  |         |\n\
          +----------------------------------------+---------+\n\
@@ -95,8 +96,7 @@ fn render_multilane_alignment() {
          |                                        |        a|\n\
          |                                        |     long|\n\
          |                                        |   string|\n\
-         +----------------------------------------+---------+\n"
-    );
+         +----------------------------------------+---------+\n";
 
     let g = grid.to_string();
     assert_eq!(expected, g);
@@ -405,6 +405,111 @@ fn render_empty_cell() {
 // }
 
 // #[test]
+#[test]
+fn render_with_limit() {
+    let mut grid = Grid::new(3, 2);
+    grid.set(Entity::Global, Settings::new().text("0-0"));
+
+    let expected = concat!(
+        "+---+---+\n",
+        "|0-0|0-0|\n",
+        "... output is truncated as it exceeds 69 bytes\n",
+    );
+
+    assert_eq!(expected, grid.render_with_limit(69));
+    assert_eq!(grid.to_string(), grid.render_with_limit(70));
+    assert_eq!(grid.to_string(), grid.render_with_limit(1000));
+    assert_eq!("", grid.render_with_limit(35));
+}
+
+#[test]
+fn render_with_limit_counts_notice_in_it() {
+    let mut grid = Grid::new(20, 2);
+    grid.set(Entity::Global, Settings::new().text("0-0"));
+    grid.set_margin(Margin::new(1, 1, 1, 1));
+    grid.set_placement(Alignment::Right, 20);
+
+    for &(line_ending, trailing_newline) in &[
+        (LineEnding::Lf, true),
+        (LineEnding::Lf, false),
+        (LineEnding::CrLf, true),
+        (LineEnding::CrLf, false),
+    ] {
+        grid.set_line_ending(line_ending);
+        grid.set_trailing_newline(trailing_newline);
+
+        let table = grid.to_string();
+        for &max_bytes in &[50, 100, 333] {
+            let output = grid.render_with_limit(max_bytes);
+            let (lines, notice) = output.split_at(output.find("...").unwrap());
+
+            assert!(output.len() <= max_bytes);
+            assert!(table.starts_with(lines));
+            assert!(lines.is_empty() || lines.ends_with('\n'));
+            assert!(notice.starts_with("... output is truncated"));
+        }
+    }
+}
+
+#[test]
+fn render_into_reserved_string() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(Entity::Global, Settings::new().text("0-0"));
+    grid.set(Entity::Cell(1, 1), Settings::new().text("привет\nмир"));
+    grid.set_margin(Margin::new(1, 1, 1, 1));
+    grid.set_line_ending(LineEnding::CrLf);
+
+    let expected = grid.to_string();
+    assert_eq!(grid.rendered_len(), expected.len());
+
+    let mut buf = String::new();
+    grid.render_into(&mut buf);
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn render_into_exact_reserved_string() {
+    let mut grid = Grid::new(3, 3);
+    grid.set(Entity::Global, Settings::new().text("0-0"));
+    grid.set(
+        Entity::Cell(0, 0),
+        Settings::new()
+            .text("привет\nмир")
+            .padding(Padding::new(1, 2, 1, 0).fill('·')),
+    );
+    grid.set(Entity::Cell(1, 1), Settings::new().text("我们"));
+    grid.set_column_span(2, 0, 2).unwrap();
+    grid.set_row_span(0, 2, 2).unwrap();
+    grid.set_theme(
+        Theme::new()
+            .top('═', Some('╤'), Some('╔'), Some('╗'))
+            .bottom('═', Some('╧'), Some('╚'), Some('╝'))
+            .header('─', Some('┼'), Some('╟'), Some('╢'))
+            .inner(Some('│'), Some('║'), Some('║')),
+    );
+    grid.override_horizontal_line_text(1, "ёж").unwrap();
+    grid.set_row_detail(1, "подробности строки").unwrap();
+    grid.show_details(true);
+    grid.set_caption(CaptionPosition::Top, "заголовок", Alignment::Center);
+    grid.set_caption(CaptionPosition::Bottom, "конец", Alignment::Right);
+    grid.push_legend_entry("★", "звезда");
+    grid.set_margin(Margin::new(2, 1, 1, 2).fill('░'));
+    grid.set_placement(Alignment::Center, 60);
+    grid.set_line_ending(LineEnding::CrLf);
+    grid.set_trailing_newline(false);
+
+    let expected = grid.to_string();
+    assert_eq!(grid.rendered_len(), expected.len());
+
+    let mut reserved = String::new();
+    reserved.reserve(expected.len());
+
+    let mut buf = String::new();
+    grid.render_into(&mut buf);
+    assert_eq!(buf, expected);
+    assert_eq!(buf.capacity(), reserved.capacity());
+}
+
 // #[ignore = "
 //             This seems to be an issue.
 
@@ -0,0 +1,53 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+#![cfg(feature = "snapshot")]
+
+use papergrid::{Alignment, CellBorder, Entity, Grid, RowMetadata, Settings, Severity};
+
+#[test]
+fn snapshot_round_trip() {
+    let mut grid = Grid::new(2, 2);
+    grid.set(
+        Entity::Global,
+        Settings::new()
+            .ident(1, 1, 0, 0)
+            .alignment(Alignment::Right),
+    );
+    grid.set(Entity::Row(0), Settings::new().text("header"));
+    grid.set_provider(Entity::Cell(1, 1), |row, column| {
+        format!("{}-{}", row, column)
+    });
+    grid.set_cell_border(1, 0, CellBorder::new().left('#'))
+        .unwrap();
+    grid.set_row_metadata(1, RowMetadata::new().severity(Severity::Error))
+        .unwrap();
+    grid.push_legend_entry("#", "an error");
+
+    let snapshot = grid.to_snapshot().unwrap();
+    let restored = Grid::from_snapshot(&snapshot).unwrap();
+
+    assert_eq!(restored.to_string(), grid.to_string());
+    assert_eq!(
+        restored.get_row_metadata(1).and_then(|m| m.get_severity()),
+        Some(Severity::Error)
+    );
+}
+
+#[test]
+fn snapshot_corrupted() {
+    let grid = Grid::new(2, 2);
+    let snapshot = grid.to_snapshot().unwrap();
+
+    assert!(Grid::from_snapshot(&snapshot[..snapshot.len() / 2]).is_err());
+    assert!(Grid::from_snapshot(&[]).is_err());
+}
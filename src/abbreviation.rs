@@ -0,0 +1,97 @@
+use papergrid::{string_width, Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// Abbreviations replaces long column names by approved abbreviations,
+/// but only when a table doesn't fit into a given width.
+///
+/// Names which give the biggest gain are abbreviated first
+/// and it stops as soon as the table fits.
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+///     use tabled::{table, Abbreviations, Style, Tabled};
+///
+///     #[derive(Tabled)]
+///     struct Load {
+///         #[tabled(rename = "Transactions per second")]
+///         tps: usize,
+///         #[tabled(rename = "Latency")]
+///         latency: usize,
+///     }
+///
+///     let data = vec![Load { tps: 120, latency: 3 }];
+///     let abbreviations = Abbreviations::new(20)
+///         .add("Transactions per second", "TPS")
+///         .add("Latency", "Lat");
+///
+///     let table = table!(&data, Style::psql(), abbreviations);
+///     assert_eq!(table, concat!(
+///         " TPS | Latency \n",
+///         "-----+---------\n",
+///         " 120 |    3    \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Abbreviations {
+    max_width: usize,
+    dictionary: Vec<(String, String)>,
+}
+
+impl Abbreviations {
+    /// New creates an empty dictionary which is used when a table is wider than `max_width`.
+    pub fn new(max_width: usize) -> Self {
+        Self {
+            max_width,
+            dictionary: Vec::new(),
+        }
+    }
+
+    /// Add adds an abbreviation for a column name.
+    pub fn add<N, A>(mut self, name: N, abbreviation: A) -> Self
+    where
+        N: Into<String>,
+        A: Into<String>,
+    {
+        self.dictionary.push((name.into(), abbreviation.into()));
+        self
+    }
+}
+
+impl TableOption for Abbreviations {
+    fn change(&self, grid: &mut Grid) {
+        if grid.count_rows() == 0 {
+            return;
+        }
+
+        let mut candidates = Vec::new();
+        for column in 0..grid.count_columns() {
+            let header = grid.get_cell_content(0, column).to_owned();
+            let abbreviation = self
+                .dictionary
+                .iter()
+                .find(|(name, _)| *name == header)
+                .map(|(_, abbreviation)| abbreviation);
+
+            if let Some(abbreviation) = abbreviation {
+                let gain = string_width(&header).saturating_sub(string_width(abbreviation));
+                candidates.push((gain, column, abbreviation));
+            }
+        }
+
+        candidates.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        for (_, column, abbreviation) in candidates {
+            if grid.table_width() <= self.max_width {
+                break;
+            }
+
+            grid.set(
+                Entity::Cell(0, column),
+                Settings::new().text(abbreviation.as_str()),
+            );
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use papergrid::{Alignment, Entity, Grid, Settings};
+use std::collections::BTreeMap;
+
+use papergrid::{string_width, Alignment, AlignmentVertical, Entity, Grid, Settings};
 
 use crate::{Object, TableOption};
 
@@ -23,3 +25,244 @@ impl<O: Object> TableOption for HorizontalAlignment<O> {
         }
     }
 }
+
+/// VerticalAlignment represent a vertical alignment setting for a [`table` macros](./macro.table.html)
+///
+/// It places a content of cells which are lower than their row,
+/// e.g. a single line header next to a multiline one.
+///
+/// ```rust
+///     use tabled::{table, AlignmentVertical, Full, Style, VerticalAlignment};
+///     let data = vec![("Revenue\n2021\nUSD", "EMEA")];
+///     let table = table!(&data, Style::psql(), VerticalAlignment(Full, AlignmentVertical::Center));
+///
+///     assert_eq!(table, concat!(
+///         "  &str   | &str \n",
+///         "---------+------\n",
+///         " Revenue |      \n",
+///         "  2021   | EMEA \n",
+///         "   USD   |      \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct VerticalAlignment<O: Object>(pub O, pub AlignmentVertical);
+
+impl<O: Object> TableOption for VerticalAlignment<O> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+            grid.set(
+                Entity::Cell(row, column),
+                Settings::new().vertical_alignment(self.1.clone()),
+            )
+        }
+    }
+}
+
+/// AlignOnChar lines up lines of multiline cells by the first occurrence of a character,
+/// so label/value pairs inside a cell look like a small two column layout.
+///
+/// Lines which don't have the character are left as they are.
+/// Because each line of a cell is aligned on its own
+/// the option is supposed to be used with a left alignment.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, AlignOnChar, Alignment, Full, HorizontalAlignment, Style};
+///     let data = vec!["name: tabled\nversion: 0.1.3\nlicense: MIT"];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         HorizontalAlignment(Full, Alignment::Left),
+///         AlignOnChar(Full, ':'),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         " &str            \n",
+///         "-----------------\n",
+///         "    name: tabled \n",
+///         " version: 0.1.3  \n",
+///         " license: MIT    \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct AlignOnChar<O: Object>(pub O, pub char);
+
+impl<O: Object> TableOption for AlignOnChar<O> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+            let content = grid.get_cell_content(row, column);
+            if !content.contains('\n') {
+                continue;
+            }
+
+            let content = align_on_char(content, self.1);
+            grid.set(Entity::Cell(row, column), Settings::new().text(content))
+        }
+    }
+}
+
+/// AlignOnDecimal lines up numbers in a column by a decimal separator.
+///
+/// Integer parts are padded on the left and fractional parts on the right,
+/// so numbers with a different precision read correctly.
+/// Cells which don't look like a number, e.g. a header, are left as they are.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, AlignOnDecimal, Column, Style};
+///     let data = vec![("rent", "1200"), ("coffee", "3.5"), ("tax", "-87.25")];
+///     let table = table!(&data, Style::psql(), AlignOnDecimal(Column(1..), '.'));
+///
+///     assert_eq!(table, concat!(
+///         "  &str  |  &str   \n",
+///         "--------+---------\n",
+///         "  rent  |  1200   \n",
+///         " coffee |    3.5  \n",
+///         "  tax   |  -87.25 \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct AlignOnDecimal<O: Object>(pub O, pub char);
+
+impl<O: Object> TableOption for AlignOnDecimal<O> {
+    fn change(&self, grid: &mut Grid) {
+        let mut columns = BTreeMap::<usize, Vec<usize>>::new();
+        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+            if is_number(grid.get_cell_content(row, column).trim(), self.1) {
+                columns.entry(column).or_default().push(row);
+            }
+        }
+
+        for (column, rows) in columns {
+            let numbers = rows
+                .iter()
+                .map(|&row| {
+                    let text = grid.get_cell_content(row, column).trim();
+                    match text.find(self.1) {
+                        Some(i) => (text[..i].to_owned(), Some(text[i..].to_owned())),
+                        None => (text.to_owned(), None),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let integer_width = numbers
+                .iter()
+                .map(|(integer, _)| string_width(integer))
+                .max()
+                .unwrap_or(0);
+            let fraction_width = numbers
+                .iter()
+                .filter_map(|(_, fraction)| fraction.as_deref().map(string_width))
+                .max()
+                .unwrap_or(0);
+
+            for (row, (integer, fraction)) in rows.into_iter().zip(numbers) {
+                let fraction = fraction.unwrap_or_default();
+                let text = format!(
+                    "{}{}{}{}",
+                    " ".repeat(integer_width - string_width(&integer)),
+                    integer,
+                    fraction,
+                    " ".repeat(fraction_width - string_width(&fraction)),
+                );
+                grid.set(Entity::Cell(row, column), Settings::new().text(text))
+            }
+        }
+    }
+}
+
+/// AlignNumbers right aligns columns where every data cell is a number,
+/// as it's done by most database clients. Empty cells are ignored.
+///
+/// A header is left as it is.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, AlignNumbers, Style};
+///     let data = vec![("Fedora", "34"), ("Debian", "11.1"), ("OpenSUSE", "")];
+///     let table = table!(&data, Style::psql(), AlignNumbers);
+///
+///     assert_eq!(table, concat!(
+///         "   &str   | &str \n",
+///         "----------+------\n",
+///         "  Fedora  |   34 \n",
+///         "  Debian  | 11.1 \n",
+///         " OpenSUSE |      \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct AlignNumbers;
+
+impl TableOption for AlignNumbers {
+    fn change(&self, grid: &mut Grid) {
+        for column in 0..grid.count_columns() {
+            if !is_numeric_column(grid, column) {
+                continue;
+            }
+
+            let first_row = if grid.has_header() { 1 } else { 0 };
+            for row in first_row..grid.count_rows() {
+                grid.set(
+                    Entity::Cell(row, column),
+                    Settings::new().alignment(Alignment::Right),
+                )
+            }
+        }
+    }
+}
+
+fn is_numeric_column(grid: &Grid, column: usize) -> bool {
+    let first_row = if grid.has_header() { 1 } else { 0 };
+    let cells = (first_row..grid.count_rows())
+        .map(|row| grid.get_cell(row, column).unwrap_or_default().trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>();
+
+    !cells.is_empty() && cells.iter().all(|text| is_number(text, '.'))
+}
+
+// a number may have a sign, a currency, a percent and group separators like `1,000`,
+// the rest has to parse as a number so dates or phone numbers like `555-1234` are not taken for numbers
+fn is_number(text: &str, separator: char) -> bool {
+    let text = text.strip_prefix(|c| c == '+' || c == '-').unwrap_or(text);
+    let text = text.trim_matches(|c| "$€£%".contains(c));
+    let number = text
+        .chars()
+        .filter(|&c| c == separator || !"_,' ".contains(c))
+        .map(|c| if c == separator { '.' } else { c })
+        .collect::<String>();
+
+    number.starts_with(|c: char| c.is_ascii_digit() || c == '.') && number.parse::<f64>().is_ok()
+}
+
+fn align_on_char(text: &str, c: char) -> String {
+    let lines = text
+        .lines()
+        .map(|line| match line.find(c) {
+            Some(i) => (&line[..i], Some(&line[i..])),
+            None => (line, None),
+        })
+        .collect::<Vec<_>>();
+
+    let anchor = lines
+        .iter()
+        .filter(|(_, rest)| rest.is_some())
+        .map(|(prefix, _)| string_width(prefix))
+        .max()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|(prefix, rest)| match rest {
+            Some(rest) => {
+                let indent = " ".repeat(anchor - string_width(prefix));
+                format!("{}{}{}", indent, prefix, rest)
+            }
+            None => prefix.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
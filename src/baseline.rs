@@ -0,0 +1,175 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// Baseline compares numeric cells with the ones on a baseline row or column
+/// and formats them depending on whether they are better or worse.
+///
+/// By default a lower value is considered better, as it's in benchmarks,
+/// better values are painted green and worse red.
+/// Cells which don't start with a number, like names of benchmarks, are left untouched.
+///
+/// To get a correct layout for colored output a `color` feature must be turned on.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Baseline, Style};
+///     let data = vec![("main", 200), ("fast", 150), ("slow", 250)];
+///
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Baseline::row(1)
+///             .better(|s| format!("+{}", s))
+///             .worse(|s| format!("-{}", s))
+///             .delta(),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         " &str |      i32      \n",
+///         "------+---------------\n",
+///         " main |      200      \n",
+///         " fast | +150 (-25.0%) \n",
+///         " slow | -250 (+25.0%) \n",
+///     ));
+/// ```
+pub struct Baseline {
+    line: Line,
+    lower_is_better: bool,
+    delta: bool,
+    better: Box<dyn Fn(&str) -> String>,
+    worse: Box<dyn Fn(&str) -> String>,
+}
+
+enum Line {
+    Row(usize),
+    Column(usize),
+}
+
+impl Baseline {
+    /// Row creates a baseline which compares each cell with a cell in the same column of a given row.
+    pub fn row(row: usize) -> Self {
+        Self::new(Line::Row(row))
+    }
+
+    /// Column creates a baseline which compares each cell with a cell in the same row of a given column.
+    ///
+    /// A header row is not compared.
+    pub fn column(column: usize) -> Self {
+        Self::new(Line::Column(column))
+    }
+
+    fn new(line: Line) -> Self {
+        Self {
+            line,
+            lower_is_better: true,
+            delta: false,
+            better: Box::new(|s| format!("\u{1b}[32m{}\u{1b}[0m", s)),
+            worse: Box::new(|s| format!("\u{1b}[31m{}\u{1b}[0m", s)),
+        }
+    }
+
+    /// Higher is better makes greater values considered better.
+    pub fn higher_is_better(mut self) -> Self {
+        self.lower_is_better = false;
+        self
+    }
+
+    /// Delta appends a relative difference with a baseline in percents, like `(+12.5%)`.
+    pub fn delta(mut self) -> Self {
+        self.delta = true;
+        self
+    }
+
+    /// Better sets a function which formats values better than a baseline.
+    pub fn better<F: Fn(&str) -> String + 'static>(mut self, f: F) -> Self {
+        self.better = Box::new(f);
+        self
+    }
+
+    /// Worse sets a function which formats values worse than a baseline.
+    pub fn worse<F: Fn(&str) -> String + 'static>(mut self, f: F) -> Self {
+        self.worse = Box::new(f);
+        self
+    }
+
+    fn baseline_cell(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        match self.line {
+            Line::Row(line) if row != line => Some((line, column)),
+            Line::Column(line) if column != line => Some((row, line)),
+            _ => None,
+        }
+    }
+}
+
+impl TableOption for Baseline {
+    fn change(&self, grid: &mut Grid) {
+        let (line, count) = match self.line {
+            Line::Row(row) => (row, grid.count_rows()),
+            Line::Column(column) => (column, grid.count_columns()),
+        };
+        if line >= count {
+            return;
+        }
+
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        let mut changes = Vec::new();
+        for row in first_row..grid.count_rows() {
+            for column in 0..grid.count_columns() {
+                let (base_row, base_column) = match self.baseline_cell(row, column) {
+                    Some(cell) => cell,
+                    None => continue,
+                };
+
+                let content = grid.get_cell_content(row, column).to_owned();
+                let value = parse_number(&content);
+                let base = parse_number(grid.get_cell_content(base_row, base_column));
+                let (value, base) = match (value, base) {
+                    (Some(value), Some(base)) => (value, base),
+                    _ => continue,
+                };
+
+                let mut text = content.trim().to_owned();
+                if self.delta && base != 0.0 {
+                    let delta = (value - base) / base.abs() * 100.0;
+                    text = format!("{} ({:+.1}%)", text, delta);
+                }
+
+                let is_better = if self.lower_is_better {
+                    value < base
+                } else {
+                    value > base
+                };
+
+                let text = if value == base {
+                    text
+                } else if is_better {
+                    (self.better)(&text)
+                } else {
+                    (self.worse)(&text)
+                };
+
+                changes.push((row, column, text));
+            }
+        }
+
+        // the changes are applied only after all cells are compared
+        // so a baseline is never compared with a formatted content
+        for (row, column, text) in changes {
+            grid.set(Entity::Cell(row, column), Settings::new().text(text))
+        }
+    }
+}
+
+// It parses a number at the beginning of a text so values with units, like `1.5ms`, are also compared.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let end = text
+        .char_indices()
+        .take_while(|&(i, c)| c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+')))
+        .map(|(i, c)| i + c.len_utf8())
+        .last()?;
+
+    text[..end].parse().ok()
+}
@@ -0,0 +1,236 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use papergrid::{Alignment, Entity, Grid, Settings};
+
+use crate::{build_grid_from_records, build_grid_from_records_without_header};
+
+/// Builder creates a [`Grid`](../papergrid/struct.Grid.html) from a data which columns are known only in runtime.
+///
+/// Options can be applied to the built grid the same way it's done by a [`table` macros](./macro.table.html).
+///
+/// # Example
+///
+/// ```rust
+///     use std::collections::BTreeMap;
+///     use tabled::{Builder, Style, TableOption};
+///
+///     let mut first = BTreeMap::new();
+///     first.insert("name", "tabled");
+///     first.insert("license", "MIT");
+///
+///     let mut second = BTreeMap::new();
+///     second.insert("name", "papergrid");
+///     second.insert("version", "0.1.9");
+///
+///     let mut grid = Builder::from_maps(vec![first, second])
+///         .default_value("-")
+///         .build();
+///     Style::psql().change(&mut grid);
+///
+///     assert_eq!(grid.to_string(), concat!(
+///         " license |   name    | version \n",
+///         "---------+-----------+---------\n",
+///         "   MIT   |  tabled   |    -    \n",
+///         "    -    | papergrid |  0.1.9  \n",
+///     ));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Builder {
+    headers: Option<Vec<String>>,
+    records: Vec<Vec<Option<String>>>,
+    default_value: String,
+    default_header: String,
+    without_header: bool,
+    alignments: Vec<(usize, Alignment)>,
+}
+
+impl Builder {
+    /// New creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// From maps creates a builder where each map is a row and columns are a union of all keys.
+    ///
+    /// Columns are sorted by keys and missing values are filled by a [`default_value`](#method.default_value).
+    pub fn from_maps<I, M, K, V>(maps: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: IntoIterator<Item = (K, V)>,
+        K: Display,
+        V: Display,
+    {
+        let maps = maps
+            .into_iter()
+            .map(|map| {
+                map.into_iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let keys = maps
+            .iter()
+            .flat_map(|map| map.iter().map(|(key, _)| key.clone()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let records = maps
+            .into_iter()
+            .map(|map| {
+                let mut record = vec![None; keys.len()];
+                for (key, value) in map {
+                    if let Ok(i) = keys.binary_search(&key) {
+                        record[i] = Some(value);
+                    }
+                }
+
+                record
+            })
+            .collect();
+
+        Self {
+            headers: Some(keys),
+            records,
+            default_value: String::new(),
+            default_header: String::new(),
+            without_header: false,
+            alignments: Vec::new(),
+        }
+    }
+
+    /// Header sets names of columns.
+    ///
+    /// If it's not set columns are named by their indexes.
+    /// If it's shorter than the longest row it's filled by a [`default_header`](#method.default_header).
+    pub fn header<H, T>(mut self, header: H) -> Self
+    where
+        H: IntoIterator<Item = T>,
+        T: Display,
+    {
+        self.headers = Some(header.into_iter().map(|h| h.to_string()).collect());
+        self
+    }
+
+    /// Add row appends a row to a table.
+    ///
+    /// A row may be shorter than others, in which case it's filled by a [`default_value`](#method.default_value).
+    pub fn add_row<R, T>(mut self, row: R) -> Self
+    where
+        R: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let row = row.into_iter().map(|v| Some(v.to_string())).collect();
+        self.records.push(row);
+        self
+    }
+
+    /// Default value sets a text which is used for missing values.
+    ///
+    /// By default it's an empty string.
+    pub fn default_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_value = value.into();
+        self
+    }
+
+    /// Default header sets a name of columns which are not covered by a header.
+    ///
+    /// By default it's an empty string.
+    ///
+    /// ```rust
+    ///     use tabled::{Builder, Style, TableOption};
+    ///     let mut grid = Builder::new()
+    ///         .header(vec!["name"])
+    ///         .add_row(vec!["tabled", "0.1.3"])
+    ///         .default_header("?")
+    ///         .build();
+    ///     Style::psql().change(&mut grid);
+    ///
+    ///     assert_eq!(grid.to_string(), concat!(
+    ///         "  name  |   ?   \n",
+    ///         "--------+-------\n",
+    ///         " tabled | 0.1.3 \n",
+    ///     ));
+    /// ```
+    pub fn default_header<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_header = value.into();
+        self
+    }
+
+    /// Without header makes a grid be built without a header row.
+    ///
+    /// A header set by [`header`](#method.header) is not shown,
+    /// but it's still taken into account in a number of columns.
+    ///
+    /// ```rust
+    ///     use tabled::{Builder, Style, TableOption};
+    ///     let mut grid = Builder::new()
+    ///         .add_row(vec!["tabled", "0.1.3"])
+    ///         .add_row(vec!["papergrid", "0.1.9"])
+    ///         .without_header()
+    ///         .build();
+    ///     Style::psql().change(&mut grid);
+    ///
+    ///     assert_eq!(grid.to_string(), concat!(
+    ///         "  tabled   | 0.1.3 \n",
+    ///         " papergrid | 0.1.9 \n",
+    ///     ));
+    /// ```
+    pub fn without_header(mut self) -> Self {
+        self.without_header = true;
+        self
+    }
+
+    /// Alignment sets an alignment of a column, including its header.
+    pub fn alignment(mut self, column: usize, alignment: Alignment) -> Self {
+        self.alignments.push((column, alignment));
+        self
+    }
+
+    /// Build creates a grid.
+    ///
+    /// Rows of different lengths are padded to the longest one, as well as a header.
+    pub fn build(self) -> Grid {
+        let count_columns = self
+            .records
+            .iter()
+            .map(|record| record.len())
+            .chain(self.headers.iter().map(|headers| headers.len()))
+            .max()
+            .unwrap_or(0);
+
+        let mut headers = self
+            .headers
+            .unwrap_or_else(|| (0..count_columns).map(|i| i.to_string()).collect());
+        headers.resize(count_columns, self.default_header);
+
+        let default_value = self.default_value;
+        let records = self
+            .records
+            .into_iter()
+            .map(|record| {
+                let mut record = record
+                    .into_iter()
+                    .map(|value| value.unwrap_or_else(|| default_value.clone()))
+                    .collect::<Vec<_>>();
+                record.resize(count_columns, default_value.clone());
+                record
+            })
+            .collect();
+
+        let mut grid = if self.without_header {
+            build_grid_from_records_without_header(count_columns, records)
+        } else {
+            build_grid_from_records(headers, records)
+        };
+
+        for (column, alignment) in self.alignments {
+            if column < count_columns {
+                grid.set(Entity::Column(column), Settings::new().alignment(alignment));
+            }
+        }
+
+        grid
+    }
+}
@@ -0,0 +1,66 @@
+use papergrid::{Alignment, CaptionPosition, Grid};
+
+use crate::TableOption;
+
+/// Caption adds a line of text above or under a table, like a title or a summary.
+///
+/// The text is aligned relative to a width of the table, it's centered by default.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Alignment, Caption, Style};
+///     let data = vec![("Fedora", 34), ("Debian", 11)];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Caption::top("Distributions"),
+///         Caption::bottom("2 total").alignment(Alignment::Right),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "Distributions \n",
+///         "  &str  | i32 \n",
+///         "--------+-----\n",
+///         " Fedora | 34  \n",
+///         " Debian | 11  \n",
+///         "       2 total\n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Caption {
+    position: CaptionPosition,
+    text: String,
+    alignment: Alignment,
+}
+
+impl Caption {
+    /// Top creates a caption which is rendered above a table.
+    pub fn top<S: Into<String>>(text: S) -> Self {
+        Self {
+            position: CaptionPosition::Top,
+            text: text.into(),
+            alignment: Alignment::Center,
+        }
+    }
+
+    /// Bottom creates a caption which is rendered under a table.
+    pub fn bottom<S: Into<String>>(text: S) -> Self {
+        Self {
+            position: CaptionPosition::Bottom,
+            ..Self::top(text)
+        }
+    }
+
+    /// Alignment sets an alignment of a caption relative to a table.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl TableOption for Caption {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_caption(self.position, self.text.as_str(), self.alignment.clone());
+    }
+}
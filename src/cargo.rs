@@ -0,0 +1,242 @@
+//! This module contains preconfigured tables for data from the Cargo ecosystem.
+//!
+//! It's available only with a `cargo` feature.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//!     use cargo_metadata::MetadataCommand;
+//!     use tabled::cargo;
+//!
+//!     let metadata = MetadataCommand::new().exec().unwrap();
+//!     let package = metadata.root_package().unwrap();
+//!
+//!     println!("{}", cargo::dependencies(package));
+//!     println!("{}", cargo::features(package));
+//!     println!("{}", cargo::benchmarks(&cargo::read_criterion("target/criterion").unwrap()));
+//! ```
+
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use cargo_metadata::{DependencyKind, Package};
+
+use crate::{build_grid, build_grid_from_records, Baseline, Style, TableOption, Tabled};
+
+/// Dependencies returns a table of dependencies of a package.
+///
+/// Dependencies are grouped by their kind and sorted by name.
+pub fn dependencies(package: &Package) -> String {
+    let mut dependencies = package
+        .dependencies
+        .iter()
+        .map(Dependency)
+        .collect::<Vec<_>>();
+    dependencies.sort_by_key(|d| (kind_order(&d.0.kind), d.0.name.clone()));
+
+    let mut grid = build_grid(dependencies);
+    Style::psql().change(&mut grid);
+    grid.to_string()
+}
+
+/// Dependency is a row of a [`dependencies`] table.
+///
+/// It can be used directly to build a table with other settings.
+pub struct Dependency<'a>(pub &'a cargo_metadata::Dependency);
+
+impl Tabled for Dependency<'_> {
+    fn fields(&self) -> Vec<String> {
+        let dependency = self.0;
+        vec![
+            dependency.name.clone(),
+            dependency.req.to_string(),
+            kind_name(&dependency.kind).to_owned(),
+            if dependency.optional { "+" } else { "" }.to_owned(),
+            dependency
+                .target
+                .as_ref()
+                .map(|target| target.to_string())
+                .unwrap_or_default(),
+        ]
+    }
+
+    fn headers() -> Vec<String> {
+        vec![
+            "name".to_owned(),
+            "version".to_owned(),
+            "kind".to_owned(),
+            "optional".to_owned(),
+            "target".to_owned(),
+        ]
+    }
+}
+
+fn kind_name(kind: &DependencyKind) -> &'static str {
+    match kind {
+        DependencyKind::Normal => "normal",
+        DependencyKind::Development => "dev",
+        DependencyKind::Build => "build",
+        _ => "unknown",
+    }
+}
+
+fn kind_order(kind: &DependencyKind) -> usize {
+    match kind {
+        DependencyKind::Normal => 0,
+        DependencyKind::Build => 1,
+        DependencyKind::Development => 2,
+        _ => 3,
+    }
+}
+
+/// Features returns a matrix of features of a package.
+///
+/// Each row is a feature and each column is something a feature enables,
+/// a dependency or another feature, which are marked by `+`.
+pub fn features(package: &Package) -> String {
+    let mut features = package.features.iter().collect::<Vec<_>>();
+    features.sort();
+
+    let enabled = features
+        .iter()
+        .flat_map(|(_, enables)| enables.iter().cloned())
+        .collect::<BTreeSet<_>>();
+
+    let mut headers = vec!["feature".to_owned()];
+    headers.extend(enabled.iter().cloned());
+
+    let records = features
+        .iter()
+        .map(|(feature, enables)| {
+            let mut record = vec![feature.to_string()];
+            record.extend(
+                enabled
+                    .iter()
+                    .map(|item| if enables.contains(item) { "+" } else { "" }.to_owned()),
+            );
+            record
+        })
+        .collect();
+
+    let mut grid = build_grid_from_records(headers, records);
+    Style::psql().change(&mut grid);
+    grid.to_string()
+}
+
+/// Benchmark is a result of a benchmark together with an optional result of a baseline run.
+///
+/// Times are kept in nanoseconds as it's done by criterion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Benchmark {
+    /// Name of the benchmark
+    pub name: String,
+    /// Mean time of a baseline run
+    pub base: Option<f64>,
+    /// Mean time of the latest run
+    pub new: f64,
+}
+
+impl Benchmark {
+    /// From criterion builds a benchmark from a content of criterion's `estimates.json` files.
+    pub fn from_criterion(
+        name: &str,
+        base: Option<&str>,
+        new: &str,
+    ) -> Result<Self, serde_json::Error> {
+        let base = match base {
+            Some(base) => Some(mean_estimate(base)?),
+            None => None,
+        };
+
+        Ok(Self {
+            name: name.to_owned(),
+            base,
+            new: mean_estimate(new)?,
+        })
+    }
+}
+
+fn mean_estimate(estimates: &str) -> Result<f64, serde_json::Error> {
+    let mut estimates: serde_json::Value = serde_json::from_str(estimates)?;
+    serde_json::from_value(estimates["mean"]["point_estimate"].take())
+}
+
+/// Read criterion reads all benchmarks from a criterion output directory, usually it's `target/criterion`.
+///
+/// A benchmark name is a path to its directory, so benchmarks from groups are named like `group/benchmark`.
+/// A baseline is taken from a `base` directory which criterion keeps for a previous run.
+pub fn read_criterion<P: AsRef<Path>>(dir: P) -> io::Result<Vec<Benchmark>> {
+    let dir = dir.as_ref();
+    let mut benchmarks = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(path) = dirs.pop() {
+        let new = path.join("new").join("estimates.json");
+        if new.is_file() {
+            let name = path.strip_prefix(dir).unwrap_or(&path);
+            let name = name.to_string_lossy().replace('\\', "/");
+            let base = read_optional(path.join("base").join("estimates.json"))?;
+            let new = fs::read_to_string(new)?;
+
+            let benchmark = Benchmark::from_criterion(&name, base.as_deref(), &new)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            benchmarks.push(benchmark);
+            continue;
+        }
+
+        for entry in fs::read_dir(&path)? {
+            let entry = entry?;
+            // a report directory is generated by criterion and doesn't contain results
+            if entry.file_type()?.is_dir() && entry.file_name() != "report" {
+                dirs.push(entry.path());
+            }
+        }
+    }
+
+    benchmarks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(benchmarks)
+}
+
+fn read_optional(path: PathBuf) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Benchmarks returns a table which compares benchmarks with their baselines.
+///
+/// Faster results are painted green and slower red, with a relative change appended.
+/// Both times in a row are printed in the same units so they're easy to compare.
+pub fn benchmarks(benchmarks: &[Benchmark]) -> String {
+    let headers = vec!["benchmark".to_owned(), "base".to_owned(), "new".to_owned()];
+    let records = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let (unit, scale) = time_unit(benchmark.base.unwrap_or(benchmark.new));
+            let format = |time: f64| format!("{:.2} {}", time / scale, unit);
+            vec![
+                benchmark.name.clone(),
+                benchmark.base.map(format).unwrap_or_else(|| "-".to_owned()),
+                format(benchmark.new),
+            ]
+        })
+        .collect();
+
+    let mut grid = build_grid_from_records(headers, records);
+    Style::psql().change(&mut grid);
+    Baseline::column(1).delta().change(&mut grid);
+    grid.to_string()
+}
+
+fn time_unit(nanoseconds: f64) -> (&'static str, f64) {
+    match nanoseconds {
+        t if t < 1e3 => ("ns", 1.0),
+        t if t < 1e6 => ("µs", 1e3),
+        t if t < 1e9 => ("ms", 1e6),
+        _ => ("s", 1e9),
+    }
+}
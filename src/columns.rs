@@ -0,0 +1,334 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// ReorderColumns puts columns with given headers first, in the given order.
+///
+/// Columns which are not listed keep their order and go after the listed ones,
+/// unless [`ReorderColumns::only`] is set. Unknown names are ignored,
+/// so an order can come straight from a user, e.g. from a command line flag.
+///
+/// A grid built without a header is left as it is.
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+///     use tabled::{table, ReorderColumns, Style, Tabled};
+///
+///     #[derive(Tabled)]
+///     struct File {
+///         name: &'static str,
+///         date: &'static str,
+///         size: usize,
+///     }
+///
+///     let data = vec![File { name: "README.md", date: "2021-03-01", size: 1024 }];
+///     let table = table!(&data, Style::psql(), ReorderColumns::new(["name", "size"]));
+///
+///     assert_eq!(table, concat!(
+///         "   name    | size |    date    \n",
+///         "-----------+------+------------\n",
+///         " README.md | 1024 | 2021-03-01 \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReorderColumns {
+    names: Vec<String>,
+    only: bool,
+}
+
+impl ReorderColumns {
+    /// New creates an option which orders columns by names of their headers.
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            only: false,
+        }
+    }
+
+    /// Only removes columns which are not listed.
+    pub fn only(mut self) -> Self {
+        self.only = true;
+        self
+    }
+}
+
+impl TableOption for ReorderColumns {
+    fn change(&self, grid: &mut Grid) {
+        if !grid.has_header() {
+            return;
+        }
+
+        let mut order = Vec::new();
+        for name in &self.names {
+            if let Some(column) = find_column(grid, name) {
+                if !order.contains(&column) {
+                    order.push(column);
+                }
+            }
+        }
+
+        let count_listed = order.len();
+        for column in 0..grid.count_columns() {
+            if !order.contains(&column) {
+                order.push(column);
+            }
+        }
+
+        // columns are put in place one by one, `positions` tracks where the original columns are now
+        let mut positions = (0..grid.count_columns()).collect::<Vec<_>>();
+        let mut columns = positions.clone();
+        for (target, original) in order.into_iter().enumerate() {
+            let current = positions[original];
+            if target != current {
+                let _ = grid.swap_columns(target, current);
+                columns.swap(target, current);
+                positions[columns[target]] = target;
+                positions[columns[current]] = current;
+            }
+        }
+
+        if self.only {
+            while grid.count_columns() > count_listed {
+                let _ = grid.remove_column(grid.count_columns() - 1);
+            }
+        }
+    }
+}
+
+/// RemoveColumn removes a column with a given header.
+///
+/// If there's no such column, or a grid is built without a header, the table is left as it is.
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+///     use tabled::{table, RemoveColumn, Style, Tabled};
+///
+///     #[derive(Tabled)]
+///     struct File {
+///         name: &'static str,
+///         checksum: &'static str,
+///     }
+///
+///     let data = vec![File { name: "README.md", checksum: "d41d8cd9" }];
+///     let table = table!(&data, Style::psql(), RemoveColumn::new("checksum"));
+///
+///     assert_eq!(table, concat!(
+///         "   name    \n",
+///         "-----------\n",
+///         " README.md \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RemoveColumn {
+    name: String,
+}
+
+impl RemoveColumn {
+    /// New creates an option which removes a column by a name of its header.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl TableOption for RemoveColumn {
+    fn change(&self, grid: &mut Grid) {
+        if !grid.has_header() {
+            return;
+        }
+
+        if let Some(column) = find_column(grid, &self.name) {
+            let _ = grid.remove_column(column);
+        }
+    }
+}
+
+/// ComputedColumn adds a column which values are computed from other cells of their rows,
+/// e.g. a ratio of two columns.
+///
+/// A function gets a content of all cells of a row before the column is added.
+/// By default the column is appended, [`ComputedColumn::position`] inserts it before a given column.
+///
+/// The option is supposed to be applied before options which change a content of cells.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, ComputedColumn, Style};
+///     let data = vec![("apples", 10, 4), ("pears", 6, 3)];
+///     let ratio = |row: &[&str]| {
+///         let sold: f64 = row[1].parse().unwrap();
+///         let returned: f64 = row[2].parse().unwrap();
+///         format!("{:.2}", returned / sold)
+///     };
+///
+///     let table = table!(&data, Style::psql(), ComputedColumn::new("ratio", ratio));
+///
+///     assert_eq!(table, concat!(
+///         "  &str  | i32 | i32 | ratio \n",
+///         "--------+-----+-----+-------\n",
+///         " apples | 10  |  4  | 0.40  \n",
+///         " pears  |  6  |  3  | 0.50  \n",
+///     ));
+/// ```
+pub struct ComputedColumn {
+    header: String,
+    position: Option<usize>,
+    compute: Compute,
+}
+
+type Compute = Box<dyn Fn(&[&str]) -> String>;
+
+impl ComputedColumn {
+    /// New creates an option which appends a column with a given header.
+    pub fn new<S, F>(header: S, compute: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&[&str]) -> String + 'static,
+    {
+        Self {
+            header: header.into(),
+            position: None,
+            compute: Box::new(compute),
+        }
+    }
+
+    /// Position sets an index of the column, the columns after it are shifted.
+    ///
+    /// If it's out of the grid the column is appended.
+    pub fn position(mut self, column: usize) -> Self {
+        self.position = Some(column);
+        self
+    }
+}
+
+impl TableOption for ComputedColumn {
+    fn change(&self, grid: &mut Grid) {
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        let values = (first_row..grid.count_rows())
+            .map(|row| {
+                let cells = (0..grid.count_columns())
+                    .map(|column| grid.get_cell(row, column).unwrap_or_default())
+                    .collect::<Vec<_>>();
+                (self.compute)(&cells)
+            })
+            .collect::<Vec<_>>();
+
+        let column = match self.position {
+            Some(column) if column < grid.count_columns() => column,
+            _ => grid.count_columns(),
+        };
+        if grid.insert_column(column).is_err() {
+            return;
+        }
+
+        if grid.has_header() {
+            grid.set(
+                Entity::Cell(0, column),
+                Settings::new().text(self.header.clone()),
+            );
+        }
+
+        for (row, value) in (first_row..).zip(values) {
+            grid.set(Entity::Cell(row, column), Settings::new().text(value));
+        }
+    }
+}
+
+/// RenameHeader changes a header of a column found by its index or its current name,
+/// so names shown to a user can differ from names of fields.
+///
+/// If there's no such column, or a grid is built without a header, the table is left as it is.
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+///     use tabled::{table, RenameHeader, Style, Tabled};
+///
+///     #[derive(Tabled)]
+///     struct Release {
+///         version: &'static str,
+///         created_at: &'static str,
+///     }
+///
+///     let data = vec![Release { version: "0.1.3", created_at: "2021-03-01" }];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         RenameHeader::new("created_at", "Created"),
+///         RenameHeader::column(0, "Version"),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         " Version |  Created   \n",
+///         "---------+------------\n",
+///         "  0.1.3  | 2021-03-01 \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RenameHeader {
+    column: HeaderColumn,
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+enum HeaderColumn {
+    Index(usize),
+    Header(String),
+}
+
+impl RenameHeader {
+    /// New creates an option which renames a column with a given header.
+    pub fn new<S, N>(header: S, name: N) -> Self
+    where
+        S: Into<String>,
+        N: Into<String>,
+    {
+        Self {
+            column: HeaderColumn::Header(header.into()),
+            name: name.into(),
+        }
+    }
+
+    /// Column creates an option which renames a column with a given index.
+    pub fn column<N: Into<String>>(column: usize, name: N) -> Self {
+        Self {
+            column: HeaderColumn::Index(column),
+            name: name.into(),
+        }
+    }
+}
+
+impl TableOption for RenameHeader {
+    fn change(&self, grid: &mut Grid) {
+        if !grid.has_header() {
+            return;
+        }
+
+        let column = match &self.column {
+            HeaderColumn::Index(column) if *column < grid.count_columns() => *column,
+            HeaderColumn::Index(_) => return,
+            HeaderColumn::Header(header) => match find_column(grid, header) {
+                Some(column) => column,
+                None => return,
+            },
+        };
+
+        grid.set(
+            Entity::Cell(0, column),
+            Settings::new().text(self.name.clone()),
+        );
+    }
+}
+
+fn find_column(grid: &Grid, name: &str) -> Option<usize> {
+    (0..grid.count_columns()).find(|&column| grid.get_cell(0, column) == Some(name))
+}
@@ -0,0 +1,73 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Details attaches a detail line under data rows, like an error message under a failed test.
+///
+/// A function is called with an index of a record, the first data row is 0,
+/// and rows for which it returns `None` are left without a detail.
+/// A detail spans the whole width of a table and is wrapped if it doesn't fit into it.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Details, Style};
+///     let data = vec![("parse", "ok"), ("render", "failed")];
+///     let errors = vec![None, Some("index out of bounds")];
+///
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Details::new(|i| errors[i].map(String::from)),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "  &str  |  &str  \n",
+///         "--------+--------\n",
+///         " parse  |   ok   \n",
+///         " render | failed \n",
+///         " index out of    \n",
+///         " bounds          \n",
+///     ));
+/// ```
+pub struct Details<F> {
+    f: F,
+    show: bool,
+}
+
+impl<F> Details<F>
+where
+    F: Fn(usize) -> Option<String>,
+{
+    /// New creates details produced by a given function.
+    pub fn new(f: F) -> Self {
+        Self { f, show: true }
+    }
+
+    /// Show sets whether details are rendered.
+    ///
+    /// Hidden details are kept in a grid so they can be shown later by [`Grid::show_details`](../papergrid/struct.Grid.html#method.show_details).
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = show;
+        self
+    }
+}
+
+impl<F> TableOption for Details<F>
+where
+    F: Fn(usize) -> Option<String>,
+{
+    fn change(&self, grid: &mut Grid) {
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        for row in first_row..grid.count_rows() {
+            match (self.f)(row - first_row) {
+                Some(detail) => {
+                    let _ = grid.set_row_detail(row, detail);
+                }
+                None => grid.remove_row_detail(row),
+            }
+        }
+
+        grid.show_details(self.show);
+    }
+}
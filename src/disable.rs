@@ -26,18 +26,21 @@ impl<R: RangeBounds<usize>> TableOption for Disable<R> {
             Self::Column(range) => {
                 let (x, y) =
                     bounds_to_usize(range.start_bound(), range.end_bound(), grid.count_columns());
-                for (shifted, i) in (x..y).enumerate() {
-                    grid.remove_column(i - shifted);
+                for _ in x..y {
+                    // columns after a removed one are shifted so the same index is removed every time,
+                    // a part of the range which is out of the grid is ignored
+                    if grid.remove_column(x).is_err() {
+                        break;
+                    }
                 }
             }
             Self::Row(range) => {
                 let (x, y) =
                     bounds_to_usize(range.start_bound(), range.end_bound(), grid.count_rows());
-
-                // It's kindof a bad design that we must controll shift.
-                // It basically unveils an implementation...
-                for (shifted, i) in (x..y).enumerate() {
-                    grid.remove_row(i - shifted);
+                for _ in x..y {
+                    if grid.remove_row(x).is_err() {
+                        break;
+                    }
                 }
             }
         }
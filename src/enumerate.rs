@@ -0,0 +1,77 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// Enumerate prepends a column with numbers of data rows.
+///
+/// By default rows are numbered from 1 and the column is titled `#`,
+/// a table without a header gets no title.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Enumerate, Style};
+///     let data = vec!["Fedora", "OpenSUSE"];
+///     let table = table!(&data, Style::psql(), Enumerate::new());
+///
+///     assert_eq!(table, concat!(
+///         " # |   &str   \n",
+///         "---+----------\n",
+///         " 1 |  Fedora  \n",
+///         " 2 | OpenSUSE \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Enumerate {
+    start: usize,
+    header: String,
+}
+
+impl Enumerate {
+    /// New creates an option which numbers rows from 1.
+    pub fn new() -> Self {
+        Self {
+            start: 1,
+            header: String::from("#"),
+        }
+    }
+
+    /// Start sets a number of the first data row.
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Header sets a title of the column.
+    pub fn header<S: Into<String>>(mut self, header: S) -> Self {
+        self.header = header.into();
+        self
+    }
+}
+
+impl Default for Enumerate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for Enumerate {
+    fn change(&self, grid: &mut Grid) {
+        if grid.insert_column(0).is_err() {
+            return;
+        }
+
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        if grid.has_header() {
+            grid.set(Entity::Cell(0, 0), Settings::new().text(&self.header));
+        }
+
+        for row in first_row..grid.count_rows() {
+            let number = self.start + row - first_row;
+            grid.set(
+                Entity::Cell(row, 0),
+                Settings::new().text(number.to_string()),
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Filter hides data rows for which a predicate returns false, a header is always kept.
+///
+/// A predicate gets a content of cells of a row, the source data is not touched.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Filter, Style};
+///     let data = vec![("Fedora", 34), ("Debian", 11), ("OpenSUSE", 15)];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Filter(|record: &[&str]| record[1].parse::<u32>().unwrap() > 12),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "   &str   | i32 \n",
+///         "----------+-----\n",
+///         "  Fedora  | 34  \n",
+///         " OpenSUSE | 15  \n",
+///     ));
+/// ```
+pub struct Filter<F: Fn(&[&str]) -> bool>(pub F);
+
+impl<F: Fn(&[&str]) -> bool> TableOption for Filter<F> {
+    fn change(&self, grid: &mut Grid) {
+        // rows are removed from the end so indexes of the rest stay the same
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        for row in (first_row..grid.count_rows()).rev() {
+            let record = (0..grid.count_columns())
+                .map(|column| grid.get_cell(row, column).unwrap_or_default())
+                .collect::<Vec<_>>();
+            if !(self.0)(&record) {
+                let _ = grid.remove_row(row);
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use papergrid::{Entity, Grid, HorizontalLine, Settings};
+
+use crate::{Aggregate, TableOption};
+
+/// Footer appends a row with labels and values aggregated over data rows of a table.
+///
+/// Cells which don't start with a number are not taken into account by aggregates except [`Aggregate::Count`].
+/// A footer can be made distinct by formatting its cells and by a line which separates it from data.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Aggregate, Footer, HorizontalLine, Style};
+///     let data = vec![("apples", 10), ("pears", 5)];
+///
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Footer::new()
+///             .label(0, "Total")
+///             .aggregate(1, Aggregate::Sum)
+///             .separator(HorizontalLine::new('-', '+', None, None)),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "  &str  | i32 \n",
+///         "--------+-----\n",
+///         " apples | 10  \n",
+///         " pears  |  5  \n",
+///         "--------+-----\n",
+///         " Total  | 15  \n",
+///     ));
+/// ```
+pub struct Footer {
+    labels: Vec<(usize, String)>,
+    aggregates: Vec<(usize, Aggregate)>,
+    format: Box<dyn Fn(&str) -> String>,
+    separator: Option<HorizontalLine>,
+}
+
+impl Footer {
+    /// New creates an empty footer.
+    pub fn new() -> Self {
+        Self {
+            labels: Vec::new(),
+            aggregates: Vec::new(),
+            format: Box::new(|s| s.to_owned()),
+            separator: None,
+        }
+    }
+
+    /// Label puts a text into a given column of a footer.
+    pub fn label<S: Into<String>>(mut self, column: usize, label: S) -> Self {
+        self.labels.push((column, label.into()));
+        self
+    }
+
+    /// Aggregate adds a column which is aggregated in a footer.
+    pub fn aggregate(mut self, column: usize, aggregate: Aggregate) -> Self {
+        self.aggregates.push((column, aggregate));
+        self
+    }
+
+    /// Format sets a function which styles non empty cells of a footer, like making them bold.
+    pub fn format<F: Fn(&str) -> String + 'static>(mut self, f: F) -> Self {
+        self.format = Box::new(f);
+        self
+    }
+
+    /// Separator sets a line which goes between data rows and a footer.
+    pub fn separator(mut self, line: HorizontalLine) -> Self {
+        self.separator = Some(line);
+        self
+    }
+}
+
+impl Default for Footer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableOption for Footer {
+    fn change(&self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        let mut footer = vec![String::new(); count_columns];
+        for (column, label) in &self.labels {
+            if *column < count_columns {
+                footer[*column] = label.clone();
+            }
+        }
+
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        for &(column, aggregate) in &self.aggregates {
+            if column < count_columns {
+                let values =
+                    (first_row..grid.count_rows()).filter_map(|row| grid.get_cell(row, column));
+                footer[column] = aggregate.compute(values);
+            }
+        }
+
+        grid.push_row();
+        let row = grid.count_rows() - 1;
+        for (column, text) in footer.into_iter().enumerate() {
+            if !text.is_empty() {
+                let text = (self.format)(&text);
+                grid.set(Entity::Cell(row, column), Settings::new().text(text));
+            }
+        }
+
+        if let Some(line) = &self.separator {
+            let _ = grid.set_horizontal_line(row, line.clone());
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use papergrid::{Entity, Grid, Settings};
 
 use crate::{Object, TableOption};
@@ -58,5 +60,164 @@ impl<O: Object, F: Fn(&str) -> String> TableOption for Format<O, F> {
 ///     let data: Vec<&'static str> = Vec::new();
 ///     table!(&data, Format(Full, multiline(|s| { format!("{}", s) })));
 pub fn multiline<F: 'static + Fn(&str) -> String>(f: F) -> Box<dyn Fn(&str) -> String> {
-    Box::new(move |s: &str| s.lines().map(|s| f(s)).collect::<Vec<_>>().join("\n"))
+    Box::new(move |s: &str| s.lines().map(&f).collect::<Vec<_>>().join("\n"))
+}
+
+/// Zebra formats every second data row so rows of a wide table are easier to follow.
+///
+/// The header row is left untouched and multiline content is formatted line by line.
+/// To get a correct layout for colored output a `color` feature must be turned on.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Style, Zebra};
+///     let data = vec!["Fedora", "OpenSUSE", "Endeavouros"];
+///     let table = table!(&data, Style::psql(), Zebra(|s| format!("~{}~", s)));
+///
+///     assert_eq!(table, concat!(
+///         "    &str     \n",
+///         "-------------\n",
+///         "   Fedora    \n",
+///         " ~OpenSUSE~  \n",
+///         " Endeavouros \n",
+///     ));
+/// ```
+pub struct Zebra<F: Fn(&str) -> String>(pub F);
+
+impl Zebra<fn(&str) -> String> {
+    /// Dimmed returns a zebra which dims every second data row by an ANSI escape sequence.
+    pub fn dimmed() -> Self {
+        Zebra(|s| format!("\u{1b}[2m{}\u{1b}[0m", s))
+    }
+}
+
+impl Zebra<Box<dyn Fn(&str) -> String>> {
+    /// Background returns a zebra which sets a background color of every second data row.
+    ///
+    /// The color is an index in a 256 colors ANSI palette.
+    pub fn background(color: u8) -> Self {
+        Zebra(Box::new(move |s| {
+            format!("\u{1b}[48;5;{}m{}\u{1b}[0m", color, s)
+        }))
+    }
+}
+
+impl<F: Fn(&str) -> String> TableOption for Zebra<F> {
+    fn change(&self, grid: &mut Grid) {
+        for row in (2..grid.count_rows()).step_by(2) {
+            for column in 0..grid.count_columns() {
+                let content = grid.get_cell_content(row, column);
+                let content = content
+                    .lines()
+                    .map(|line| (self.0)(line))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                grid.set(Entity::Cell(row, column), Settings::new().text(content))
+            }
+        }
+    }
+}
+
+/// FormatIf modifies only the cells which satisfy a predicate.
+///
+/// The predicate is given a [`Record`](./struct.Record.html) so it can check
+/// a cell content as well as its position and the whole row the cell belongs to.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, FormatIf, Full, Style};
+///     let data = vec![("Build", "OK"), ("Tests", "FAILED")];
+///
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         FormatIf(Full, |r| r.row() > 0 && r.field(1) == Some("FAILED"), |s| format!("!{}", s)),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "  &str  |  &str   \n",
+///         "--------+---------\n",
+///         " Build  |   OK    \n",
+///         " !Tests | !FAILED \n",
+///     ));
+/// ```
+pub struct FormatIf<O, P, F>(pub O, pub P, pub F)
+where
+    O: Object,
+    P: Fn(&Record) -> bool,
+    F: Fn(&str) -> String;
+
+impl<O, P, F> TableOption for FormatIf<O, P, F>
+where
+    O: Object,
+    P: Fn(&Record) -> bool,
+    F: Fn(&str) -> String,
+{
+    fn change(&self, grid: &mut Grid) {
+        let cells = self.0.cells(grid.count_rows(), grid.count_columns());
+        let mut records: HashMap<usize, Vec<String>> = HashMap::new();
+        let mut changes = Vec::new();
+        for (row, column) in cells {
+            let fields = records.entry(row).or_insert_with(|| {
+                (0..grid.count_columns())
+                    .map(|column| grid.get_cell_content(row, column).to_owned())
+                    .collect()
+            });
+
+            let record = Record {
+                row,
+                column,
+                fields,
+            };
+
+            if (self.1)(&record) {
+                changes.push((row, column, (self.2)(record.text())));
+            }
+        }
+
+        // the changes are applied only after all predicates are checked
+        // so a predicate always sees an original content
+        for (row, column, content) in changes {
+            grid.set(Entity::Cell(row, column), Settings::new().text(content))
+        }
+    }
+}
+
+/// Record represents a cell together with a row it belongs to.
+///
+/// It's used by [`FormatIf`](./struct.FormatIf.html) predicates.
+#[derive(Debug)]
+pub struct Record<'a> {
+    row: usize,
+    column: usize,
+    fields: &'a [String],
+}
+
+impl<'a> Record<'a> {
+    /// Row returns an index of a row of the cell
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Column returns an index of a column of the cell
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Text returns a content of the cell
+    pub fn text(&self) -> &'a str {
+        &self.fields[self.column]
+    }
+
+    /// Field returns a content of a cell in the same row
+    pub fn field(&self, column: usize) -> Option<&'a str> {
+        self.fields.get(column).map(|s| s.as_str())
+    }
+
+    /// Fields returns a content of all cells in the row
+    pub fn fields(&self) -> &'a [String] {
+        self.fields
+    }
 }
@@ -0,0 +1,104 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::TableOption;
+
+/// Height controls a height of rows of a table.
+///
+/// [`Height::limit`] cuts a content of cells which has more lines than a limit,
+/// [`Height::increase`] makes rows which are lower than a given height higher.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Height, Style};
+///     let data = vec!["one\ntwo\nthree"];
+///     let table = table!(&data, Style::psql(), Height::limit(2).suffix("..."));
+///
+///     assert_eq!(table, concat!(
+///         "  &str  \n",
+///         "--------\n",
+///         "  one   \n",
+///         " two... \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Height {
+    kind: HeightKind,
+}
+
+#[derive(Debug, Clone)]
+enum HeightKind {
+    Limit { lines: usize, suffix: String },
+    Increase(usize),
+}
+
+impl Height {
+    /// Limit creates an option which cuts a content of cells to at most a given number of lines.
+    ///
+    /// A limit doesn't include a padding of cells.
+    pub fn limit(lines: usize) -> Self {
+        Self {
+            kind: HeightKind::Limit {
+                lines,
+                suffix: String::new(),
+            },
+        }
+    }
+
+    /// Increase creates an option which sets a minimal height of rows, including their padding.
+    ///
+    /// An extra space is added at the bottom of cells.
+    pub fn increase(height: usize) -> Self {
+        Self {
+            kind: HeightKind::Increase(height),
+        }
+    }
+
+    /// Suffix sets a text which is appended to the last line of a cut content, like `...`.
+    ///
+    /// It's used only by a limiting option.
+    pub fn suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        if let HeightKind::Limit { suffix: s, .. } = &mut self.kind {
+            *s = suffix.into();
+        }
+
+        self
+    }
+}
+
+impl TableOption for Height {
+    fn change(&self, grid: &mut Grid) {
+        match &self.kind {
+            HeightKind::Limit { lines, suffix } => {
+                for row in 0..grid.count_rows() {
+                    for column in 0..grid.count_columns() {
+                        let text = grid.get_cell_content(row, column);
+                        if text.lines().count() <= *lines {
+                            continue;
+                        }
+
+                        let text = cut_lines(text, *lines, suffix);
+                        grid.set(Entity::Cell(row, column), Settings::new().text(text));
+                    }
+                }
+            }
+            HeightKind::Increase(height) => {
+                let (_, heights) = grid.estimate_dimensions();
+                for (row, current) in heights.into_iter().enumerate() {
+                    if current < *height {
+                        let _ = grid.set_row_height(row, *height);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn cut_lines(text: &str, count: usize, suffix: &str) -> String {
+    let mut lines = text.lines().take(count).collect::<Vec<_>>().join("\n");
+    if count > 0 {
+        lines.push_str(suffix);
+    }
+
+    lines
+}
@@ -0,0 +1,338 @@
+use std::collections::HashSet;
+
+use papergrid::{CellBorder, Grid};
+
+use crate::{Object, TableOption};
+
+/// Highlight draws a frame around a set of cells.
+///
+/// The frame overrides a style of a table only on a boundary of the region,
+/// so it can be used to draw attention to a cell, a column or any combination of them.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Border, Cell, Highlight, Style};
+///     let data = vec!["Hello", "World"];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Highlight(Cell(1, 0), Border::filled('*')),
+///     );
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             "  &str   \n",
+///             "*********\n",
+///             "* Hello *\n",
+///             "*********\n",
+///             "  World  \n",
+///         )
+///     );
+/// ```
+pub struct Highlight<O: Object>(pub O, pub Border);
+
+impl<O: Object> TableOption for Highlight<O> {
+    fn change(&self, grid: &mut Grid) {
+        let (count_rows, count_columns) = (grid.count_rows(), grid.count_columns());
+        let cells = self
+            .0
+            .cells(count_rows, count_columns)
+            .into_iter()
+            .filter(|&(row, column)| row < count_rows && column < count_columns)
+            .collect::<HashSet<_>>();
+
+        draw_sides(grid, &cells, &self.1);
+        draw_corners(grid, &cells, &self.1);
+    }
+}
+
+/// Border is a set of characters of a frame made by [`Highlight`].
+#[derive(Debug, Clone)]
+pub struct Border {
+    top: char,
+    bottom: char,
+    left: char,
+    right: char,
+    top_left_corner: char,
+    top_right_corner: char,
+    bottom_left_corner: char,
+    bottom_right_corner: char,
+}
+
+impl Border {
+    /// Full constructs a border with a different character for each side and corner.
+    #[allow(clippy::too_many_arguments)]
+    pub fn full(
+        top: char,
+        bottom: char,
+        left: char,
+        right: char,
+        top_left_corner: char,
+        top_right_corner: char,
+        bottom_left_corner: char,
+        bottom_right_corner: char,
+    ) -> Self {
+        Self {
+            top,
+            bottom,
+            left,
+            right,
+            top_left_corner,
+            top_right_corner,
+            bottom_left_corner,
+            bottom_right_corner,
+        }
+    }
+
+    /// Filled constructs a border which uses the same character everywhere.
+    pub fn filled(c: char) -> Self {
+        Self::full(c, c, c, c, c, c, c, c)
+    }
+
+    /// Empty constructs a border made of spaces, it makes an edge of a cell look open.
+    pub fn empty() -> Self {
+        Self::filled(' ')
+    }
+
+    /// Sets a character of a top side.
+    pub fn top(mut self, c: char) -> Self {
+        self.top = c;
+        self
+    }
+
+    /// Returns a character of a top side.
+    pub fn get_top(&self) -> char {
+        self.top
+    }
+
+    /// Sets a character of a bottom side.
+    pub fn bottom(mut self, c: char) -> Self {
+        self.bottom = c;
+        self
+    }
+
+    /// Returns a character of a bottom side.
+    pub fn get_bottom(&self) -> char {
+        self.bottom
+    }
+
+    /// Sets a character of a left side.
+    pub fn left(mut self, c: char) -> Self {
+        self.left = c;
+        self
+    }
+
+    /// Returns a character of a left side.
+    pub fn get_left(&self) -> char {
+        self.left
+    }
+
+    /// Sets a character of a right side.
+    pub fn right(mut self, c: char) -> Self {
+        self.right = c;
+        self
+    }
+
+    /// Returns a character of a right side.
+    pub fn get_right(&self) -> char {
+        self.right
+    }
+
+    /// Sets a character of a top left corner.
+    pub fn top_left_corner(mut self, c: char) -> Self {
+        self.top_left_corner = c;
+        self
+    }
+
+    /// Returns a character of a top left corner.
+    pub fn get_top_left_corner(&self) -> char {
+        self.top_left_corner
+    }
+
+    /// Sets a character of a top right corner.
+    pub fn top_right_corner(mut self, c: char) -> Self {
+        self.top_right_corner = c;
+        self
+    }
+
+    /// Returns a character of a top right corner.
+    pub fn get_top_right_corner(&self) -> char {
+        self.top_right_corner
+    }
+
+    /// Sets a character of a bottom left corner.
+    pub fn bottom_left_corner(mut self, c: char) -> Self {
+        self.bottom_left_corner = c;
+        self
+    }
+
+    /// Returns a character of a bottom left corner.
+    pub fn get_bottom_left_corner(&self) -> char {
+        self.bottom_left_corner
+    }
+
+    /// Sets a character of a bottom right corner.
+    pub fn bottom_right_corner(mut self, c: char) -> Self {
+        self.bottom_right_corner = c;
+        self
+    }
+
+    /// Returns a character of a bottom right corner.
+    pub fn get_bottom_right_corner(&self) -> char {
+        self.bottom_right_corner
+    }
+}
+
+/// CellBorders sets a [`Border`] to each cell of an object separately.
+///
+/// Unlike [`Highlight`] every cell gets its own frame,
+/// sides shared with neighbours are changed too as they are the same line.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Border, Cell, CellBorders};
+///     let data = vec!["Hello", "World"];
+///     let table = table!(
+///         &data,
+///         CellBorders(Cell(2, 0), Border::filled('#').top('=')),
+///     );
+///
+///     assert_eq!(
+///         table,
+///         concat!(
+///             "+-------+\n",
+///             "| &str  |\n",
+///             "+-------+\n",
+///             "| Hello |\n",
+///             "#=======#\n",
+///             "# World #\n",
+///             "#########\n",
+///         )
+///     );
+/// ```
+pub struct CellBorders<O: Object>(pub O, pub Border);
+
+impl<O: Object> TableOption for CellBorders<O> {
+    fn change(&self, grid: &mut Grid) {
+        let (count_rows, count_columns) = (grid.count_rows(), grid.count_columns());
+        let border = &self.1;
+        for (row, column) in self.0.cells(count_rows, count_columns) {
+            let cell_border = CellBorder::full(
+                border.top,
+                border.bottom,
+                border.left,
+                border.right,
+                border.top_left_corner,
+                border.top_right_corner,
+                border.bottom_left_corner,
+                border.bottom_right_corner,
+            );
+
+            let _ = grid.set_cell_border(row, column, cell_border);
+        }
+    }
+}
+
+fn draw_sides(grid: &mut Grid, cells: &HashSet<(usize, usize)>, border: &Border) {
+    for &(row, column) in cells {
+        let is_outside = |r: Option<usize>, c: Option<usize>| match (r, c) {
+            (Some(r), Some(c)) => !cells.contains(&(r, c)),
+            _ => true,
+        };
+
+        let mut cell_border = CellBorder::new();
+        if is_outside(row.checked_sub(1), Some(column)) {
+            cell_border = cell_border.top(border.top);
+        }
+        if is_outside(Some(row + 1), Some(column)) {
+            cell_border = cell_border.bottom(border.bottom);
+        }
+        if is_outside(Some(row), column.checked_sub(1)) {
+            cell_border = cell_border.left(border.left);
+        }
+        if is_outside(Some(row), Some(column + 1)) {
+            cell_border = cell_border.right(border.right);
+        }
+
+        set_border(grid, row, column, cell_border);
+    }
+}
+
+// A corner is a point where 4 cells meet, it's drawn depending on which of them are highlighted.
+fn draw_corners(grid: &mut Grid, cells: &HashSet<(usize, usize)>, border: &Border) {
+    let points = cells
+        .iter()
+        .flat_map(|&(row, column)| {
+            vec![
+                (row, column),
+                (row, column + 1),
+                (row + 1, column),
+                (row + 1, column + 1),
+            ]
+        })
+        .collect::<HashSet<_>>();
+
+    for (row, column) in points {
+        let has = |r: Option<usize>, c: Option<usize>| match (r, c) {
+            (Some(r), Some(c)) => cells.contains(&(r, c)),
+            _ => false,
+        };
+
+        let top_left = has(row.checked_sub(1), column.checked_sub(1));
+        let top_right = has(row.checked_sub(1), Some(column));
+        let bottom_left = has(Some(row), column.checked_sub(1));
+        let bottom_right = has(Some(row), Some(column));
+
+        let c = match (top_left, top_right, bottom_left, bottom_right) {
+            (true, true, true, true) => continue,
+            // outer corners
+            (false, false, false, true) => border.top_left_corner,
+            (false, false, true, false) => border.top_right_corner,
+            (false, true, false, false) => border.bottom_left_corner,
+            (true, false, false, false) => border.bottom_right_corner,
+            // inner corners
+            (false, true, true, true) => border.bottom_right_corner,
+            (true, false, true, true) => border.bottom_left_corner,
+            (true, true, false, true) => border.top_right_corner,
+            (true, true, true, false) => border.top_left_corner,
+            // straight lines
+            (true, true, false, false) => border.bottom,
+            (false, false, true, true) => border.top,
+            (true, false, true, false) => border.right,
+            (false, true, false, true) => border.left,
+            // cells touched only diagonally
+            (false, true, true, false) => border.top_right_corner,
+            (true, false, false, true) => border.top_left_corner,
+            (false, false, false, false) => continue,
+        };
+
+        // the character is set to a cell which has the highest priority for the point
+        if bottom_right {
+            set_border(grid, row, column, CellBorder::new().top_left_corner(c));
+        } else if bottom_left {
+            set_border(grid, row, column - 1, CellBorder::new().top_right_corner(c));
+        } else if top_right {
+            set_border(
+                grid,
+                row - 1,
+                column,
+                CellBorder::new().bottom_left_corner(c),
+            );
+        } else {
+            set_border(
+                grid,
+                row - 1,
+                column - 1,
+                CellBorder::new().bottom_right_corner(c),
+            );
+        }
+    }
+}
+
+// all cells are checked to be on the grid beforehand so an error can't happen
+fn set_border(grid: &mut Grid, row: usize, column: usize, border: CellBorder) {
+    let _ = grid.set_cell_border(row, column, border);
+}
@@ -0,0 +1,41 @@
+use papergrid::{Entity, Grid, Padding, Settings};
+
+use crate::{Object, TableOption};
+
+/// Indent sets a padding of cells, each side of it is set independently.
+///
+/// A padded area can be filled with a character other than a space to make it visible.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Indent, Padding, Row, Style};
+///     let data = vec!["Hello", "World"];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Indent(Row(1..), Padding::new(3, 0, 0, 1).fill('.')),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "  &str  \n",
+///         "--------\n",
+///         "...Hello\n",
+///         "........\n",
+///         "...World\n",
+///         "........\n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct Indent<O: Object>(pub O, pub Padding);
+
+impl<O: Object> TableOption for Indent<O> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+            grid.set(
+                Entity::Cell(row, column),
+                Settings::new().padding(self.1.clone()),
+            )
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use serde_json::{Map, Value};
+
+use crate::Builder;
+
+impl Builder {
+    /// From json creates a builder from an array of JSON objects.
+    ///
+    /// Columns are a union of keys of all objects.
+    /// Nested objects are flattened so their fields become columns named like `address.city`.
+    /// A single object is considered to be an array with one element.
+    ///
+    /// The method is available only with a `serde` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use tabled::{Builder, Style, TableOption};
+    ///     let value = serde_json::json!([
+    ///         {"name": "tabled", "owner": {"login": "zhiburt"}},
+    ///         {"name": "papergrid", "archived": false},
+    ///     ]);
+    ///
+    ///     let mut grid = Builder::from_json(&value).build();
+    ///     Style::psql().change(&mut grid);
+    ///
+    ///     assert_eq!(grid.to_string(), concat!(
+    ///         " archived |   name    | owner.login \n",
+    ///         "----------+-----------+-------------\n",
+    ///         "          |  tabled   |   zhiburt   \n",
+    ///         "  false   | papergrid |             \n",
+    ///     ));
+    /// ```
+    pub fn from_json(value: &Value) -> Self {
+        Self::from_maps(json_rows(value).into_iter().map(|row| {
+            let mut fields = Vec::new();
+            flatten_object(&row, "", &mut fields);
+            fields
+        }))
+    }
+
+    /// From json nested creates a builder from an array of JSON objects
+    /// where nested objects and arrays of objects are rendered as nested tables.
+    ///
+    /// The method is available only with a `serde` feature.
+    pub fn from_json_nested(value: &Value) -> Self {
+        Self::from_maps(json_rows(value).into_iter().map(|row| {
+            row.iter()
+                .map(|(key, value)| (key.clone(), nested_value(value)))
+                .collect::<Vec<_>>()
+        }))
+    }
+}
+
+// Each row is an object, scalars are put into a `value` column.
+fn json_rows(value: &Value) -> Vec<Map<String, Value>> {
+    let as_row = |value: &Value| match value {
+        Value::Object(object) => object.clone(),
+        value => {
+            let mut object = Map::new();
+            object.insert("value".to_owned(), value.clone());
+            object
+        }
+    };
+
+    match value {
+        Value::Array(values) => values.iter().map(as_row).collect(),
+        value => vec![as_row(value)],
+    }
+}
+
+fn flatten_object(object: &Map<String, Value>, prefix: &str, fields: &mut Vec<(String, String)>) {
+    for (key, value) in object {
+        let key = format!("{}{}", prefix, key);
+        match value {
+            Value::Object(object) if !object.is_empty() => {
+                flatten_object(object, &format!("{}.", key), fields)
+            }
+            value => fields.push((key, scalar_value(value))),
+        }
+    }
+}
+
+fn nested_value(value: &Value) -> String {
+    match value {
+        Value::Object(object) if !object.is_empty() => nested_table(value),
+        Value::Array(values) if values.iter().any(Value::is_object) => nested_table(value),
+        Value::Array(values) => values
+            .iter()
+            .map(nested_value)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        value => scalar_value(value),
+    }
+}
+
+fn nested_table(value: &Value) -> String {
+    let table = Builder::from_json_nested(value).build().to_string();
+    table.trim_end_matches('\n').to_owned()
+}
+
+fn scalar_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
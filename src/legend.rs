@@ -0,0 +1,74 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Legend adds a block under a table which explains symbols and colors used in it.
+///
+/// It's handy together with conditional formatting like [`FormatIf`](./struct.FormatIf.html)
+/// when the meaning of a mark isn't obvious.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, FormatIf, Full, Legend, Style};
+///     let data = vec![("build", "OK"), ("tests", "FAILED")];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         FormatIf(Full, |r| r.text() == "FAILED", |s| format!("{}!", s)),
+///         Legend::new()
+///             .symbol("!", "a step must be fixed before a release")
+///             .color(|s| format!("\u{1b}[31m{}\u{1b}[0m", s), "a blocking issue"),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         " &str  |  &str   \n",
+///         "-------+---------\n",
+///         " build |   OK    \n",
+///         " tests | FAILED! \n",
+///         "Legend:\n",
+///         "  ! a step must be fixed before a release\n",
+///         "  \u{1b}[31m■\u{1b}[0m a blocking issue\n",
+///     ));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Legend {
+    entries: Vec<(String, String)>,
+}
+
+impl Legend {
+    /// New creates an empty legend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Symbol adds an explanation of a symbol.
+    pub fn symbol<S, M>(mut self, symbol: S, meaning: M) -> Self
+    where
+        S: Into<String>,
+        M: Into<String>,
+    {
+        self.entries.push((symbol.into(), meaning.into()));
+        self
+    }
+
+    /// Color adds an explanation of a color.
+    ///
+    /// The function is the same one which is used to color cells,
+    /// it's applied to a sample square which is shown in the legend.
+    pub fn color<F, M>(self, color: F, meaning: M) -> Self
+    where
+        F: Fn(&str) -> String,
+        M: Into<String>,
+    {
+        self.symbol(color("■"), meaning)
+    }
+}
+
+impl TableOption for Legend {
+    fn change(&self, grid: &mut Grid) {
+        for (symbol, meaning) in &self.entries {
+            grid.push_legend_entry(symbol.as_str(), meaning.as_str());
+        }
+    }
+}
@@ -17,7 +17,8 @@
 //! The common and probably the best way to begin is to annotate your type with
 //! `#[derive(Tabled)]`. You can also implement it on your own as well.
 //!
-//! ```rust
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
 //!     use tabled::{Tabled, table};
 //!
 //!     #[derive(Tabled)]
@@ -93,7 +94,8 @@
 //!
 //! You also can combine structures by means of tuples.
 //!
-//! ```rust
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
 //!     use tabled::{Tabled, table, Style};
 //!
 //!     #[derive(Tabled)]
@@ -130,18 +132,82 @@
 //! ```
 //!
 
+mod abbreviation;
 mod alignment;
+mod baseline;
+mod builder;
+mod caption;
+#[cfg(feature = "cargo")]
+pub mod cargo;
+mod columns;
+mod details;
 mod disable;
+mod enumerate;
+mod filter;
+mod footer;
 mod formating;
+mod height;
+mod highlight;
+mod indent;
+#[cfg(feature = "serde")]
+mod json;
+mod legend;
+mod live;
+mod merge;
+mod metadata;
+mod nested;
+mod newline;
+mod numeric;
 mod object;
+mod pages;
+mod parse;
+mod placeholder;
+mod placement;
+mod separator;
+mod side_by_side;
+mod sort;
+mod span;
+mod split;
+pub mod sql;
 pub mod style;
+mod subtotal;
+mod tab;
+pub mod testdata;
+pub mod testing;
+#[cfg(feature = "timestamp")]
+mod timestamp;
+mod transition;
+mod vertical;
+#[cfg(feature = "ratatui")]
+mod widget;
+mod width;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
+
+pub use crate::{
+    abbreviation::*, alignment::*, baseline::*, builder::*, caption::*, columns::*, details::*,
+    disable::*, enumerate::*, filter::*, footer::*, formating::*, height::*, highlight::*,
+    indent::*, legend::*, live::*, merge::*, metadata::*, nested::*, newline::*, numeric::*,
+    object::*, pages::*, placeholder::*, placement::*, separator::*, side_by_side::*, sort::*,
+    span::*, split::*, style::Style, subtotal::*, tab::*, transition::*, vertical::*, width::*,
+};
+
+#[cfg(feature = "timestamp")]
+pub use crate::timestamp::*;
 
-pub use crate::{alignment::*, disable::*, formating::*, object::*, style::Style};
+#[cfg(feature = "ratatui")]
+pub use crate::widget::*;
 
-pub use papergrid::Alignment;
+pub use papergrid::{
+    Alignment, AlignmentVertical, Appender, ColumnRef, Error, Estimator, ExactWidths,
+    HorizontalLine, LineEnding, Margin, Padding, PercentWidths, RowMetadata, SampleWidths,
+    Sanitization, Severity, SizePolicy, SpanDistribution, VerticalLine,
+};
+#[cfg(feature = "derive")]
 pub use tabled_derive::Tabled;
 
 use papergrid::{Entity, Grid, Settings};
+use std::fmt::Display;
 
 /// Tabled a trait responsible for providing a header filds and a row fields.
 ///
@@ -169,6 +235,47 @@ where
     }
 }
 
+impl<T> Tabled for Box<T>
+where
+    T: Tabled,
+{
+    fn fields(&self) -> Vec<String> {
+        T::fields(self)
+    }
+    fn headers() -> Vec<String> {
+        T::headers()
+    }
+}
+
+/// An absent value is shown as a set of empty cells.
+impl<T> Tabled for Option<T>
+where
+    T: Tabled,
+{
+    fn fields(&self) -> Vec<String> {
+        match self {
+            Some(value) => value.fields(),
+            None => vec![String::new(); T::headers().len()],
+        }
+    }
+    fn headers() -> Vec<String> {
+        T::headers()
+    }
+}
+
+/// An array is shown as a row where each element takes its own column named by its index.
+impl<T, const N: usize> Tabled for [T; N]
+where
+    T: Display,
+{
+    fn fields(&self) -> Vec<String> {
+        self.iter().map(|value| value.to_string()).collect()
+    }
+    fn headers() -> Vec<String> {
+        (0..N).map(|i| i.to_string()).collect()
+    }
+}
+
 /// A trait for configuring a `Grid`.
 ///
 /// Mainly was created to be able to have a variadic set of parameters in a [the `table` macros](./macros.table.html)
@@ -181,6 +288,95 @@ impl TableOption for () {
     fn change(&self, _: &mut Grid) {}
 }
 
+/// Sanitization can be changed by passing it to a [`table` macros](./macro.table.html).
+///
+/// ```rust
+///     use tabled::{table, Sanitization};
+///     let data = vec!["\u{1b}[2Jasd"];
+///     let table = table!(&data, Sanitization::Escape);
+///     assert!(table.contains("\\u{1b}[2Jasd"));
+/// ```
+impl TableOption for Sanitization {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_sanitization(*self);
+    }
+}
+
+/// LineEnding can be changed by passing it to a [`table` macros](./macro.table.html).
+///
+/// ```rust
+///     use tabled::{table, LineEnding, Style};
+///     let data = vec!["asd"];
+///     let table = table!(&data, Style::psql(), LineEnding::CrLf);
+///     assert_eq!(table, " &str \r\n------\r\n asd  \r\n");
+/// ```
+impl TableOption for LineEnding {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_line_ending(*self);
+    }
+}
+
+/// Margin can be set by passing it to a [`table` macros](./macro.table.html).
+///
+/// ```rust
+///     use tabled::{table, Margin, Style};
+///     let data = vec!["asd"];
+///     let table = table!(&data, Style::psql(), Margin::new(2, 0, 1, 0));
+///     assert_eq!(table, "        \n   &str \n  ------\n   asd  \n");
+/// ```
+impl TableOption for Margin {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_margin(self.clone());
+    }
+}
+
+/// SpanDistribution can be changed by passing it to a [`table` macros](./macro.table.html).
+///
+/// ```rust
+///     use tabled::{table, Cell, Span, SpanDistribution, Style};
+///     let data = vec![("A very long line", "a"), ("b", "c")];
+///     let table = table!(&data, Style::psql(), Span(Cell(1, 0), 2), SpanDistribution::Last);
+///     assert_eq!(table, concat!(
+///         " &str |   &str    \n",
+///         "------+-----------\n",
+///         " A very long line \n",
+///         "  b   |     c     \n",
+///     ));
+/// ```
+impl TableOption for SpanDistribution {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_span_distribution(*self);
+    }
+}
+
+/// Exact widths of columns can be set by passing them to a [`table` macros](./macro.table.html).
+///
+/// ```rust
+///     use tabled::{table, ExactWidths, Style};
+///     let data = vec!["asd"];
+///     let table = table!(&data, Style::psql(), ExactWidths(vec![8]));
+///     assert_eq!(table, "  &str  \n--------\n  asd   \n");
+/// ```
+impl TableOption for ExactWidths {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_estimator(self.clone());
+    }
+}
+
+/// Percentage widths of columns can be set by passing them to a [`table` macros](./macro.table.html).
+impl TableOption for PercentWidths {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_estimator(self.clone());
+    }
+}
+
+/// Sampled widths of columns can be set by passing them to a [`table` macros](./macro.table.html).
+impl TableOption for SampleWidths {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_estimator(*self);
+    }
+}
+
 impl<E> TableOption for Vec<E>
 where
     E: TableOption,
@@ -219,7 +415,6 @@ where
 ///     );
 ///     println!("{}", table);
 /// ```
-///
 #[macro_export]
 macro_rules! table {
     ( $data:expr ) => {
@@ -236,13 +431,123 @@ macro_rules! table {
     }};
 }
 
+/// Grid macro builds a [`Grid`] without a header from rows listed in square brackets.
+///
+/// The grid is not rendered so options can be applied to it.
+/// Rows may have different lengths, missing cells are left empty.
+///
+/// ```rust
+///     use tabled::{grid, Style, TableOption};
+///     let mut grid = grid![["a", "b"], ["c", 1]];
+///     Style::psql().change(&mut grid);
+///     assert_eq!(grid.to_string(), " a | b \n c | 1 \n");
+/// ```
+#[macro_export]
+macro_rules! grid {
+    ( $( [ $($cell:expr),* $(,)? ] ),+ $(,)? ) => {
+        tabled::build_grid_from_rows(vec![ $( vec![ $( $cell.to_string() ),* ] ),+ ])
+    };
+}
+
+/// Row macro builds a [`Grid`] of a single row without a header.
+///
+/// ```rust
+///     use tabled::row;
+///     let grid = row!["name", 1, 'c'];
+///     assert_eq!(grid.to_string(), "+------+---+---+\n| name | 1 | c |\n+------+---+---+\n");
+/// ```
+#[macro_export]
+macro_rules! row {
+    ( $($cell:expr),* $(,)? ) => {
+        tabled::build_grid_from_rows(vec![vec![ $( $cell.to_string() ),* ]])
+    };
+}
+
+/// Col macro builds a [`Grid`] of a single column without a header.
+///
+/// ```rust
+///     use tabled::{col, Style, TableOption};
+///     let mut grid = col!["name", 1];
+///     Style::psql().change(&mut grid);
+///     assert_eq!(grid.to_string(), " name \n  1   \n");
+/// ```
+#[macro_export]
+macro_rules! col {
+    ( $($cell:expr),* $(,)? ) => {
+        tabled::build_grid_from_rows(vec![ $( vec![$cell.to_string()] ),* ])
+    };
+}
+
 /// Build_grid function build a [`Grid`](../papergrid/struct.Grid.html) from a data.
 /// A [`table` macros](./macro.table.html) should be prefered over this function.
 pub fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
-    let headers = T::headers();
-    let obj: Vec<Vec<String>> = iter.into_iter().map(|t| t.fields()).collect();
+    let records = iter.into_iter().map(|t| t.fields()).collect();
+    build_grid_from_records(T::headers(), records)
+}
 
-    let mut grid = Grid::new(obj.len() + 1, headers.len());
+/// Build_grid_without_header function build a [`Grid`](../papergrid/struct.Grid.html) from a data
+/// without a header row, so the first row is a first record.
+///
+/// Widths of columns are measured only by records and
+/// a style draws a line under the first row as a line between any other rows.
+/// Options which refer to a header, like [`Head`], refer to the first record then.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{build_grid_without_header, Style, TableOption};
+///     let data = vec![("tabled", "MIT"), ("papergrid", "MIT")];
+///     let mut grid = build_grid_without_header(&data);
+///     Style::psql().change(&mut grid);
+///
+///     assert_eq!(grid.to_string(), concat!(
+///         "  tabled   | MIT \n",
+///         " papergrid | MIT \n",
+///     ));
+/// ```
+pub fn build_grid_without_header<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
+    let records = iter.into_iter().map(|t| t.fields()).collect();
+    build_grid_from_records_without_header(T::headers().len(), records)
+}
+
+// It's used by `grid!`, `row!` and `col!` macros built from a list of cells.
+#[doc(hidden)]
+pub fn build_grid_from_rows(mut rows: Vec<Vec<String>>) -> Grid {
+    let count_columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(count_columns, String::new());
+    }
+
+    build_grid_from_records_without_header(count_columns, rows)
+}
+
+// It's used by tables which columns are known only in runtime.
+pub(crate) fn build_grid_from_records(headers: Vec<String>, obj: Vec<Vec<String>>) -> Grid {
+    let mut grid = new_grid(obj.len() + 1, headers.len());
+
+    // strings are moved into the grid so they are not copied
+    for (i, h) in headers.into_iter().enumerate() {
+        grid.set(Entity::Cell(0, i), Settings::new().text(h));
+    }
+
+    set_records(&mut grid, 1, obj);
+
+    grid
+}
+
+pub(crate) fn build_grid_from_records_without_header(
+    count_columns: usize,
+    obj: Vec<Vec<String>>,
+) -> Grid {
+    let mut grid = new_grid(obj.len(), count_columns);
+    grid.set_header(false);
+    set_records(&mut grid, 0, obj);
+
+    grid
+}
+
+fn new_grid(count_rows: usize, count_columns: usize) -> Grid {
+    let mut grid = Grid::new(count_rows, count_columns);
 
     // it's crusial to set a global setting rather than a setting for an each cell
     // as it will be hard to override that since how Grid::style method works
@@ -253,24 +558,22 @@ pub fn build_grid<T: Tabled>(iter: impl IntoIterator<Item = T>) -> Grid {
             .alignment(Alignment::Center),
     );
 
-    for (i, h) in headers.iter().enumerate() {
-        grid.set(Entity::Cell(0, i), Settings::new().text(h));
-    }
+    grid
+}
 
-    let mut row = 1;
-    for fields in &obj {
-        for (column, field) in fields.iter().enumerate() {
+fn set_records(grid: &mut Grid, mut row: usize, obj: Vec<Vec<String>>) {
+    for fields in obj {
+        // don't show off a empty data array
+        // currently it's possible when `#[header(hidden)]` attribute used for a enum
+        let is_empty = fields.is_empty();
+        for (column, field) in fields.into_iter().enumerate() {
             grid.set(Entity::Cell(row, column), Settings::new().text(field));
         }
 
-        // don't show off a empty data array
-        // currently it's possible when `#[header(hidden)]` attribute used for a enum
-        if !fields.is_empty() {
+        if !is_empty {
             row += 1;
         }
     }
-
-    grid
 }
 
 macro_rules! tuple_table {
@@ -296,9 +599,15 @@ macro_rules! tuple_table {
 tuple_table! { A }
 tuple_table! { A B }
 tuple_table! { A B C }
-tuple_table! { A B C D}
-tuple_table! { A B C D E}
-tuple_table! { A B C D E F}
+tuple_table! { A B C D }
+tuple_table! { A B C D E }
+tuple_table! { A B C D E F }
+tuple_table! { A B C D E F G }
+tuple_table! { A B C D E F G H }
+tuple_table! { A B C D E F G H I }
+tuple_table! { A B C D E F G H I J }
+tuple_table! { A B C D E F G H I J K }
+tuple_table! { A B C D E F G H I J K L }
 
 macro_rules! default_table {
     ( $t:ty ) => {
@@ -314,6 +623,7 @@ macro_rules! default_table {
 }
 
 default_table!(&str);
+default_table!(String);
 
 default_table!(char);
 
@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+/// Live re-renders a table over its previous output in a terminal,
+/// so a table which shows a progress can be refreshed without scrolling.
+///
+/// The output of an update starts with ANSI escape sequences
+/// which move a cursor to the beginning of the previous output and clear it.
+/// It relies on a table being not wider than a terminal as wrapped lines are not counted.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Live, Style};
+///     let mut live = Live::new();
+///
+///     let first = live.update(table!(&vec!["building"], Style::psql()));
+///     assert_eq!(first, "   &str   \n----------\n building \n");
+///
+///     let second = live.update(table!(&vec!["done"], Style::psql()));
+///     assert_eq!(second, "\r\u{1b}[3A\u{1b}[J &str \n------\n done \n");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Live {
+    // a number of line endings in the previous output, `None` if nothing is printed yet
+    lines: Option<usize>,
+}
+
+impl Live {
+    /// New creates a live output which hasn't printed anything yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update returns a table prefixed by sequences which erase the previous one.
+    pub fn update<T: Display>(&mut self, table: T) -> String {
+        let table = table.to_string();
+        let clear = match self.lines {
+            None => String::new(),
+            Some(0) => String::from("\r\u{1b}[J"),
+            Some(lines) => format!("\r\u{1b}[{}A\u{1b}[J", lines),
+        };
+
+        self.lines = Some(table.matches('\n').count());
+
+        clear + &table
+    }
+}
@@ -0,0 +1,97 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Merge collapses runs of adjacent cells with the same content into a single spanned cell.
+///
+/// [`Merge::vertical`] merges cells of a column, [`Merge::horizontal`] merges cells of a row.
+/// Only data rows are merged, a header is left as it is, and empty cells are never merged.
+/// Cells which are already a part of a span, e.g. after a merge in the other direction,
+/// are not merged either.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Merge};
+///     let data = vec![("east", "apples"), ("east", "pears"), ("west", "apples")];
+///     let table = table!(&data, Merge::vertical());
+///
+///     assert_eq!(table, concat!(
+///         "+------+--------+\n",
+///         "| &str |  &str  |\n",
+///         "+------+--------+\n",
+///         "| east | apples |\n",
+///         "|      +--------+\n",
+///         "|      | pears  |\n",
+///         "+------+--------+\n",
+///         "| west | apples |\n",
+///         "+------+--------+\n",
+///     ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Merge {
+    vertical: bool,
+}
+
+impl Merge {
+    /// Vertical creates an option which merges cells of columns.
+    pub fn vertical() -> Self {
+        Self { vertical: true }
+    }
+
+    /// Horizontal creates an option which merges cells of rows.
+    pub fn horizontal() -> Self {
+        Self { vertical: false }
+    }
+}
+
+impl TableOption for Merge {
+    fn change(&self, grid: &mut Grid) {
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        if self.vertical {
+            for column in 0..grid.count_columns() {
+                let texts = (first_row..grid.count_rows())
+                    .map(|row| mergeable_text(grid, row, column))
+                    .collect::<Vec<_>>();
+                for (start, span) in runs(&texts) {
+                    let _ = grid.set_row_span(start + first_row, column, span);
+                }
+            }
+        } else {
+            for row in first_row..grid.count_rows() {
+                let texts = (0..grid.count_columns())
+                    .map(|column| mergeable_text(grid, row, column))
+                    .collect::<Vec<_>>();
+                for (start, span) in runs(&texts) {
+                    let _ = grid.set_column_span(row, start, span);
+                }
+            }
+        }
+    }
+}
+
+// a spanned cell is treated as an empty one so a run is broken by it
+fn mergeable_text(grid: &Grid, row: usize, column: usize) -> String {
+    if grid.is_spanned(row, column) {
+        String::new()
+    } else {
+        grid.get_cell(row, column).unwrap_or_default().to_owned()
+    }
+}
+
+// It returns a start and a length of each run of identical non empty texts longer than 1.
+fn runs(texts: &[String]) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for end in 1..=texts.len() {
+        if end == texts.len() || texts[end] != texts[start] {
+            if end - start > 1 && !texts[start].is_empty() {
+                runs.push((start, end - start));
+            }
+
+            start = end;
+        }
+    }
+
+    runs
+}
@@ -0,0 +1,83 @@
+use papergrid::{Entity, Grid, RowMetadata, Settings, Severity};
+
+use crate::TableOption;
+
+/// Metadata attaches a [`RowMetadata`](../papergrid/struct.RowMetadata.html) to a row.
+///
+/// The metadata isn't shown in a text table by default,
+/// it's meant for renderers which can make use of it,
+/// but [`SeverityColor`](./struct.SeverityColor.html) can paint rows according to it.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Metadata, RowMetadata, Severity, Style};
+///     let data = vec!["ok", "disk is full"];
+///     let metadata = data
+///         .iter()
+///         .enumerate()
+///         .map(|(i, _)| Metadata(i + 1, RowMetadata::new().id(i.to_string())))
+///         .collect::<Vec<_>>();
+///
+///     let table = table!(&data, Style::psql(), metadata);
+///     assert_eq!(table, concat!(
+///         "     &str     \n",
+///         "--------------\n",
+///         "      ok      \n",
+///         " disk is full \n",
+///     ));
+/// ```
+pub struct Metadata(pub usize, pub RowMetadata);
+
+impl TableOption for Metadata {
+    fn change(&self, grid: &mut Grid) {
+        // a row out of the grid is ignored
+        let _ = grid.set_row_metadata(self.0, self.1.clone());
+    }
+}
+
+/// SeverityColor paints rows according to a severity set in their metadata.
+///
+/// Warnings are painted yellow, errors red and debug rows are dimmed.
+/// To get a correct layout a `color` feature must be turned on.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Metadata, RowMetadata, Severity, SeverityColor, Style};
+///     let data = vec!["ok", "failed"];
+///
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Metadata(2, RowMetadata::new().severity(Severity::Error)),
+///         SeverityColor,
+///     );
+///
+///     assert!(table.contains("\u{1b}[31mfailed\u{1b}[0m"));
+/// ```
+pub struct SeverityColor;
+
+impl TableOption for SeverityColor {
+    fn change(&self, grid: &mut Grid) {
+        for row in 0..grid.count_rows() {
+            let severity = grid.get_row_metadata(row).and_then(|m| m.get_severity());
+            let color = match severity {
+                Some(Severity::Debug) => "2",
+                Some(Severity::Warning) => "33",
+                Some(Severity::Error) => "31",
+                Some(Severity::Info) | None => continue,
+            };
+
+            for column in 0..grid.count_columns() {
+                let content = grid.get_cell_content(row, column);
+                let content = content
+                    .lines()
+                    .map(|line| format!("\u{1b}[{}m{}\u{1b}[0m", color, line))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                grid.set(Entity::Cell(row, column), Settings::new().text(content))
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use papergrid::{Alignment, Entity, Grid, HorizontalLine, Padding, Settings, VerticalLine};
+
+use crate::{Object, TableOption};
+
+/// Nested puts a table into cells of another one.
+///
+/// By default the inner table is rendered as it is, aligned to the left of a cell.
+/// [`Nested::frameless`] hides its outer frame and a padding of the cells,
+/// so the inner lines meet borders of the outer table.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{build_grid, table, Cell, Nested, Style, TableOption};
+///     let mut inner = build_grid(&vec![("dnf", 4), ("rpm", 4)]);
+///     Style::psql().change(&mut inner);
+///
+///     let data = vec![("Fedora", "")];
+///     let table = table!(&data, Nested::new(Cell(1, 1), inner).frameless());
+///
+///     assert_eq!(table, concat!(
+///         "+--------+------------+\n",
+///         "|  &str  |    &str    |\n",
+///         "+--------+------------+\n",
+///         "| Fedora | &str | i32 |\n",
+///         "|        |------+-----|\n",
+///         "|        | dnf  |  4  |\n",
+///         "|        | rpm  |  4  |\n",
+///         "+--------+------------+\n",
+///     ));
+/// ```
+pub struct Nested<O: Object> {
+    object: O,
+    grid: Grid,
+    frameless: bool,
+}
+
+impl<O: Object> Nested<O> {
+    /// New creates an option which sets a rendered `grid` as a content of cells of an `object`.
+    pub fn new(object: O, grid: Grid) -> Self {
+        Self {
+            object,
+            grid,
+            frameless: false,
+        }
+    }
+
+    /// Frameless hides an outer frame of the inner table and a padding of cells it's put in.
+    pub fn frameless(mut self) -> Self {
+        let (count_rows, count_columns) = (self.grid.count_rows(), self.grid.count_columns());
+        // lines are on the grid so an error can't happen
+        let _ = self.grid.set_horizontal_line(0, HorizontalLine::empty());
+        let _ = self
+            .grid
+            .set_horizontal_line(count_rows, HorizontalLine::empty());
+        let _ = self.grid.set_vertical_line(0, VerticalLine::empty());
+        let _ = self
+            .grid
+            .set_vertical_line(count_columns, VerticalLine::empty());
+
+        self.frameless = true;
+        self
+    }
+}
+
+impl<O: Object> TableOption for Nested<O> {
+    fn change(&self, grid: &mut Grid) {
+        let table = self.grid.to_string();
+        // the last line ending would add an empty line to a cell
+        let table = table.strip_suffix('\n').unwrap_or(&table);
+        let table = table.strip_suffix('\r').unwrap_or(table);
+
+        for (row, column) in self.object.cells(grid.count_rows(), grid.count_columns()) {
+            // lines of a cell are trimmed on the right so they're aligned to the left to keep them in place
+            let mut settings = Settings::new().text(table).alignment(Alignment::Left);
+            if self.frameless {
+                settings = settings.padding(Padding::new(0, 0, 0, 0));
+            }
+
+            grid.set(Entity::Cell(row, column), settings);
+        }
+    }
+}
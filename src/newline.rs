@@ -0,0 +1,24 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// TrailingNewline sets whether the last line of a table is ended by a line ending.
+///
+/// It's on by default.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Style, TrailingNewline};
+///     let data = vec!["asd"];
+///     let table = table!(&data, Style::psql(), TrailingNewline(false));
+///     assert_eq!(table, " &str \n------\n asd  ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrailingNewline(pub bool);
+
+impl TableOption for TrailingNewline {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_trailing_newline(self.0);
+    }
+}
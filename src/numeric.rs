@@ -0,0 +1,105 @@
+/// Thousands a helper function which groups digits of an integer part of numbers by thousands.
+///
+/// A content which isn't a number is left as it is, so it can be applied to a whole column.
+///
+/// ```rust
+///     use tabled::{table, thousands, Column, Format, Style};
+///     let data = vec![1234567, -1000, 999];
+///     let table = table!(&data, Style::psql(), Format(Column(..), thousands(',')));
+///     assert_eq!(table, "    i32    \n-----------\n 1,234,567 \n  -1,000   \n    999    \n");
+/// ```
+pub fn thousands(separator: char) -> Box<dyn Fn(&str) -> String> {
+    Box::new(move |s: &str| {
+        let text = s.trim();
+        if text.parse::<f64>().is_err() {
+            return s.to_owned();
+        }
+
+        let (sign, text) = match text.strip_prefix(|c| c == '-' || c == '+') {
+            Some(rest) => (&text[..1], rest),
+            None => ("", text),
+        };
+        let (integer, fraction) = match text.find(|c: char| !c.is_ascii_digit()) {
+            Some(i) => text.split_at(i),
+            None => (text, ""),
+        };
+
+        let mut grouped = String::new();
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+
+            grouped.push(c);
+        }
+
+        format!("{}{}{}", sign, grouped, fraction)
+    })
+}
+
+/// Precision a helper function which prints numbers with a given number of digits after a decimal point.
+///
+/// A content which isn't a number is left as it is.
+///
+/// ```rust
+///     use tabled::{table, precision, Column, Format, Style};
+///     let data = vec![3.14159, 2.0];
+///     let table = table!(&data, Style::psql(), Format(Column(..), precision(2)));
+///     assert_eq!(table, " f64  \n------\n 3.14 \n 2.00 \n");
+/// ```
+pub fn precision(digits: usize) -> Box<dyn Fn(&str) -> String> {
+    Box::new(move |s: &str| match s.trim().parse::<f64>() {
+        Ok(number) => format!("{:.*}", digits, number),
+        Err(_) => s.to_owned(),
+    })
+}
+
+/// Percent a helper function which prints fractions as percentages with a given precision.
+///
+/// A content which isn't a number is left as it is.
+///
+/// ```rust
+///     use tabled::{table, percent, Column, Format, Style};
+///     let data = vec![0.125, 1.0];
+///     let table = table!(&data, Style::psql(), Format(Column(..), percent(1)));
+///     assert_eq!(table, "  f64   \n--------\n 12.5%  \n 100.0% \n");
+/// ```
+pub fn percent(digits: usize) -> Box<dyn Fn(&str) -> String> {
+    Box::new(move |s: &str| match s.trim().parse::<f64>() {
+        Ok(number) => format!("{:.*}%", digits, number * 100.0),
+        Err(_) => s.to_owned(),
+    })
+}
+
+/// Bytes a helper function which prints a number of bytes in binary units, like `1.2 GiB`.
+///
+/// A content which isn't a number is left as it is.
+///
+/// ```rust
+///     use tabled::{table, bytes, Column, Format, Style};
+///     let data = vec![512, 1536, 1288490188];
+///     let table = table!(&data, Style::psql(), Format(Column(..), bytes()));
+///     assert_eq!(table, "   i32   \n---------\n  512 B  \n 1.5 KiB \n 1.2 GiB \n");
+/// ```
+pub fn bytes() -> Box<dyn Fn(&str) -> String> {
+    const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    Box::new(|s: &str| {
+        let mut size = match s.trim().parse::<f64>() {
+            Ok(size) => size,
+            Err(_) => return s.to_owned(),
+        };
+
+        let mut unit = 0;
+        while size.abs() >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            format!("{} {}", size, UNITS[unit])
+        } else {
+            format!("{:.1} {}", size, UNITS[unit])
+        }
+    })
+}
@@ -81,6 +81,7 @@ impl<R: RangeBounds<usize>> Object for Column<R> {
 }
 
 /// Cell denotes a particular of cells on a `Grid`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Cell(pub usize, pub usize);
 
 impl Object for Cell {
@@ -112,7 +113,7 @@ where
 
 fn combine_cells(lhs: Vec<(usize, usize)>, rhs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
     lhs.into_iter()
-        .chain(rhs.into_iter())
+        .chain(rhs)
         .collect::<BTreeSet<_>>()
         .into_iter()
         .collect()
@@ -0,0 +1,79 @@
+use papergrid::Grid;
+
+/// Pages splits a grid into pages of a given number of data rows,
+/// each page repeats a header of the grid if it has one.
+///
+/// Widths of columns are the same on every page, so printed pages line up.
+/// A grid with a header but without data rows produces a single page with the header.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{build_grid, Pages, Style, TableOption};
+///     let data = vec![("Fedora", 34), ("Debian", 11), ("OpenSUSE", 15)];
+///     let mut grid = build_grid(&data);
+///     Style::psql().change(&mut grid);
+///
+///     let pages = Pages::new(&grid, 2).collect::<Vec<_>>();
+///
+///     assert_eq!(pages, vec![
+///         concat!(
+///             "   &str   | i32 \n",
+///             "----------+-----\n",
+///             "  Fedora  | 34  \n",
+///             "  Debian  | 11  \n",
+///         ),
+///         concat!(
+///             "   &str   | i32 \n",
+///             "----------+-----\n",
+///             " OpenSUSE | 15  \n",
+///         ),
+///     ]);
+/// ```
+pub struct Pages<'a> {
+    grid: &'a Grid,
+    rows_per_page: usize,
+    first_row: usize,
+    next_row: usize,
+}
+
+impl<'a> Pages<'a> {
+    /// New creates an iterator over pages of `rows_per_page` data rows.
+    ///
+    /// A page has at least one data row even if `rows_per_page` is 0.
+    pub fn new(grid: &'a Grid, rows_per_page: usize) -> Self {
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        Self {
+            grid,
+            rows_per_page: rows_per_page.max(1),
+            first_row,
+            next_row: first_row,
+        }
+    }
+}
+
+impl Iterator for Pages<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let count_rows = self.grid.count_rows();
+        let is_first = self.next_row == self.first_row;
+        if count_rows == 0 || (self.next_row >= count_rows && !is_first) {
+            return None;
+        }
+
+        let end = (self.next_row + self.rows_per_page).min(count_rows);
+        let header = if self.grid.has_header() {
+            Some(0)
+        } else {
+            None
+        };
+        let rows = header
+            .into_iter()
+            .chain(self.next_row..end)
+            .collect::<Vec<_>>();
+        self.next_row = end.max(self.next_row + 1);
+
+        Some(self.grid.render_rows(&rows))
+    }
+}
@@ -0,0 +1,319 @@
+use papergrid::{string_width, Alignment};
+
+use crate::Builder;
+
+// characters which horizontal lines are made of, a line has to have at least one of `HORIZONTAL`
+const HORIZONTAL: &str = "-=─━═";
+const INTERSECTIONS: &str = "+|:│┃║┼┬┴├┤┌┐└┘╋┳┻┣┫┏┓┗┛╞╡╪╤╧╒╕╘╛╬╦╩╠╣╔╗╚╝";
+const VERTICAL: &str = "|│┃║";
+
+impl Builder {
+    /// Parse creates a builder from a rendered table, e.g. one which was printed before or typed by hand.
+    ///
+    /// Columns must be separated by vertical lines like `|` or `│`, which are found on the same
+    /// positions in all lines of a table. Horizontal lines are recognized by characters like `-`, `=` or `─`.
+    /// The first row is a header and a padding of cells is trimmed.
+    ///
+    /// When there are lines between data rows each row may take several lines,
+    /// otherwise each line is a row.
+    ///
+    /// It returns `None` if a text has no rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use tabled::{Builder, Style, TableOption};
+    ///
+    ///     let text = concat!(
+    ///         "+------+-------+\n",
+    ///         "| name | value |\n",
+    ///         "+------+-------+\n",
+    ///         "|  a   |   1   |\n",
+    ///         "+------+-------+\n",
+    ///     );
+    ///
+    ///     let mut grid = Builder::parse(text).unwrap().build();
+    ///     Style::psql().change(&mut grid);
+    ///
+    ///     assert_eq!(grid.to_string(), concat!(
+    ///         " name | value \n",
+    ///         "------+-------\n",
+    ///         "  a   |   1   \n",
+    ///     ));
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        // lines with a content are grouped by horizontal lines between them
+        let mut groups: Vec<Vec<&str>> = vec![Vec::new()];
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            if is_horizontal_line(line) {
+                groups.push(Vec::new());
+            } else {
+                groups.last_mut().unwrap().push(line);
+            }
+        }
+        groups.retain(|group| !group.is_empty());
+
+        let lines = groups.iter().flatten().copied().collect::<Vec<_>>();
+        let columns = Columns::new(&lines);
+
+        let rows = match groups.len() {
+            0 => return None,
+            // a header of a table without any lines can't be told from records
+            1 => groups[0]
+                .iter()
+                .map(|line| columns.split(line))
+                .collect::<Vec<_>>(),
+            2 => std::iter::once(columns.join(&groups[0]))
+                .chain(groups[1].iter().map(|line| columns.split(line)))
+                .collect(),
+            _ => groups.iter().map(|lines| columns.join(lines)).collect(),
+        };
+
+        let mut rows = rows.into_iter();
+        let mut builder = Builder::new().header(rows.next()?);
+        for row in rows {
+            builder = builder.add_row(row);
+        }
+
+        Some(builder)
+    }
+
+    /// From_markdown creates a builder from the first GitHub Flavored Markdown table found in a text,
+    /// so a table from a documentation can be restyled.
+    ///
+    /// Columns get an alignment set by colons in the delimiter row, e.g. `:--` is left and `--:` is right.
+    /// Columns without colons keep a default alignment. Escaped pipes `\|` are unescaped.
+    /// Rows are padded or cut to the number of header cells.
+    ///
+    /// It returns `None` if a text has no table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use tabled::{Builder, Style, TableOption};
+    ///
+    ///     let text = concat!(
+    ///         "| name | value |\n",
+    ///         "|:-----|------:|\n",
+    ///         "| a | 1 |\n",
+    ///         "| bcd | 100 |\n",
+    ///     );
+    ///
+    ///     let mut grid = Builder::from_markdown(text).unwrap().build();
+    ///     Style::psql().change(&mut grid);
+    ///
+    ///     assert_eq!(grid.to_string(), concat!(
+    ///         " name | value \n",
+    ///         "------+-------\n",
+    ///         " a    |     1 \n",
+    ///         " bcd  |   100 \n",
+    ///     ));
+    /// ```
+    pub fn from_markdown(text: &str) -> Option<Self> {
+        let lines = text.lines().map(str::trim).collect::<Vec<_>>();
+
+        // a table starts with a header which is followed by a delimiter row with the same number of cells
+        let (start, header, alignments) = lines.windows(2).enumerate().find_map(|(i, lines)| {
+            if !lines[0].contains('|') {
+                return None;
+            }
+
+            let header = split_markdown_row(lines[0]);
+            let alignments = parse_markdown_delimiter(lines[1])?;
+            if header.len() != alignments.len() {
+                return None;
+            }
+
+            Some((i, header, alignments))
+        })?;
+
+        let count_columns = header.len();
+        let mut builder = Builder::new().header(header);
+        for (column, alignment) in alignments.into_iter().enumerate() {
+            if let Some(alignment) = alignment {
+                builder = builder.alignment(column, alignment);
+            }
+        }
+
+        // a table ends on a blank line
+        for line in lines[start + 2..]
+            .iter()
+            .take_while(|line| !line.is_empty())
+        {
+            let mut row = split_markdown_row(line);
+            row.resize(count_columns, String::new());
+            builder = builder.add_row(row);
+        }
+
+        Some(builder)
+    }
+}
+
+fn split_markdown_row(line: &str) -> Vec<String> {
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => line,
+    };
+
+    let mut cells = vec![String::new()];
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cells.last_mut().unwrap().push('|');
+                chars.next();
+            }
+            '|' => cells.push(String::new()),
+            c => cells.last_mut().unwrap().push(c),
+        }
+    }
+
+    cells
+        .into_iter()
+        .map(|cell| cell.trim().to_owned())
+        .collect()
+}
+
+// a delimiter row consists of cells like `---`, `:--`, `--:` or `:-:`,
+// `+` is taken as `|` because `Style::github_markdown` puts it on intersections
+fn parse_markdown_delimiter(line: &str) -> Option<Vec<Option<Alignment>>> {
+    if !line.contains(['|', '+', ':']) {
+        return None;
+    }
+
+    split_markdown_row(&line.replace('+', "|"))
+        .iter()
+        .map(|cell| {
+            let left = cell.starts_with(':');
+            let right = cell.ends_with(':');
+            let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+
+            Some(match (left, right) {
+                (true, true) => Some(Alignment::Center),
+                (true, false) => Some(Alignment::Left),
+                (false, true) => Some(Alignment::Right),
+                (false, false) => None,
+            })
+        })
+        .collect()
+}
+
+fn is_horizontal_line(line: &str) -> bool {
+    let line = line.trim();
+    line.chars().any(|c| HORIZONTAL.contains(c))
+        && line
+            .chars()
+            .all(|c| HORIZONTAL.contains(c) || INTERSECTIONS.contains(c))
+}
+
+// Columns are display positions of vertical lines which are the same in all lines,
+// and whether the first and the last ones are a frame rather than lines between columns.
+struct Columns {
+    boundaries: Vec<usize>,
+    left_frame: bool,
+    right_frame: bool,
+}
+
+impl Columns {
+    fn new(lines: &[&str]) -> Self {
+        let mut boundaries: Option<Vec<usize>> = None;
+        for line in lines {
+            let positions = char_positions(line)
+                .filter(|(_, c)| VERTICAL.contains(*c))
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>();
+
+            boundaries = Some(match boundaries {
+                Some(boundaries) => boundaries
+                    .into_iter()
+                    .filter(|position| positions.contains(position))
+                    .collect(),
+                None => positions,
+            });
+        }
+        let boundaries = boundaries.unwrap_or_default();
+
+        // a frame has nothing but a margin on its outer side in all lines
+        let left_frame = boundaries.first().is_some_and(|&first| {
+            lines.iter().all(|line| {
+                char_positions(line)
+                    .take_while(|&(position, _)| position < first)
+                    .all(|(_, c)| c.is_whitespace())
+            })
+        });
+        let right_frame = boundaries.last().is_some_and(|&last| {
+            lines.iter().all(|line| {
+                char_positions(line)
+                    .skip_while(|&(position, _)| position <= last)
+                    .all(|(_, c)| c.is_whitespace())
+            })
+        });
+
+        Self {
+            boundaries,
+            left_frame,
+            right_frame,
+        }
+    }
+
+    fn split(&self, line: &str) -> Vec<String> {
+        let mut cells = vec![String::new()];
+        for (position, c) in char_positions(line) {
+            if self.boundaries.contains(&position) {
+                cells.push(String::new());
+            } else {
+                cells.last_mut().unwrap().push(c);
+            }
+        }
+
+        if self.right_frame {
+            cells.pop();
+        }
+        if self.left_frame {
+            cells.remove(0);
+        }
+
+        cells
+            .into_iter()
+            .map(|cell| cell.trim().to_owned())
+            .collect()
+    }
+
+    // lines of a multiline row are joined cell by cell
+    fn join(&self, lines: &[&str]) -> Vec<String> {
+        let mut row: Vec<Vec<String>> = Vec::new();
+        for line in lines {
+            for (i, cell) in self.split(line).into_iter().enumerate() {
+                if i == row.len() {
+                    row.push(Vec::new());
+                }
+
+                row[i].push(cell);
+            }
+        }
+
+        row.into_iter()
+            .map(|lines| {
+                // a content lower than its row is surrounded by empty lines, they're dropped
+                let start = lines.iter().position(|line| !line.is_empty());
+                let end = lines.iter().rposition(|line| !line.is_empty());
+                match (start, end) {
+                    (Some(start), Some(end)) => lines[start..=end].join("\n"),
+                    _ => String::new(),
+                }
+            })
+            .collect()
+    }
+}
+
+fn char_positions(line: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    line.chars().scan(0, |position, c| {
+        let current = *position;
+        *position += string_width(c.encode_utf8(&mut [0; 4]));
+        Some((current, c))
+    })
+}
@@ -0,0 +1,44 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{Object, TableOption};
+
+/// Placeholder replaces a content of empty cells by a given text, like `-` or `N/A`.
+///
+/// A cell which has only whitespaces is considered empty.
+/// As a filled cell is not empty any more, a placeholder for particular columns
+/// must go before a global one.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Column, Full, Placeholder, Style};
+///     let data = vec![("Fedora", ""), ("", "11")];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Placeholder(Column(1..), "N/A"),
+///         Placeholder(Full, "-"),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "  &str  | &str \n",
+///         "--------+------\n",
+///         " Fedora | N/A  \n",
+///         "   -    |  11  \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct Placeholder<O: Object, S: AsRef<str>>(pub O, pub S);
+
+impl<O: Object, S: AsRef<str>> TableOption for Placeholder<O, S> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+            if grid.get_cell_content(row, column).trim().is_empty() {
+                grid.set(
+                    Entity::Cell(row, column),
+                    Settings::new().text(self.1.as_ref()),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use papergrid::{Alignment, Grid};
+
+use crate::TableOption;
+
+/// Placement aligns the whole table within a given width, like a width of a terminal.
+///
+/// Each line of the table is indented, so nothing is appended to the end of lines.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Placement, Style};
+///     let data = vec!["a"];
+///     let table = table!(&data, Style::psql(), Placement::center(12));
+///
+///     assert_eq!(table, concat!(
+///         "    &str \n",
+///         "   ------\n",
+///         "     a   \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Placement {
+    alignment: Alignment,
+    width: usize,
+}
+
+impl Placement {
+    /// Center creates an option which centers a table within a given width.
+    pub fn center(width: usize) -> Self {
+        Self {
+            alignment: Alignment::Center,
+            width,
+        }
+    }
+
+    /// Right creates an option which aligns a table to the right edge of a given width.
+    pub fn right(width: usize) -> Self {
+        Self {
+            alignment: Alignment::Right,
+            width,
+        }
+    }
+}
+
+impl TableOption for Placement {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_placement(self.alignment.clone(), self.width);
+    }
+}
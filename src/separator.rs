@@ -0,0 +1,151 @@
+use std::ops::RangeBounds;
+
+use papergrid::{Grid, HorizontalLine, VerticalLine};
+
+use crate::{bounds_to_usize, TableOption};
+
+/// Separator overrides horizontal lines above rows in a range.
+///
+/// A line with an index `i` goes above a row `i`, so a range `1..2` is a line under a header.
+/// Lines out of a table are ignored and the line under the last row can be changed only by a [`Style`](crate::Style).
+///
+/// [`HorizontalLine::empty`] hides the lines.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, HorizontalLine, Separator};
+///     let data = vec!["Hello", "World", "!"];
+///     let table = table!(
+///         &data,
+///         Separator(1..2, HorizontalLine::new('=', '+', Some('+'), Some('+'))),
+///         Separator(2.., HorizontalLine::empty()),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "+-------+\n",
+///         "| &str  |\n",
+///         "+=======+\n",
+///         "| Hello |\n",
+///         "| World |\n",
+///         "|   !   |\n",
+///         "+-------+\n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct Separator<R: RangeBounds<usize>>(pub R, pub HorizontalLine);
+
+impl<R: RangeBounds<usize>> TableOption for Separator<R> {
+    fn change(&self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let (x, y) = bounds_to_usize(self.0.start_bound(), self.0.end_bound(), count_rows);
+        for line in x..y.min(count_rows) {
+            let _ = grid.set_horizontal_line(line, self.1.clone());
+        }
+    }
+}
+
+/// SeparatorEvery puts a horizontal line after each `N` data rows.
+///
+/// A header is not counted, and there's no line added under the last row.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, HorizontalLine, SeparatorEvery, Style};
+///     let data = vec![1, 2, 3, 4, 5];
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         SeparatorEvery(2, HorizontalLine::new('-', '+', None, None)),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         " i32 \n",
+///         "-----\n",
+///         "  1  \n",
+///         "  2  \n",
+///         "-----\n",
+///         "  3  \n",
+///         "  4  \n",
+///         "-----\n",
+///         "  5  \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct SeparatorEvery(pub usize, pub HorizontalLine);
+
+impl TableOption for SeparatorEvery {
+    fn change(&self, grid: &mut Grid) {
+        if self.0 == 0 {
+            return;
+        }
+
+        for line in (1 + self.0..grid.count_rows()).step_by(self.0) {
+            let _ = grid.set_horizontal_line(line, self.1.clone());
+        }
+    }
+}
+
+/// VerticalSeparator overrides vertical lines on the left of columns in a range.
+///
+/// A line with an index `i` goes on the left of a column `i`, so a range `1..` is all lines between columns.
+/// Lines out of a table are ignored and the line on the right of the last column can be changed only by a [`Style`](crate::Style).
+///
+/// [`VerticalLine::empty`] hides the lines.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Style, VerticalLine, VerticalSeparator};
+///     let data = vec![("Hello", "World", "!")];
+///     let table = table!(&data, Style::psql(), VerticalSeparator(2.., VerticalLine::empty()));
+///
+///     assert_eq!(table, concat!(
+///         " &str  | &str   &str \n",
+///         "-------+-------------\n",
+///         " Hello | World   !   \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct VerticalSeparator<R: RangeBounds<usize>>(pub R, pub VerticalLine);
+
+impl<R: RangeBounds<usize>> TableOption for VerticalSeparator<R> {
+    fn change(&self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        let (x, y) = bounds_to_usize(self.0.start_bound(), self.0.end_bound(), count_columns);
+        for line in x..y.min(count_columns) {
+            let _ = grid.set_vertical_line(line, self.1.clone());
+        }
+    }
+}
+
+/// LineText puts a text into a horizontal line above a given row, like a title of a section.
+///
+/// The text starts right after a left border of the line and it's cut so it doesn't cover a right border.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, LineText};
+///     let data = vec!["Hello", "World"];
+///     let table = table!(&data, LineText(1, "- Data "));
+///
+///     assert_eq!(table, concat!(
+///         "+-------+\n",
+///         "| &str  |\n",
+///         "+- Data +\n",
+///         "| Hello |\n",
+///         "+-------+\n",
+///         "| World |\n",
+///         "+-------+\n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct LineText<S: AsRef<str>>(pub usize, pub S);
+
+impl<S: AsRef<str>> TableOption for LineText<S> {
+    fn change(&self, grid: &mut Grid) {
+        let _ = grid.override_horizontal_line_text(self.0, self.1.as_ref());
+    }
+}
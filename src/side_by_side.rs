@@ -0,0 +1,102 @@
+use std::fmt::{self, Display};
+
+use papergrid::string_width;
+
+/// SideBySide renders tables next to each other, their tops are aligned.
+///
+/// Tables are separated by a gutter of spaces, 2 by default.
+/// A lower table is padded by empty lines, so it works for any text and not only tables.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, SideBySide, Style};
+///     let left = table!(&vec![("Fedora", 34), ("Debian", 11)], Style::psql());
+///     let right = table!(&vec!["dnf"], Style::psql());
+///
+///     let output = SideBySide::new().gutter(1).table(left).table(right).to_string();
+///
+///     assert_eq!(output, concat!(
+///         "  &str  | i32   &str \n",
+///         "--------+----- ------\n",
+///         " Fedora | 34    dnf  \n",
+///         " Debian | 11  \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SideBySide {
+    tables: Vec<String>,
+    gutter: usize,
+}
+
+impl SideBySide {
+    /// New creates an empty layout.
+    pub fn new() -> Self {
+        Self {
+            tables: Vec::new(),
+            gutter: 2,
+        }
+    }
+
+    /// Gutter sets a number of spaces between tables.
+    pub fn gutter(mut self, gutter: usize) -> Self {
+        self.gutter = gutter;
+        self
+    }
+
+    /// Table puts a table to the right of already added ones.
+    pub fn table<T: Display>(mut self, table: T) -> Self {
+        self.tables.push(table.to_string());
+        self
+    }
+}
+
+impl Default for SideBySide {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for SideBySide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tables = self
+            .tables
+            .iter()
+            .map(|table| table.lines().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let widths = tables
+            .iter()
+            .map(|lines| {
+                lines
+                    .iter()
+                    .map(|line| string_width(line))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        let height = tables.iter().map(Vec::len).max().unwrap_or(0);
+
+        for i in 0..height {
+            // a line isn't padded after the last table which has it
+            let last = tables
+                .iter()
+                .rposition(|lines| i < lines.len())
+                .unwrap_or(0);
+            for (j, lines) in tables.iter().enumerate().take(last + 1) {
+                if j > 0 {
+                    write!(f, "{}", " ".repeat(self.gutter))?;
+                }
+
+                let line = lines.get(i).copied().unwrap_or("");
+                f.write_str(line)?;
+                if j < last {
+                    write!(f, "{}", " ".repeat(widths[j] - string_width(line)))?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
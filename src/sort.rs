@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Sort orders data rows of a table by a column, a header is left in place.
+///
+/// Rows are moved together with their settings and the source data is not touched.
+/// The sort is stable, so rows with equal values keep their order.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Sort, Style};
+///     let data = vec!["file10", "file2", "file1"];
+///     let table = table!(&data, Style::psql(), Sort::by_column(0).natural());
+///
+///     assert_eq!(table, concat!(
+///         "  &str  \n",
+///         "--------\n",
+///         " file1  \n",
+///         " file2  \n",
+///         " file10 \n",
+///     ));
+/// ```
+pub struct Sort {
+    column: SortColumn,
+    descending: bool,
+    compare: Comparator,
+}
+
+type Comparator = Box<dyn Fn(&str, &str) -> Ordering>;
+
+enum SortColumn {
+    Index(usize),
+    Header(String),
+}
+
+impl Sort {
+    /// By column creates an option which sorts rows by a column with a given index.
+    pub fn by_column(column: usize) -> Self {
+        Self::new(SortColumn::Index(column))
+    }
+
+    /// By header creates an option which sorts rows by a column with a given header.
+    ///
+    /// If there's no such column or the table has no header it's left as it is.
+    pub fn by_header<S: Into<String>>(header: S) -> Self {
+        Self::new(SortColumn::Header(header.into()))
+    }
+
+    fn new(column: SortColumn) -> Self {
+        Self {
+            column,
+            descending: false,
+            compare: Box::new(|a, b| a.cmp(b)),
+        }
+    }
+
+    /// Descending reverses an order of rows.
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+
+    /// Natural makes numbers inside of values to be compared as numbers,
+    /// so `file10` goes after `file2`.
+    pub fn natural(self) -> Self {
+        self.compare(natural_cmp)
+    }
+
+    /// Compare sets a custom function which compares values of a column.
+    pub fn compare<F: Fn(&str, &str) -> Ordering + 'static>(mut self, f: F) -> Self {
+        self.compare = Box::new(f);
+        self
+    }
+
+    fn column(&self, grid: &Grid) -> Option<usize> {
+        match &self.column {
+            SortColumn::Index(column) if *column < grid.count_columns() => Some(*column),
+            SortColumn::Index(_) => None,
+            SortColumn::Header(_) if !grid.has_header() => None,
+            SortColumn::Header(header) => {
+                (0..grid.count_columns()).find(|&column| grid.get_cell(0, column) == Some(header))
+            }
+        }
+    }
+}
+
+impl TableOption for Sort {
+    fn change(&self, grid: &mut Grid) {
+        let column = match self.column(grid) {
+            Some(column) => column,
+            None => return,
+        };
+
+        let first_row = if grid.has_header() { 1 } else { 0 };
+        let values = (0..grid.count_rows())
+            .map(|row| grid.get_cell(row, column).unwrap_or_default().to_owned())
+            .collect::<Vec<_>>();
+        let mut order = (first_row..grid.count_rows()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            let ordering = (self.compare)(&values[a], &values[b]);
+            if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        // rows are put in place one by one, `positions` tracks where the original rows are now
+        let mut positions = (0..grid.count_rows()).collect::<Vec<_>>();
+        let mut rows = positions.clone();
+        for (i, original) in order.into_iter().enumerate() {
+            let (target, current) = (i + first_row, positions[original]);
+            if target != current {
+                let _ = grid.swap_rows(target, current);
+                rows.swap(target, current);
+                positions[rows[target]] = target;
+                positions[rows[current]] = current;
+            }
+        }
+    }
+}
+
+// It compares runs of digits as numbers and the rest of a text as is.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (x, rest_a) = split_digits(a);
+                let (y, rest_b) = split_digits(b);
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                a = rest_a;
+                b = rest_b;
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+
+                a = &a[x.len_utf8()..];
+                b = &b[y.len_utf8()..];
+            }
+        }
+    }
+}
+
+fn split_digits(text: &str) -> (&str, &str) {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    text.split_at(end)
+}
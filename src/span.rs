@@ -0,0 +1,48 @@
+use papergrid::Grid;
+
+use crate::{Object, TableOption};
+
+/// Span makes cells span over a given number of columns.
+///
+/// Cells of an object are processed row by row from left to right,
+/// a cell which is covered by a span set before it is skipped,
+/// so `Span(Row(1..2), 2)` merges cells of a row in pairs.
+/// A span which goes beyond the last column is ignored.
+///
+/// If a content of a spanned cell doesn't fit into its columns they are widened,
+/// it can be changed by passing a [`SpanDistribution`](./enum.SpanDistribution.html) to a table.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Cell, Span, Style};
+///     let data = vec![("Fedora", "dnf"), ("OpenSUSE", "zypper")];
+///     let table = table!(&data, Style::psql(), Span(Cell(1, 0), 2));
+///
+///     assert_eq!(table, concat!(
+///         "   &str   |  &str  \n",
+///         "----------+--------\n",
+///         "      Fedora       \n",
+///         " OpenSUSE | zypper \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct Span<O: Object>(pub O, pub usize);
+
+impl<O: Object> TableOption for Span<O> {
+    fn change(&self, grid: &mut Grid) {
+        let mut cells = self.0.cells(grid.count_rows(), grid.count_columns());
+        cells.sort_unstable();
+
+        let mut covered = None;
+        for (row, column) in cells {
+            if matches!(covered, Some((r, end)) if r == row && column < end) {
+                continue;
+            }
+
+            if grid.set_column_span(row, column, self.1).is_ok() {
+                covered = Some((row, column + self.1));
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// Split cuts a table which is too wide into segments of at most a given number of columns
+/// and stacks them one under another, each segment repeats a header.
+///
+/// A key column can be repeated at the beginning of each segment so rows of different segments can be matched.
+///
+/// Only a content of cells is moved, settings stay in place.
+/// A header of each segment takes over borders of the first row,
+/// so the option is supposed to be applied after a [`Style`](crate::Style).
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Split, Style};
+///     let data = vec![("Fedora", 34, "dnf"), ("Debian", 11, "apt")];
+///     let table = table!(&data, Style::psql(), Split::column(2));
+///
+///     assert_eq!(table, concat!(
+///         "  &str  | i32 \n",
+///         "--------+-----\n",
+///         " Fedora | 34  \n",
+///         " Debian | 11  \n",
+///         "--------+-----\n",
+///         "  &str  |     \n",
+///         "--------+-----\n",
+///         "  dnf   |     \n",
+///         "  apt   |     \n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Split {
+    columns: usize,
+    key: Option<usize>,
+}
+
+impl Split {
+    /// Column creates an option which leaves at most `columns` columns in a segment.
+    pub fn column(columns: usize) -> Self {
+        Self { columns, key: None }
+    }
+
+    /// Key sets a column which is repeated in each segment, it's counted in the limit of columns.
+    pub fn key(mut self, column: usize) -> Self {
+        self.key = Some(column);
+        self
+    }
+}
+
+impl TableOption for Split {
+    fn change(&self, grid: &mut Grid) {
+        let count_rows = grid.count_rows();
+        let count_columns = grid.count_columns();
+        let key = self.key.filter(|&column| column < count_columns);
+
+        let columns = (0..count_columns)
+            .filter(|&column| Some(column) != key)
+            .collect::<Vec<_>>();
+        let keys = if key.is_some() { 1 } else { 0 };
+        let per_segment = self.columns.saturating_sub(keys).max(1);
+        if count_rows == 0 || columns.len() <= per_segment {
+            return;
+        }
+
+        let segments = columns
+            .chunks(per_segment)
+            .map(|chunk| key.iter().chain(chunk).copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let records = grid
+            .rows()
+            .map(|row| row.map(|(_, _, text)| text.to_owned()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        grid.resize(count_rows * segments.len(), per_segment + keys);
+
+        for (i, segment) in segments.iter().enumerate() {
+            for (row, record) in records.iter().enumerate() {
+                for column in 0..grid.count_columns() {
+                    let text = segment.get(column).map(|&c| record[c].clone());
+                    if let Some(cell) = grid.get_cell_mut(i * count_rows + row, column) {
+                        *cell = text.unwrap_or_default();
+                    }
+                }
+            }
+
+            // a segment is separated from the previous one by the same line as its header
+            if i > 0 {
+                let header = grid.get_border_mut(0).clone();
+                *grid.get_border_mut(i * count_rows - 1) = header.clone();
+                *grid.get_border_mut(i * count_rows) = header;
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! This module contains an export of a grid into SQL `INSERT` statements,
+//! to move tabulated data into a database.
+//!
+//! # Example
+//!
+//! ```rust
+//!     use tabled::{build_grid, sql};
+//!
+//!     let data = vec![("Fedora", 34), ("O'Reilly", 15)];
+//!     let grid = build_grid(&data);
+//!
+//!     assert_eq!(sql::insert(&grid, "distributions"), concat!(
+//!         "INSERT INTO distributions (\"&str\", \"i32\") VALUES ('Fedora', 34);\n",
+//!         "INSERT INTO distributions (\"&str\", \"i32\") VALUES ('O''Reilly', 15);\n",
+//!     ));
+//! ```
+
+use papergrid::Grid;
+
+/// Insert returns an `INSERT` statement for each record of a grid.
+///
+/// Headers are used as names of columns, they're quoted by `"` so any header is a valid name.
+/// If a grid is built without a header names of columns are omitted.
+/// A name of a table is written as it is, so it may include a schema.
+///
+/// Numbers are written as they are, empty cells are `NULL`
+/// and everything else is a string literal quoted by `'`.
+pub fn insert(grid: &Grid, table: &str) -> String {
+    let first_row = if grid.has_header() { 1 } else { 0 };
+    let columns = if grid.has_header() {
+        let names = (0..grid.count_columns())
+            .map(|column| quote_identifier(&grid.get_content(0, column).unwrap_or_default()))
+            .collect::<Vec<_>>();
+        format!(" ({})", names.join(", "))
+    } else {
+        String::new()
+    };
+
+    let mut sql = String::new();
+    for row in first_row..grid.count_rows() {
+        let values = (0..grid.count_columns())
+            .map(|column| value(&grid.get_content(row, column).unwrap_or_default()))
+            .collect::<Vec<_>>();
+
+        sql.push_str(&format!(
+            "INSERT INTO {}{} VALUES ({});\n",
+            table,
+            columns,
+            values.join(", ")
+        ));
+    }
+
+    sql
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn value(text: &str) -> String {
+    if text.is_empty() {
+        "NULL".to_owned()
+    } else if is_number(text) {
+        text.to_owned()
+    } else {
+        format!("'{}'", text.replace('\'', "''"))
+    }
+}
+
+// only plain decimal numbers are written unquoted,
+// a number with leading zeros like a zip code would lose them in a numeric literal
+//
+// It's used by an xlsx export as well so a cell is a number in both of them.
+pub(crate) fn is_number(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits, None),
+    };
+
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+    is_digits(integer)
+        && (integer == "0" || !integer.starts_with('0'))
+        && fraction.map_or(true, is_digits)
+}
@@ -1,4 +1,4 @@
-use papergrid::{Border, Grid};
+use papergrid::{Entity, Grid, Padding, Settings, Theme};
 
 use crate::TableOption;
 
@@ -21,11 +21,43 @@ use crate::TableOption;
 ///     println!("{}", table);
 /// ```
 ///
+/// With a `serde` feature a style can be serialized, so it can be kept in a config file like a theme.
+/// Absent lines are treated as turned off.
+///
+/// A preset can be tweaked further and kept as a reusable value.
+///
+/// ```rust
+///     use tabled::{table, Style};
+///     let style = Style::pseudo_clean()
+///         .line_under_header('═')
+///         .corner_top_left('╭')
+///         .corner_top_right('╮')
+///         .corner_bottom_left('╰')
+///         .corner_bottom_right('╯');
+///
+///     let data = vec!["Hello", "2021"];
+///     let table = table!(&data, style.clone());
+///
+///     assert_eq!(table, concat!(
+///         "╭───────╮\n",
+///         "│ &str  │\n",
+///         "├═══════┤\n",
+///         "│ Hello │\n",
+///         "│ 2021  │\n",
+///         "╰───────╯\n",
+///     ));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
+    #[cfg_attr(feature = "serde", serde(default))]
     frame: Frame,
+    #[cfg_attr(feature = "serde", serde(rename = "header"))]
     header_split_line: Option<Line>,
     split: Option<Line>,
-    inner_split_char: char,
+    #[cfg_attr(feature = "serde", serde(rename = "inner"))]
+    inner_split_char: Option<char>,
+    column_gap: Option<usize>,
 }
 
 impl Style {
@@ -42,6 +74,7 @@ impl Style {
     ///     | 3  | Endeavouros  | https://endeavouros.com/  |
     ///     +----+--------------+---------------------------+
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
         let line = Line::bordered('-', '+', '+', '+');
 
@@ -54,7 +87,7 @@ impl Style {
             },
             Some(line.clone()),
             Some(line),
-            '|',
+            Some('|'),
         )
     }
 
@@ -67,7 +100,7 @@ impl Style {
     ///      3    Endeavouros    https://endeavouros.com/
     /// ```
     pub fn noborder() -> Self {
-        Self::new(Frame::default(), None, None, ' ')
+        Self::new(Frame::default(), None, None, Some(' '))
     }
 
     /// Psql style looks like the following table
@@ -80,7 +113,12 @@ impl Style {
     ///      3  | Endeavouros  | https://endeavouros.com/
     /// ```
     pub fn psql() -> Self {
-        Self::new(Frame::default(), Some(Line::short('-', '+')), None, '|')
+        Self::new(
+            Frame::default(),
+            Some(Line::short('-', '+')),
+            None,
+            Some('|'),
+        )
     }
 
     /// Github_markdown style looks like the following table
@@ -101,7 +139,7 @@ impl Style {
             },
             Some(Line::bordered('-', '+', '|', '|')),
             None,
-            '|',
+            Some('|'),
         )
     }
     /// Pseudo style looks like the following table
@@ -127,7 +165,7 @@ impl Style {
             },
             Some(Line::bordered('─', '┼', '├', '┤')),
             Some(Line::bordered('─', '┼', '├', '┤')),
-            '│',
+            Some('│'),
         )
     }
 
@@ -148,6 +186,54 @@ impl Style {
         pseudo
     }
 
+    /// Frame style looks like the following table
+    ///
+    /// ```text
+    ///     +---------------------------------------------+
+    ///     | id  destribution            link            |
+    ///     | 0      Fedora      https://getfedora.org/   |
+    ///     | 2     OpenSUSE    https://www.opensuse.org/ |
+    ///     | 3   Endeavouros   https://endeavouros.com/  |
+    ///     +---------------------------------------------+
+    /// ```
+    pub fn frame() -> Self {
+        let line = Line {
+            main: '-',
+            intersection: None,
+            left_corner: Some('+'),
+            right_corner: Some('+'),
+        };
+
+        Self::new(
+            Frame {
+                bottom: Some(line.clone()),
+                top: Some(line),
+                left: Some('|'),
+                right: Some('|'),
+            },
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Blank style has no lines at all, columns are separated by `gap` spaces.
+    ///
+    /// It removes a padding of cells so it's supposed to be applied before other settings of a padding.
+    /// It looks like the following table with a gap of 2
+    ///
+    /// ```text
+    ///     id  destribution  link
+    ///     0   Fedora        https://getfedora.org/
+    ///     2   OpenSUSE      https://www.opensuse.org/
+    ///     3   Endeavouros   https://endeavouros.com/
+    /// ```
+    pub fn blank(gap: usize) -> Self {
+        let mut blank = Self::new(Frame::default(), None, None, None);
+        blank.column_gap = Some(gap);
+        blank
+    }
+
     pub fn frame_left(mut self, frame: Option<char>) -> Self {
         self.frame.left = frame;
         self
@@ -180,24 +266,168 @@ impl Style {
     }
 
     pub fn inner(mut self, c: char) -> Self {
-        self.inner_split_char = c;
+        self.inner_split_char = Some(c);
+        self
+    }
+
+    /// Remove_horizontal removes horizontal lines between rows, including the one under a header.
+    pub fn remove_horizontal(mut self) -> Self {
+        self.header_split_line = None;
+        self.split = None;
+        self
+    }
+
+    /// Remove_vertical removes vertical lines between columns.
+    pub fn remove_vertical(mut self) -> Self {
+        self.inner_split_char = None;
+        for line in self.lines_mut() {
+            line.intersection = None;
+        }
+
+        self
+    }
+
+    /// Remove_frame removes lines around a table.
+    pub fn remove_frame(mut self) -> Self {
+        self.frame = Frame::default();
+        for line in self.lines_mut() {
+            line.left_corner = None;
+            line.right_corner = None;
+        }
+
+        self
+    }
+
+    /// Horizontal sets a character of all horizontal lines.
+    pub fn horizontal(mut self, c: char) -> Self {
+        for line in self.lines_mut() {
+            line.main = c;
+        }
+
+        self
+    }
+
+    /// Vertical sets a character of all vertical lines, including the ones of a frame.
+    pub fn vertical(mut self, c: char) -> Self {
+        let frame = &mut self.frame;
+        for side in [
+            &mut self.inner_split_char,
+            &mut frame.left,
+            &mut frame.right,
+        ]
+        .iter_mut()
+        {
+            if side.is_some() {
+                **side = Some(c);
+            }
+        }
+
+        self
+    }
+
+    /// Line_under_header sets a character of a line under a header, the line is added if there's none.
+    pub fn line_under_header(mut self, c: char) -> Self {
+        let line = self.header_split_line.get_or_insert(Line {
+            main: c,
+            intersection: self.inner_split_char,
+            left_corner: self.frame.left,
+            right_corner: self.frame.right,
+        });
+        line.main = c;
+        self
+    }
+
+    /// Corner_top_left sets a top left corner of a frame.
+    pub fn corner_top_left(mut self, c: char) -> Self {
+        if let Some(line) = &mut self.frame.top {
+            line.left_corner = Some(c);
+        }
+
+        self
+    }
+
+    /// Corner_top_right sets a top right corner of a frame.
+    pub fn corner_top_right(mut self, c: char) -> Self {
+        if let Some(line) = &mut self.frame.top {
+            line.right_corner = Some(c);
+        }
+
         self
     }
 
-    fn new(frame: Frame, header: Option<Line>, split: Option<Line>, inner: char) -> Self {
+    /// Corner_bottom_left sets a bottom left corner of a frame.
+    pub fn corner_bottom_left(mut self, c: char) -> Self {
+        if let Some(line) = &mut self.frame.bottom {
+            line.left_corner = Some(c);
+        }
+
+        self
+    }
+
+    /// Corner_bottom_right sets a bottom right corner of a frame.
+    pub fn corner_bottom_right(mut self, c: char) -> Self {
+        if let Some(line) = &mut self.frame.bottom {
+            line.right_corner = Some(c);
+        }
+
+        self
+    }
+
+    // lines are resolved by positions of rows at render time,
+    // so a style can be applied before or after rows are added or removed
+    fn theme(&self) -> Theme {
+        let mut theme =
+            Theme::new().inner(self.inner_split_char, self.frame.left, self.frame.right);
+        let lines = [
+            (&self.frame.top, Theme::top as LineSetter),
+            (&self.frame.bottom, Theme::bottom),
+            (&self.header_split_line, Theme::header),
+            (&self.split, Theme::split),
+        ];
+        for (line, set) in lines.iter() {
+            if let Some(line) = line {
+                theme = set(
+                    theme,
+                    line.main,
+                    line.intersection,
+                    line.left_corner,
+                    line.right_corner,
+                );
+            }
+        }
+
+        theme
+    }
+
+    fn lines_mut(&mut self) -> impl Iterator<Item = &mut Line> {
+        vec![
+            self.frame.top.as_mut(),
+            self.frame.bottom.as_mut(),
+            self.header_split_line.as_mut(),
+            self.split.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    fn new(frame: Frame, header: Option<Line>, split: Option<Line>, inner: Option<char>) -> Self {
         Self {
             frame,
             split,
             header_split_line: header,
             inner_split_char: inner,
+            column_gap: None,
         }
     }
 }
 
+type LineSetter = fn(Theme, char, Option<char>, Option<char>, Option<char>) -> Theme;
+
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line {
     main: char,
-    intersection: char,
+    intersection: Option<char>,
     left_corner: Option<char>,
     right_corner: Option<char>,
 }
@@ -205,7 +435,7 @@ pub struct Line {
 impl Line {
     pub fn bordered(main: char, intersection: char, left: char, right: char) -> Self {
         Self {
-            intersection,
+            intersection: Some(intersection),
             main,
             left_corner: Some(left),
             right_corner: Some(right),
@@ -215,13 +445,14 @@ impl Line {
     pub fn short(main: char, intersection: char) -> Self {
         Self {
             main,
-            intersection,
+            intersection: Some(intersection),
             ..Default::default()
         }
     }
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Frame {
     top: Option<Line>,
     bottom: Option<Line>,
@@ -231,56 +462,20 @@ struct Frame {
 
 impl TableOption for Style {
     fn change(&self, grid: &mut Grid) {
-        let count_rows = grid.count_rows();
-        for row in 0..count_rows {
-            let border = grid.get_border_mut(row);
-            make_style(self, border, row == 0, row == count_rows - 1);
-        }
-    }
-}
-
-fn make_style(style: &Style, border: &mut Border, is_first_row: bool, is_last_row: bool) {
-    let border = border.empty();
+        grid.set_theme(self.theme());
 
-    if is_first_row {
-        if let Some(line) = &style.frame.top {
-            border.top(
-                line.main,
-                line.intersection,
-                line.left_corner,
-                line.right_corner,
+        if let Some(gap) = self.column_gap {
+            let count_columns = grid.count_columns();
+            grid.set(
+                Entity::Global,
+                Settings::new().padding(Padding::new(0, gap, 0, 0)),
             );
+            if count_columns > 0 {
+                grid.set(
+                    Entity::Column(count_columns - 1),
+                    Settings::new().padding(Padding::default()),
+                );
+            }
         }
-
-        if let Some(line) = &style.header_split_line {
-            border.bottom(
-                line.main,
-                line.intersection,
-                line.left_corner,
-                line.right_corner,
-            );
-        }
-    } else if is_last_row {
-        if let Some(line) = &style.frame.bottom {
-            border.bottom(
-                line.main,
-                line.intersection,
-                line.left_corner,
-                line.right_corner,
-            );
-        }
-    } else if let Some(line) = &style.split {
-        border.bottom(
-            line.main,
-            line.intersection,
-            line.left_corner,
-            line.right_corner,
-        );
     }
-
-    border.inner(
-        Some(style.inner_split_char),
-        style.frame.left,
-        style.frame.right,
-    );
 }
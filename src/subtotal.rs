@@ -0,0 +1,221 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{baseline::parse_number, TableOption};
+
+/// Subtotals inserts a subtotal row after each group of rows and a grand total at the end.
+///
+/// A group is a run of consecutive rows with the same value in a group-by column,
+/// so data is expected to be sorted by it.
+/// A label of a subtotal row is put into a group-by column,
+/// aggregated values are put into the columns they were computed from.
+///
+/// Cells which don't start with a number are not taken into account by aggregates except [`Aggregate::Count`].
+/// The option rewrites a content of data rows so it's expected to be applied before any per-row settings.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Aggregate, Style, Subtotals};
+///     let data = vec![("east", "apples", 10), ("east", "pears", 5), ("west", "apples", 7)];
+///
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Subtotals::new(0)
+///             .aggregate(2, Aggregate::Sum)
+///             .format(|s| format!("*{}*", s)),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "    &str    |  &str  | i32  \n",
+///         "------------+--------+------\n",
+///         "    east    | apples |  10  \n",
+///         "    east    | pears  |  5   \n",
+///         " *Subtotal* |        | *15* \n",
+///         "    west    | apples |  7   \n",
+///         " *Subtotal* |        | *7*  \n",
+///         "  *Total*   |        | *22* \n",
+///     ));
+/// ```
+pub struct Subtotals {
+    group_by: usize,
+    aggregates: Vec<(usize, Aggregate)>,
+    label: String,
+    total_label: Option<String>,
+    format: Box<dyn Fn(&str) -> String>,
+}
+
+/// Aggregate is a function which computes a value of a column for a group of rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// A sum of values
+    Sum,
+    /// A number of rows in a group
+    Count,
+    /// An arithmetic mean of values
+    Average,
+    /// The smallest value
+    Min,
+    /// The largest value
+    Max,
+}
+
+impl Subtotals {
+    /// New creates a subtotal option which groups rows by a given column.
+    ///
+    /// By default subtotal rows are labeled `Subtotal` and a grand total `Total`.
+    pub fn new(group_by: usize) -> Self {
+        Self {
+            group_by,
+            aggregates: Vec::new(),
+            label: String::from("Subtotal"),
+            total_label: Some(String::from("Total")),
+            format: Box::new(|s| s.to_owned()),
+        }
+    }
+
+    /// Aggregate adds a column which is aggregated in subtotal rows.
+    pub fn aggregate(mut self, column: usize, aggregate: Aggregate) -> Self {
+        self.aggregates.push((column, aggregate));
+        self
+    }
+
+    /// Label sets a label of subtotal rows.
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Total label sets a label of a grand total row.
+    pub fn total_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.total_label = Some(label.into());
+        self
+    }
+
+    /// No total turns off a grand total row.
+    pub fn no_total(mut self) -> Self {
+        self.total_label = None;
+        self
+    }
+
+    /// Format sets a function which styles non empty cells of subtotal and total rows,
+    /// like making them bold.
+    pub fn format<F: Fn(&str) -> String + 'static>(mut self, f: F) -> Self {
+        self.format = Box::new(f);
+        self
+    }
+
+    fn summary(&self, label: &str, rows: &[Vec<String>], count_columns: usize) -> Vec<String> {
+        let mut summary = vec![String::new(); count_columns];
+        summary[self.group_by] = label.to_owned();
+        for &(column, aggregate) in &self.aggregates {
+            if column < count_columns {
+                let values = rows.iter().map(|row| row[column].as_str());
+                summary[column] = aggregate.compute(values);
+            }
+        }
+
+        summary
+            .into_iter()
+            .map(|text| {
+                if text.is_empty() {
+                    text
+                } else {
+                    (self.format)(&text)
+                }
+            })
+            .collect()
+    }
+}
+
+impl Aggregate {
+    pub(crate) fn compute<'a>(&self, texts: impl Iterator<Item = &'a str>) -> String {
+        let texts = texts.collect::<Vec<_>>();
+        if let Aggregate::Count = self {
+            return texts.len().to_string();
+        }
+
+        let values = texts
+            .iter()
+            .filter_map(|text| parse_number(text))
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return String::new();
+        }
+
+        // a result is printed with the same precision as the values
+        let precision = texts.iter().map(|text| decimals(text)).max().unwrap_or(0);
+        let (value, precision) = match self {
+            Aggregate::Sum => (values.iter().sum(), precision),
+            Aggregate::Average => (
+                values.iter().sum::<f64>() / values.len() as f64,
+                precision.max(2),
+            ),
+            Aggregate::Min => (
+                values.iter().cloned().fold(f64::INFINITY, f64::min),
+                precision,
+            ),
+            Aggregate::Max => (
+                values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+                precision,
+            ),
+            Aggregate::Count => unreachable!(),
+        };
+
+        format!("{:.*}", precision, value)
+    }
+}
+
+// It counts digits after a decimal point of a number at the beginning of a text.
+fn decimals(text: &str) -> usize {
+    let text = text.trim();
+    match text.find('.') {
+        Some(dot) if parse_number(&text[..dot]).is_some() => text[dot + 1..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .count(),
+        _ => 0,
+    }
+}
+
+impl TableOption for Subtotals {
+    fn change(&self, grid: &mut Grid) {
+        let count_columns = grid.count_columns();
+        if self.group_by >= count_columns || grid.count_rows() < 2 {
+            return;
+        }
+
+        let records = grid
+            .rows()
+            .skip(1)
+            .map(|row| row.map(|(_, _, text)| text.to_owned()).collect())
+            .collect::<Vec<Vec<String>>>();
+
+        let mut rows = Vec::new();
+        let mut start = 0;
+        for end in 1..=records.len() {
+            let is_group_end = end == records.len()
+                || records[end][self.group_by] != records[start][self.group_by];
+            if is_group_end {
+                let group = &records[start..end];
+                rows.extend(group.iter().cloned());
+                rows.push(self.summary(&self.label, group, count_columns));
+                start = end;
+            }
+        }
+
+        if let Some(label) = &self.total_label {
+            rows.push(self.summary(label, &records, count_columns));
+        }
+
+        while grid.count_rows() < rows.len() + 1 {
+            grid.push_row();
+        }
+
+        for (i, row) in rows.into_iter().enumerate() {
+            for (column, text) in row.into_iter().enumerate() {
+                grid.set(Entity::Cell(i + 1, column), Settings::new().text(text));
+            }
+        }
+    }
+}
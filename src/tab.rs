@@ -0,0 +1,24 @@
+use papergrid::Grid;
+
+use crate::TableOption;
+
+/// TabSize sets a distance between tab stops tabs in cells are expanded to.
+///
+/// By default a tab stop is each 4 columns, 0 leaves tabs as they are.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Style, TabSize};
+///     let data = vec!["a\tb"];
+///     let table = table!(&data, Style::psql(), TabSize(2));
+///     assert_eq!(table, " &str \n------\n a b  \n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabSize(pub usize);
+
+impl TableOption for TabSize {
+    fn change(&self, grid: &mut Grid) {
+        grid.set_tab_width(self.0);
+    }
+}
@@ -0,0 +1,187 @@
+//! This module contains a generator of random tables.
+//!
+//! Tables are reproducible, the same seed always gives the same table,
+//! so they can be used for fuzzing, stress-testing of settings and demos.
+//!
+//! # Example
+//!
+//! ```rust
+//!     use tabled::testdata::{Content, Generator};
+//!
+//!     let generator = Generator::new(42)
+//!         .size(3, 4)
+//!         .content(&[Content::Unicode, Content::Multiline]);
+//!
+//!     let grid = generator.build();
+//!     assert_eq!(grid.count_rows(), 4);
+//!     assert_eq!(grid.count_columns(), 4);
+//!     assert_eq!(grid.to_string(), generator.build().to_string());
+//! ```
+
+use papergrid::Grid;
+
+use crate::build_grid_from_records;
+
+/// Content is a class of a text generated for cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Content {
+    /// Latin letters and digits
+    Ascii,
+    /// Non latin letters
+    Unicode,
+    /// Text colored by ANSI escape sequences
+    Ansi,
+    /// Text split into several lines
+    Multiline,
+}
+
+/// Generator generates tables with random content.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    seed: u64,
+    rows: usize,
+    columns: usize,
+    max_width: usize,
+    max_lines: usize,
+    content: Vec<Content>,
+}
+
+impl Generator {
+    /// New creates a generator with a given seed.
+    ///
+    /// By default it generates a 5x5 table with ASCII content.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rows: 5,
+            columns: 5,
+            max_width: 10,
+            max_lines: 3,
+            content: vec![Content::Ascii],
+        }
+    }
+
+    /// Size sets a number of data rows and columns.
+    ///
+    /// A header row is generated in addition to the data rows.
+    pub fn size(mut self, rows: usize, columns: usize) -> Self {
+        self.rows = rows;
+        self.columns = columns;
+        self
+    }
+
+    /// Max width sets a maximum length of a line in a cell.
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Max lines sets a maximum number of lines in a multiline cell.
+    pub fn max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = lines;
+        self
+    }
+
+    /// Content sets classes of a text, each cell gets one of them at random.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the list is empty.
+    pub fn content(mut self, content: &[Content]) -> Self {
+        assert!(
+            !content.is_empty(),
+            "at least one content class is expected"
+        );
+        self.content = content.to_vec();
+        self
+    }
+
+    /// Headers generates names of columns.
+    pub fn headers(&self) -> Vec<String> {
+        let mut rng = Rng::new(self.seed);
+        (0..self.columns)
+            .map(|_| self.text(&mut rng, Content::Ascii))
+            .collect()
+    }
+
+    /// Records generates a content of data rows.
+    pub fn records(&self) -> Vec<Vec<String>> {
+        // a different stream is used so records don't depend on a number of columns in a header
+        let mut rng = Rng::new(self.seed ^ 0x5DEE_CE66_D1CE_4E5B);
+        (0..self.rows)
+            .map(|_| {
+                (0..self.columns)
+                    .map(|_| {
+                        let content = self.content[rng.below(self.content.len())];
+                        self.text(&mut rng, content)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Build generates a grid.
+    pub fn build(&self) -> Grid {
+        build_grid_from_records(self.headers(), self.records())
+    }
+
+    fn text(&self, rng: &mut Rng, content: Content) -> String {
+        match content {
+            Content::Ascii => self.line(rng, ASCII),
+            Content::Unicode => self.line(rng, UNICODE),
+            Content::Ansi => {
+                let color = 31 + rng.below(7);
+                format!("\u{1b}[{}m{}\u{1b}[0m", color, self.line(rng, ASCII))
+            }
+            Content::Multiline => {
+                let count_lines = 1 + rng.below(self.max_lines.max(1));
+                (0..count_lines)
+                    .map(|_| self.line(rng, ASCII))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        }
+    }
+
+    fn line(&self, rng: &mut Rng, alphabet: &[char]) -> String {
+        let length = rng.below(self.max_width + 1);
+        (0..length)
+            .map(|_| alphabet[rng.below(alphabet.len())])
+            .collect()
+    }
+}
+
+const ASCII: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'X', 'Y', 'Z', '0', '1', '2', '7', '8', '9',
+    ' ', '-', '_',
+];
+
+const UNICODE: &[char] = &[
+    'д', 'ж', 'я', 'Ё', 'λ', 'Ω', 'é', 'ß', 'ø', 'ü', 'ł', 'ñ', 'ç', 'ş', 'ў', 'ї',
+];
+
+// SplitMix64 is used as it's simple and good enough for generating test data.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+
+        (self.next() % n as u64) as usize
+    }
+}
@@ -0,0 +1,88 @@
+//! This module contains helpers for testing rendered tables.
+//!
+//! [`assert_table!`](../macro.assert_table.html) compares a table with an expected text
+//! and prints a line by line diff when they don't match,
+//! which is easier to read than two long strings.
+//!
+//! # Example
+//!
+//! ```rust
+//!     use tabled::{assert_table, table, Style};
+//!
+//!     let table = table!(&["asd"], Style::psql());
+//!     assert_table!(table, " &str \n------\n asd  \n");
+//! ```
+
+/// Diff compares an expected text with an actual one line by line,
+/// it returns `None` if they are equal.
+///
+/// Lines are printed quoted so trailing spaces are visible,
+/// mismatched expected lines are marked by `-` and painted red,
+/// mismatched actual lines are marked by `+` and painted green.
+///
+/// ```rust
+///     use tabled::testing::diff;
+///
+///     assert_eq!(diff("a\nb\n", "a\nb\n"), None);
+///     assert_eq!(
+///         diff("a\nb\n", "a\nc\n").unwrap(),
+///         "  \"a\"\n\u{1b}[31m- \"b\"\u{1b}[0m\n\u{1b}[32m+ \"c\"\u{1b}[0m\n  \"\"\n",
+///     );
+/// ```
+pub fn diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let expected_lines = split_lines(expected);
+    let actual_lines = split_lines(actual);
+    let count_lines = expected_lines.len().max(actual_lines.len());
+
+    let mut diff = String::new();
+    for i in 0..count_lines {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                diff.push_str(&format!("  {:?}\n", expected));
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    diff.push_str(&format!("\u{1b}[31m- {:?}\u{1b}[0m\n", expected));
+                }
+                if let Some(actual) = actual {
+                    diff.push_str(&format!("\u{1b}[32m+ {:?}\u{1b}[0m\n", actual));
+                }
+            }
+        }
+    }
+
+    Some(diff)
+}
+
+// a text is split by line endings so a missing trailing newline is shown as a missing empty line
+fn split_lines(text: &str) -> Vec<&str> {
+    text.split('\n').collect()
+}
+
+/// Assert_table asserts that a table, or anything which implements `Display`, renders as an expected text.
+///
+/// On mismatch it panics with a line by line diff made by [`testing::diff`](./testing/fn.diff.html).
+///
+/// # Example
+///
+/// ```rust,should_panic
+///     use tabled::{assert_table, table, Style};
+///
+///     let table = table!(&["asd"], Style::psql());
+///     assert_table!(table, " &str \n------\n qwe  \n");
+/// ```
+#[macro_export]
+macro_rules! assert_table {
+    ( $table:expr, $expected:expr $(,)? ) => {{
+        let table = $table.to_string();
+        let expected = $expected;
+        let expected: &str = ::std::convert::AsRef::<str>::as_ref(&expected);
+        if let Some(diff) = tabled::testing::diff(expected, &table) {
+            panic!("a table doesn't match the expected one:\n{}", diff);
+        }
+    }};
+}
@@ -0,0 +1,125 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use papergrid::{Entity, Grid, Settings};
+use std::fmt::{Display, Write};
+
+use crate::{Object, TableOption};
+
+/// Timestamp converts timestamps in cells to a given timezone and prints them in a given format.
+///
+/// It understands RFC 3339 timestamps, like `2021-06-14T10:04:00+03:00`,
+/// and timestamps without an offset which are considered to be in UTC.
+/// Cells which can't be parsed, like a header, are left untouched,
+/// as well as all cells if a pattern is invalid.
+///
+/// The option is available only with a `timestamp` feature.
+///
+/// # Example
+///
+/// ```rust
+///     use chrono::{FixedOffset, TimeZone, Utc};
+///     use tabled::{table, Row, Style, TimeFormat, Timestamp};
+///     let data = vec!["2021-06-14T10:04:00+03:00", "2021-06-14 09:00:00"];
+///     let now = Utc.with_ymd_and_hms(2021, 6, 14, 9, 30, 0).unwrap();
+///
+///     let table = table!(
+///         &data,
+///         Style::psql(),
+///         Timestamp(Row(1..2), FixedOffset::east_opt(3600).unwrap(), TimeFormat::pattern("%H:%M")),
+///         Timestamp(Row(2..), Utc, TimeFormat::RelativeTo(now)),
+///     );
+///
+///     assert_eq!(table, concat!(
+///         "  &str   \n",
+///         "---------\n",
+///         "  08:04  \n",
+///         " 30m ago \n",
+///     ));
+/// ```
+pub struct Timestamp<O: Object, Tz: TimeZone>(pub O, pub Tz, pub TimeFormat);
+
+/// TimeFormat is a way a timestamp is printed by [`Timestamp`](./struct.Timestamp.html).
+#[derive(Debug, Clone)]
+pub enum TimeFormat {
+    /// A format in terms of [`chrono::format::strftime`].
+    Pattern(String),
+    /// A time passed since the timestamp, e.g. `3m ago`.
+    Relative,
+    /// A time passed since the timestamp till a given moment.
+    RelativeTo(DateTime<Utc>),
+}
+
+impl TimeFormat {
+    /// Pattern creates a [`TimeFormat::Pattern`].
+    pub fn pattern<S: Into<String>>(pattern: S) -> Self {
+        Self::Pattern(pattern.into())
+    }
+}
+
+impl<O, Tz> TableOption for Timestamp<O, Tz>
+where
+    O: Object,
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+            let content = grid.get_cell_content(row, column);
+            let time = match parse_timestamp(content) {
+                Some(time) => time,
+                None => continue,
+            };
+
+            let content = match &self.2 {
+                TimeFormat::Pattern(pattern) => {
+                    match format_time(&time.with_timezone(&self.1), pattern) {
+                        Some(content) => content,
+                        None => continue,
+                    }
+                }
+                TimeFormat::Relative => relative_time(time, Utc::now()),
+                TimeFormat::RelativeTo(now) => relative_time(time, *now),
+            };
+
+            grid.set(Entity::Cell(row, column), Settings::new().text(content));
+        }
+    }
+}
+
+fn parse_timestamp(text: &str) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(text) {
+        return Some(time.with_timezone(&Utc));
+    }
+
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .map(|time| Utc.from_utc_datetime(&time))
+}
+
+// an invalid pattern is reported only when a time is written, `to_string` would panic on it
+fn format_time<Tz>(time: &DateTime<Tz>, pattern: &str) -> Option<String>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    let mut text = String::new();
+    write!(text, "{}", time.format(pattern)).ok()?;
+    Some(text)
+}
+
+fn relative_time(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = now.signed_duration_since(time).num_seconds();
+    let (amount, unit) = match seconds.abs() {
+        s if s < 60 => (s, "s"),
+        s if s < 60 * 60 => (s / 60, "m"),
+        s if s < 60 * 60 * 24 => (s / (60 * 60), "h"),
+        s => (s / (60 * 60 * 24), "d"),
+    };
+
+    if seconds < 0 {
+        format!("in {}{}", amount, unit)
+    } else {
+        format!("{}{} ago", amount, unit)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{build_grid, Style, TableOption, Tabled};
+
+/// Transition renders frames of a table morphing from one style into another,
+/// it's handy for demos of styles.
+///
+/// The first frame is the table in the `from` style and the last one is in the `to` style.
+/// See [`morph`] for details.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{transition, Style};
+///     let data = vec!["Hello"];
+///
+///     let frames = transition(&data, &Style::default(), &Style::psql(), 5);
+///
+///     assert_eq!(frames.len(), 5);
+///     assert_eq!(frames[0], tabled::table!(&data));
+///     assert_eq!(frames[4], tabled::table!(&data, Style::psql()));
+/// ```
+pub fn transition<T: Tabled>(
+    data: &[T],
+    from: &Style,
+    to: &Style,
+    count_frames: usize,
+) -> Vec<String> {
+    let render = |style: &Style| {
+        let mut grid = build_grid(data);
+        style.change(&mut grid);
+        grid.to_string()
+    };
+
+    morph(&render(from), &render(to), count_frames)
+}
+
+/// Morph produces frames of a character-by-character transformation of one text into another.
+///
+/// Characters which differ are replaced in a reading order,
+/// each frame replaces about the same number of them.
+/// Texts are padded by spaces to the same size so all intermediate frames have the same shape.
+///
+/// The first frame is `from` and the last one is `to`, if only one frame is asked it's `to`.
+/// ANSI escape sequences are not taken into account so colored texts will be broken in the middle.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::morph;
+///     let frames = morph("abc\n", "xyz\n", 4);
+///     assert_eq!(frames, vec!["abc\n", "xbc\n", "xyc\n", "xyz\n"]);
+/// ```
+pub fn morph(from: &str, to: &str, count_frames: usize) -> Vec<String> {
+    if count_frames == 0 {
+        return Vec::new();
+    }
+    if count_frames == 1 {
+        return vec![to.to_owned()];
+    }
+
+    let count_lines = from.lines().count().max(to.lines().count());
+    let width = from
+        .lines()
+        .chain(to.lines())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let pad = |text: &str| {
+        let mut lines = text
+            .lines()
+            .map(|line| {
+                let mut line = line.chars().collect::<Vec<_>>();
+                line.resize(width, ' ');
+                line
+            })
+            .collect::<Vec<_>>();
+        lines.resize(count_lines, vec![' '; width]);
+        lines
+    };
+
+    let mut frame = pad(from);
+    let target = pad(to);
+    let diff = (0..count_lines)
+        .flat_map(|row| (0..width).map(move |column| (row, column)))
+        .filter(|&(row, column)| frame[row][column] != target[row][column])
+        .collect::<Vec<_>>();
+
+    let mut frames = vec![from.to_owned()];
+    let mut changed = 0;
+    for i in 1..count_frames - 1 {
+        let next = diff.len() * i / (count_frames - 1);
+        for &(row, column) in &diff[changed..next] {
+            frame[row][column] = target[row][column];
+        }
+        changed = next;
+
+        let text = frame
+            .iter()
+            .map(|line| line.iter().collect::<String>() + "\n")
+            .collect();
+        frames.push(text);
+    }
+    frames.push(to.to_owned());
+
+    frames
+}
@@ -0,0 +1,55 @@
+use papergrid::{Entity, Grid, Settings};
+
+use crate::{Object, TableOption};
+
+/// Vertical renders a content of cells vertically, one character per line.
+///
+/// It's handy for headers of narrow columns, like the ones with one digit numbers,
+/// so a table is not as wide as its header.
+/// Lines of a multiline content are put side by side.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Head, Style, Vertical};
+///     let data = vec![(1, 2)];
+///     let table = table!(&data, Style::psql(), Vertical(Head));
+///
+///     assert_eq!(table, concat!(
+///         " i | i \n",
+///         " 3 | 3 \n",
+///         " 2 | 2 \n",
+///         "---+---\n",
+///         " 1 | 2 \n",
+///     ));
+/// ```
+#[derive(Debug)]
+pub struct Vertical<O: Object>(pub O);
+
+impl<O: Object> TableOption for Vertical<O> {
+    fn change(&self, grid: &mut Grid) {
+        for (row, column) in self.0.cells(grid.count_rows(), grid.count_columns()) {
+            let content = grid.get_cell_content(row, column);
+            let content = vertical_text(content);
+            grid.set(Entity::Cell(row, column), Settings::new().text(content))
+        }
+    }
+}
+
+fn vertical_text(text: &str) -> String {
+    let lines = text
+        .lines()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let height = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+    (0..height)
+        .map(|i| {
+            lines
+                .iter()
+                .map(|line| line.get(i).copied().unwrap_or(' '))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,87 @@
+use papergrid::Grid;
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+
+/// GridWidget renders a [`Grid`](../papergrid/struct.Grid.html) into an area of a [`ratatui`] buffer.
+///
+/// Rows which don't fit into a height of the area are dropped from the end,
+/// the bottom border stays in place while at least one data row fits.
+/// Lines which are wider than the area are cut.
+/// Colors of a content are not carried over into the buffer.
+///
+/// # Example
+///
+/// ```rust
+///     use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+///     use tabled::{build_grid, GridWidget, Style, TableOption};
+///
+///     let data = vec!["Fedora", "Debian", "OpenSUSE"];
+///     let mut grid = build_grid(&data);
+///     Style::psql().change(&mut grid);
+///
+///     let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+///     GridWidget::new(&grid).render(buffer.area, &mut buffer);
+///
+///     assert_eq!(buffer, Buffer::with_lines(vec![
+///         "   &str ",
+///         "--------",
+///         "  Fedora",
+///     ]));
+/// ```
+pub struct GridWidget<'a> {
+    grid: &'a Grid,
+    style: Style,
+}
+
+impl<'a> GridWidget<'a> {
+    /// New creates a widget of a grid.
+    pub fn new(grid: &'a Grid) -> Self {
+        Self {
+            grid,
+            style: Style::default(),
+        }
+    }
+
+    /// Style sets a style of the area the grid is rendered into.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for GridWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+
+        let table = fit_height(self.grid, area.height as usize);
+        for (i, line) in table.lines().take(area.height as usize).enumerate() {
+            buf.set_stringn(
+                area.x,
+                area.y + i as u16,
+                line,
+                area.width as usize,
+                self.style,
+            );
+        }
+    }
+}
+
+// a number of rendered rows is looked up by a binary search as a height of a table only grows with them
+fn fit_height(grid: &Grid, height: usize) -> String {
+    let table = grid.to_string();
+    if table.lines().count() <= height {
+        return table;
+    }
+
+    let render = |count_rows: usize| grid.render_rows(&(0..count_rows).collect::<Vec<_>>());
+    let (mut fits, mut overflows) = (0, grid.count_rows());
+    while overflows - fits > 1 {
+        let middle = (fits + overflows) / 2;
+        if render(middle).lines().count() <= height {
+            fits = middle;
+        } else {
+            overflows = middle;
+        }
+    }
+
+    render(fits.max(1))
+}
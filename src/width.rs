@@ -0,0 +1,631 @@
+use std::{cell::RefCell, fmt};
+
+use papergrid::{string_width, Entity, Grid, Settings};
+#[cfg(feature = "unicode-width")]
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::TableOption;
+
+/// MaxWidth shrinks a table so it fits into a given width.
+///
+/// The widest columns are shrunk first, their content is either wrapped or truncated.
+/// It can be changed for particular columns by a [`Shrink`] policy and a priority,
+/// and for the whole table by a [`Peaker`].
+/// If the table doesn't fit even when all columns are narrowed down,
+/// the last columns can be hidden as well.
+///
+/// Everything which was altered is recorded in a [`LayoutReport`](./struct.LayoutReport.html),
+/// which can be got by [`MaxWidth::report`] once the option is applied.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, LayoutChange, MaxWidth, Style};
+///     let data = vec![("ok", "Connection established"), ("fail", "Timeout")];
+///
+///     let width = MaxWidth::truncating(20).suffix("..");
+///     let table = table!(&data, Style::psql(), &width);
+///
+///     assert_eq!(table, concat!(
+///         " &str |    &str     \n",
+///         "------+-------------\n",
+///         "  ok  | Connectio.. \n",
+///         " fail |   Timeout   \n",
+///     ));
+///     assert_eq!(
+///         width.report().changes(),
+///         &[LayoutChange::Truncated { row: 1, column: 1, width: 22 }],
+///     );
+/// ```
+pub struct MaxWidth {
+    width: usize,
+    shrink: Shrink,
+    suffix: String,
+    hyphenate: bool,
+    hide_columns: bool,
+    columns: Vec<(usize, Shrink)>,
+    priorities: Vec<(usize, usize)>,
+    peaker: Box<dyn Peaker>,
+    report: RefCell<LayoutReport>,
+}
+
+impl MaxWidth {
+    /// Wrapping creates an option which wraps a content of cells which don't fit.
+    pub fn wrapping(width: usize) -> Self {
+        Self {
+            width,
+            shrink: Shrink::Wrap,
+            suffix: String::new(),
+            hyphenate: false,
+            hide_columns: false,
+            columns: Vec::new(),
+            priorities: Vec::new(),
+            peaker: Box::new(PriorityMax),
+            report: RefCell::default(),
+        }
+    }
+
+    /// Truncating creates an option which truncates a content of cells which don't fit.
+    pub fn truncating(width: usize) -> Self {
+        Self {
+            shrink: Shrink::Truncate,
+            ..Self::wrapping(width)
+        }
+    }
+
+    /// Suffix sets a text which is appended to truncated lines, like `...`.
+    ///
+    /// It's used only by truncated columns.
+    pub fn suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Hyphenate makes wrapping break lines at spaces, hyphens and punctuation when it's possible,
+    /// a word which is still too long is broken with a hyphen.
+    ///
+    /// It's used only by wrapped columns.
+    ///
+    /// ```rust
+    ///     use tabled::{table, MaxWidth, Style};
+    ///     let data = vec!["A well-known extraordinarily long word"];
+    ///     let table = table!(&data, Style::psql(), MaxWidth::wrapping(10).hyphenate());
+    ///     assert_eq!(table, concat!(
+    ///         "   &str   \n",
+    ///         "----------\n",
+    ///         " A well-  \n",
+    ///         "  known   \n",
+    ///         " extraor- \n",
+    ///         " dinarily \n",
+    ///         "   long   \n",
+    ///         "   word   \n",
+    ///     ));
+    /// ```
+    pub fn hyphenate(mut self) -> Self {
+        self.hyphenate = true;
+        self
+    }
+
+    /// Column sets a policy of shrinking a given column instead of the default one.
+    ///
+    /// ```rust
+    ///     use tabled::{table, MaxWidth, Shrink, Style};
+    ///     let data = vec![("1f3a9c", "Connection established", "Timeout while reading")];
+    ///     let width = MaxWidth::wrapping(30)
+    ///         .column(0, Shrink::Never)
+    ///         .column(2, Shrink::Truncate)
+    ///         .suffix("..")
+    ///         .priority(2, 1);
+    ///     let table = table!(&data, Style::psql(), width);
+    ///     assert_eq!(table, concat!(
+    ///         "  &str  |     &str      | &.. \n",
+    ///         "--------+---------------+-----\n",
+    ///         " 1f3a9c | Connection es | T.. \n",
+    ///         "        |   tablished   |     \n",
+    ///     ));
+    /// ```
+    pub fn column(mut self, column: usize, shrink: Shrink) -> Self {
+        self.columns.push((column, shrink));
+        self
+    }
+
+    /// Priority sets an order in which columns are shrunk.
+    ///
+    /// Columns with a higher priority are shrunk first, the others are touched only when
+    /// these ones can't be narrowed any more. By default all columns have a priority 0.
+    pub fn priority(mut self, column: usize, priority: usize) -> Self {
+        self.priorities.push((column, priority));
+        self
+    }
+
+    /// Peaker sets a strategy which decides which column is narrowed next, [`PriorityMax`] by default.
+    ///
+    /// Priorities set by [`MaxWidth::priority`] are taken into account first,
+    /// the peaker chooses only between columns of the highest priority which can be narrowed.
+    ///
+    /// ```rust
+    ///     use tabled::{table, MaxWidth, PriorityRight, Style};
+    ///     let data = vec![("Connection", "established")];
+    ///     let table = table!(&data, Style::psql(), MaxWidth::truncating(18).peaker(PriorityRight));
+    ///     assert_eq!(table, concat!(
+    ///         "    &str    | &st \n",
+    ///         "------------+-----\n",
+    ///         " Connection | est \n",
+    ///     ));
+    /// ```
+    pub fn peaker<P: Peaker + 'static>(mut self, peaker: P) -> Self {
+        self.peaker = Box::new(peaker);
+        self
+    }
+
+    /// Hide columns allows to hide the last columns when a table can't be shrunk enough otherwise.
+    pub fn hide_columns(mut self) -> Self {
+        self.hide_columns = true;
+        self
+    }
+
+    /// Report returns changes made to a table the last time the option was applied.
+    pub fn report(&self) -> LayoutReport {
+        self.report.borrow().clone()
+    }
+
+    fn column_shrink(&self, column: usize) -> Shrink {
+        self.columns
+            .iter()
+            .rev()
+            .find(|(c, _)| *c == column)
+            .map_or(self.shrink, |(_, shrink)| *shrink)
+    }
+
+    fn column_priority(&self, column: usize) -> usize {
+        self.priorities
+            .iter()
+            .rev()
+            .find(|(c, _)| *c == column)
+            .map_or(0, |(_, priority)| *priority)
+    }
+
+    fn min_width(&self, column: usize, width: usize) -> usize {
+        match self.column_shrink(column) {
+            Shrink::Wrap => width.min(MIN_COLUMN_WIDTH),
+            Shrink::Truncate => width.min(MIN_COLUMN_WIDTH.max(string_width(&self.suffix) + 1)),
+            Shrink::Never => width,
+        }
+    }
+}
+
+impl fmt::Debug for MaxWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaxWidth")
+            .field("width", &self.width)
+            .field("shrink", &self.shrink)
+            .field("suffix", &self.suffix)
+            .field("hyphenate", &self.hyphenate)
+            .field("hide_columns", &self.hide_columns)
+            .field("columns", &self.columns)
+            .field("priorities", &self.priorities)
+            .field("report", &self.report)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Peaker decides which column [`MaxWidth`] narrows next, columns are narrowed by one at a time.
+///
+/// # Example
+///
+/// A peaker which keeps the first column as wide as possible.
+///
+/// ```rust
+///     use tabled::{table, MaxWidth, Peaker, Style};
+///
+///     struct KeepFirst;
+///
+///     impl Peaker for KeepFirst {
+///         fn peak(&self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
+///             (0..widths.len())
+///                 .rev()
+///                 .filter(|&i| widths[i] > min_widths[i])
+///                 .max_by_key(|&i| (i > 0, widths[i]))
+///         }
+///     }
+///
+///     let data = vec![("Connection", "established")];
+///     let table = table!(&data, Style::psql(), MaxWidth::wrapping(20).peaker(KeepFirst));
+///     assert_eq!(table, concat!(
+///         "    &str    | &str  \n",
+///         "------------+-------\n",
+///         " Connection | estab \n",
+///         "            | lishe \n",
+///         "            |   d   \n",
+///     ));
+/// ```
+pub trait Peaker {
+    /// Peak returns a column which is narrowed next or `None` to stop shrinking.
+    ///
+    /// `widths` are current widths of contents of columns,
+    /// a column can't be narrowed below its width in `min_widths`.
+    fn peak(&self, min_widths: &[usize], widths: &[usize]) -> Option<usize>;
+}
+
+/// PriorityMax narrows the widest column first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PriorityMax;
+
+impl Peaker for PriorityMax {
+    fn peak(&self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
+        (0..widths.len())
+            .filter(|&i| widths[i] > min_widths[i])
+            .max_by_key(|&i| (widths[i], std::cmp::Reverse(i)))
+    }
+}
+
+/// PriorityLeft narrows the leftmost column first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PriorityLeft;
+
+impl Peaker for PriorityLeft {
+    fn peak(&self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
+        (0..widths.len()).find(|&i| widths[i] > min_widths[i])
+    }
+}
+
+/// PriorityRight narrows the rightmost column first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PriorityRight;
+
+impl Peaker for PriorityRight {
+    fn peak(&self, min_widths: &[usize], widths: &[usize]) -> Option<usize> {
+        (0..widths.len()).rev().find(|&i| widths[i] > min_widths[i])
+    }
+}
+
+/// Shrink is a policy of narrowing a column by [`MaxWidth`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shrink {
+    /// A content which doesn't fit is wrapped.
+    Wrap,
+    /// A content which doesn't fit is truncated.
+    Truncate,
+    /// A column is never narrowed.
+    Never,
+}
+
+/// LayoutReport describes what was sacrificed to fit a table into given constraints.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutReport {
+    changes: Vec<LayoutChange>,
+}
+
+impl LayoutReport {
+    /// Is empty returns true if a table was left untouched.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Changes returns a list of changes in the order they were made.
+    pub fn changes(&self) -> &[LayoutChange] {
+        &self.changes
+    }
+}
+
+/// LayoutChange is a single change made to a table.
+///
+/// Rows and columns are indexes in a table before any column was hidden.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutChange {
+    /// A content of a cell was truncated, the `width` is its original width
+    Truncated {
+        row: usize,
+        column: usize,
+        width: usize,
+    },
+    /// A content of a cell was wrapped, the `width` is its original width
+    Wrapped {
+        row: usize,
+        column: usize,
+        width: usize,
+    },
+    /// A column was hidden
+    ColumnHidden { column: usize, header: String },
+}
+
+// a column is never shrunk below this width unless its content is narrower
+const MIN_COLUMN_WIDTH: usize = 3;
+
+impl TableOption for MaxWidth {
+    fn change(&self, grid: &mut Grid) {
+        let mut report = LayoutReport::default();
+
+        let mut widths = (0..grid.count_columns())
+            .map(|column| column_width(grid, column))
+            .collect::<Vec<_>>();
+        let min_widths = widths
+            .iter()
+            .enumerate()
+            .map(|(column, &width)| self.min_width(column, width))
+            .collect::<Vec<_>>();
+        let priorities = (0..widths.len())
+            .map(|column| self.column_priority(column))
+            .collect::<Vec<_>>();
+
+        // everything except a content, like borders and padding, stays the same
+        let overhead = |grid: &Grid, widths: &[usize]| {
+            grid.table_width()
+                .saturating_sub(widths.iter().sum::<usize>())
+        };
+
+        if self.hide_columns {
+            while grid.count_columns() > 1
+                && min_widths[..grid.count_columns()].iter().sum::<usize>()
+                    + overhead(grid, &widths)
+                    > self.width
+            {
+                let column = grid.count_columns() - 1;
+                let header = grid.get_cell(0, column).unwrap_or_default().to_owned();
+                let _ = grid.remove_column(column);
+                widths.pop();
+                report
+                    .changes
+                    .push(LayoutChange::ColumnHidden { column, header });
+            }
+        }
+
+        let available = self.width.saturating_sub(overhead(grid, &widths));
+        let limits = shrink(
+            &widths,
+            &min_widths[..widths.len()],
+            &priorities[..widths.len()],
+            available,
+            self.peaker.as_ref(),
+        );
+
+        for (column, &limit) in limits.iter().enumerate() {
+            if limit >= widths[column] {
+                continue;
+            }
+
+            for row in 0..grid.count_rows() {
+                let text = grid.get_cell_content(row, column).to_owned();
+                let width = string_width(&text);
+                if width <= limit {
+                    continue;
+                }
+
+                let (text, change) = match self.column_shrink(column) {
+                    Shrink::Truncate => (
+                        truncate(&text, limit, &self.suffix),
+                        LayoutChange::Truncated { row, column, width },
+                    ),
+                    _ if self.hyphenate => (
+                        wrap_hyphenated(&text, limit),
+                        LayoutChange::Wrapped { row, column, width },
+                    ),
+                    _ => (
+                        wrap(&text, limit),
+                        LayoutChange::Wrapped { row, column, width },
+                    ),
+                };
+
+                grid.set(Entity::Cell(row, column), Settings::new().text(text));
+                report.changes.push(change);
+            }
+        }
+
+        *self.report.borrow_mut() = report;
+    }
+}
+
+/// Justify sets the same width for all columns.
+///
+/// The width is either a given one or the width of the widest column.
+/// It includes a padding of columns and a content which doesn't fit is wrapped.
+///
+/// # Example
+///
+/// ```rust
+///     use tabled::{table, Justify, Style};
+///     let data = vec![(1, 10, 100)];
+///     let table = table!(&data, Style::psql(), Justify::max());
+///
+///     assert_eq!(table, concat!(
+///         " i32 | i32 | i32 \n",
+///         "-----+-----+-----\n",
+///         "  1  | 10  | 100 \n",
+///     ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Justify {
+    width: Option<usize>,
+}
+
+impl Justify {
+    /// New creates an option which sets a given width for all columns.
+    pub fn new(width: usize) -> Self {
+        Self { width: Some(width) }
+    }
+
+    /// Max creates an option which sets the width of the widest column for all columns.
+    pub fn max() -> Self {
+        Self { width: None }
+    }
+}
+
+impl TableOption for Justify {
+    fn change(&self, grid: &mut Grid) {
+        let width = match self.width {
+            Some(width) => width,
+            None => {
+                let (widths, _) = grid.estimate_dimensions();
+                widths.into_iter().max().unwrap_or(0)
+            }
+        };
+
+        for column in 0..grid.count_columns() {
+            // a column which can't be that narrow is left as it is
+            let _ = grid.set_column_width(column, width);
+        }
+    }
+}
+
+fn column_width(grid: &Grid, column: usize) -> usize {
+    (0..grid.count_rows())
+        .filter_map(|row| grid.get_cell(row, column))
+        .map(string_width)
+        .max()
+        .unwrap_or(0)
+}
+
+// It narrows columns with the highest priority one by one, as a peaker decides,
+// until their sum fits into the available width.
+fn shrink(
+    widths: &[usize],
+    min_widths: &[usize],
+    priorities: &[usize],
+    available: usize,
+    peaker: &dyn Peaker,
+) -> Vec<usize> {
+    let mut limits = widths.to_vec();
+    while limits.iter().sum::<usize>() > available {
+        let priority = (0..limits.len())
+            .filter(|&i| limits[i] > min_widths[i])
+            .map(|i| priorities[i])
+            .max();
+        let priority = match priority {
+            Some(priority) => priority,
+            None => break,
+        };
+
+        // columns of a lower priority are presented to a peaker as ones which can't be narrowed
+        let mins = (0..limits.len())
+            .map(|i| {
+                if priorities[i] == priority {
+                    min_widths[i]
+                } else {
+                    limits[i]
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match peaker.peak(&mins, &limits) {
+            Some(i) if i < limits.len() && limits[i] > mins[i] => limits[i] -= 1,
+            _ => break,
+        }
+    }
+
+    limits
+}
+
+// A double-width character which doesn't fit is replaced by a space.
+fn truncate(text: &str, width: usize, suffix: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if string_width(line) <= width {
+                return line.to_owned();
+            }
+
+            let width = width.saturating_sub(string_width(suffix));
+            let head = &line[..fit_width(line, width)];
+            let gap = width - string_width(head);
+            format!("{}{}{}", head.trim_end(), " ".repeat(gap), suffix)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn wrap(text: &str, width: usize) -> String {
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let mut line = line;
+        while string_width(line) > width {
+            let (head, tail) = split_at_width(line, width);
+            lines.push(head);
+            line = tail;
+        }
+
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+// A line is broken after the last space, hyphen or punctuation which fits,
+// otherwise a word is broken with a hyphen.
+fn wrap_hyphenated(text: &str, width: usize) -> String {
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let mut line = line;
+        while string_width(line) > width {
+            let end = fit_width(line, width);
+            let (head, tail) = match break_position(line, end) {
+                Some(i) => (line[..i].trim_end().to_owned(), line[i..].trim_start()),
+                None if width > 1 && fit_width(line, width - 1) > 0 => {
+                    let i = fit_width(line, width - 1);
+                    (format!("{}-", &line[..i]), &line[i..])
+                }
+                None => {
+                    let (head, tail) = split_at_width(line, width);
+                    (head.to_owned(), tail)
+                }
+            };
+
+            lines.push(head);
+            line = tail;
+        }
+
+        lines.push(line.to_owned());
+    }
+
+    lines.join("\n")
+}
+
+// It returns a byte index a line can be broken at so the first part is not longer than the end,
+// a break is possible at spaces and after hyphens and punctuation.
+fn break_position(line: &str, end: usize) -> Option<usize> {
+    if !line[..end].trim().is_empty() && line[end..].starts_with(char::is_whitespace) {
+        return Some(end);
+    }
+
+    line[..end].char_indices().rev().find_map(|(i, c)| match c {
+        _ if c.is_whitespace() && !line[..i].trim().is_empty() => Some(i),
+        '-' | '/' | ',' | '.' | ';' | ':' | '!' | '?' if i > 0 => Some(i + c.len_utf8()),
+        _ => None,
+    })
+}
+
+// It splits a line so the first part is not wider than the width, but it always takes at least one grapheme.
+fn split_at_width(line: &str, width: usize) -> (&str, &str) {
+    let mut i = fit_width(line, width);
+    if i == 0 {
+        i = graphemes(line)
+            .next()
+            .map_or(0, |(_, grapheme)| grapheme.len());
+    }
+
+    line.split_at(i)
+}
+
+// It returns a length in bytes of the longest prefix of whole graphemes which fits into the width.
+fn fit_width(line: &str, width: usize) -> usize {
+    let mut current = 0;
+    for (i, grapheme) in graphemes(line) {
+        current += string_width(grapheme);
+        if current > width {
+            return i;
+        }
+    }
+
+    line.len()
+}
+
+// It returns graphemes of a line together with their byte indexes.
+#[cfg(feature = "unicode-width")]
+fn graphemes(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.grapheme_indices(true)
+}
+
+// Without a `unicode-width` feature each character is considered to be a grapheme.
+#[cfg(not(feature = "unicode-width"))]
+fn graphemes(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.char_indices()
+        .map(move |(i, c)| (i, &line[i..i + c.len_utf8()]))
+}
@@ -0,0 +1,99 @@
+//! This module contains an export of a grid into an Excel `.xlsx` workbook.
+//!
+//! A content of each cell is put into a cell of a worksheet, numbers are written as numbers
+//! so they can be summed up or charted. They're recognized the same way as by [`crate::sql`],
+//! so a number with leading zeros like a zip code stays a string. A header is bold, and horizontal alignment of cells is kept.
+//! Borders, spans and colors are not exported.
+//!
+//! It's available only with a `xlsx` feature.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//!     use tabled::{build_grid, xlsx};
+//!
+//!     let data = vec![("Fedora", 34), ("OpenSUSE", 15)];
+//!     let grid = build_grid(&data);
+//!
+//!     xlsx::save(&grid, "distributions.xlsx").unwrap();
+//! ```
+
+use std::{convert::TryFrom, path::Path};
+
+use papergrid::{string_width, Alignment, Grid};
+
+use crate::sql::is_number;
+use rust_xlsxwriter::{ColNum, Format, FormatAlign, RowNum, Workbook, Worksheet, XlsxError};
+
+/// Save writes a grid into a workbook file with a single worksheet.
+pub fn save<P: AsRef<Path>>(grid: &Grid, path: P) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    write_worksheet(grid, workbook.add_worksheet())?;
+    workbook.save(path)
+}
+
+/// To_buffer returns a workbook with a single worksheet as bytes,
+/// e.g. to be sent as a response of a web server.
+pub fn to_buffer(grid: &Grid) -> Result<Vec<u8>, XlsxError> {
+    let mut workbook = Workbook::new();
+    write_worksheet(grid, workbook.add_worksheet())?;
+    workbook.save_to_buffer()
+}
+
+/// Write_worksheet writes a grid into a worksheet starting from its first cell,
+/// so several tables can be put into different sheets of one workbook.
+///
+/// Widths of columns are set by the widest lines of their cells.
+pub fn write_worksheet(grid: &Grid, worksheet: &mut Worksheet) -> Result<(), XlsxError> {
+    for column in 0..grid.count_columns() {
+        let col = ColNum::try_from(column).map_err(|_| XlsxError::RowColumnLimitError)?;
+        let mut width = 0;
+
+        for row in 0..grid.count_rows() {
+            let text = grid.get_content(row, column).unwrap_or_default();
+            let alignment = grid.get_alignment(row, column).unwrap_or(Alignment::Left);
+            let is_header = row == 0 && grid.has_header();
+
+            let mut format = Format::new().set_align(format_align(&alignment));
+            if is_header {
+                format = format.set_bold();
+            }
+            if text.contains('\n') {
+                format = format.set_text_wrap();
+            }
+
+            let row = RowNum::try_from(row).map_err(|_| XlsxError::RowColumnLimitError)?;
+            match parse_number(&text) {
+                Some(number) if !is_header => {
+                    worksheet.write_number_with_format(row, col, number, &format)?;
+                }
+                _ => {
+                    worksheet.write_string_with_format(row, col, text.as_ref(), &format)?;
+                }
+            }
+
+            width = text.lines().map(string_width).fold(width, usize::max);
+        }
+
+        // a bit of room is left as fonts of a spreadsheet are not monospaced
+        worksheet.set_column_width(col, width as f64 + 2.0)?;
+    }
+
+    Ok(())
+}
+
+fn parse_number(text: &str) -> Option<f64> {
+    if is_number(text) {
+        text.parse::<f64>().ok()
+    } else {
+        None
+    }
+}
+
+fn format_align(alignment: &Alignment) -> FormatAlign {
+    match alignment {
+        Alignment::Left => FormatAlign::Left,
+        Alignment::Center => FormatAlign::Center,
+        Alignment::Right => FormatAlign::Right,
+    }
+}
@@ -14,12 +14,9 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::*;
-use syn::{
-    parse_macro_input, Attribute, DeriveInput, Field, Lit,
-    Meta, NestedMeta,
-};
+use syn::{parse_macro_input, Attribute, DeriveInput, Lit, Meta, NestedMeta};
 
-#[proc_macro_derive(Tabled, attributes(header))]
+#[proc_macro_derive(Tabled, attributes(header, tabled))]
 pub fn tabled(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -28,19 +25,19 @@ pub fn tabled(input: TokenStream) -> TokenStream {
 
 fn impl_tabled(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
-    let headers = get_headers(&ast.data);
+    let headers = get_headers(&ast.data, &ast.attrs);
     let fields = get_fields(&ast.data);
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
     let expanded = quote! {
-        impl #impl_generics Tabled for #name #ty_generics #where_clause {
+        impl #impl_generics ::tabled::Tabled for #name #ty_generics #where_clause {
             fn fields(&self) -> Vec<String> {
                 #fields
             }
 
             fn headers() -> Vec<String> {
-                vec![#(String::from(#headers),)*]
+                #headers
             }
         }
     };
@@ -48,169 +45,458 @@ fn impl_tabled(ast: &syn::DeriveInput) -> TokenStream {
     TokenStream::from(expanded)
 }
 
-fn get_headers(d: &syn::Data) -> Vec<String> {
-    match d {
-        syn::Data::Struct(st) => get_st_headers(st),
-        syn::Data::Enum(e) => get_enum_headers(e),
+fn get_headers(d: &syn::Data, attrs: &[Attribute]) -> proc_macro2::TokenStream {
+    let rename_all = find_name_attribute(attrs, "tabled", "rename_all").map(|rule| {
+        RenameRule::parse(&rule)
+            .unwrap_or_else(|| panic!("Unsupported value {:?} of a rename_all attribute", rule))
+    });
+
+    let headers = match d {
+        syn::Data::Struct(st) => get_fields_headers(&st.fields, rename_all),
+        syn::Data::Enum(e) => get_enum_headers(e, rename_all),
         syn::Data::Union(_) => todo!("it's not clear how to handle union type"),
-    }
-}
+    };
 
-fn get_st_headers(st: &syn::DataStruct) -> Vec<String> {
-    get_fields_headers(st.fields.iter())
+    quote! {
+        let headers: Vec<Vec<String>> = vec![#(#headers,)*];
+        headers.concat()
+    }
 }
 
-fn get_fields_headers<'a>(fields: impl Iterator<Item = &'a Field>) -> Vec<String> {
-    fields
-        .enumerate()
-        .filter(|(_, f)| {
-            let is_ignored = find_bool_attribute(&f.attrs, "header", "hidden");
-            is_ignored != Some(true)
-        })
+// each header is an expression which returns a list of headers
+fn get_fields_headers(
+    fields: &syn::Fields,
+    rename_all: Option<RenameRule>,
+) -> Vec<proc_macro2::TokenStream> {
+    ordered_fields(fields)
+        .into_iter()
         .map(|(i, f)| {
-            let override_name = find_name_attribute(&f.attrs, "header", "name");
-            match override_name {
+            if let Some(prefix) = find_inline(&f.attrs) {
+                let ty = &f.ty;
+                return quote! {
+                    <#ty as ::tabled::Tabled>::headers()
+                        .into_iter()
+                        .map(|header| format!("{}{}", #prefix, header))
+                        .collect::<Vec<_>>()
+                };
+            }
+
+            let name = match find_override_name(&f.attrs) {
                 Some(name) => name,
                 None => f
                     .ident
                     .as_ref()
-                    .map_or_else(|| format!("{}", i), |f| f.to_string()),
-            }
+                    .map_or_else(|| format!("{}", i), |f| rename(&f.to_string(), rename_all)),
+            };
+
+            quote! { vec![String::from(#name)] }
         })
         .collect()
 }
 
-fn get_enum_headers(e: &syn::DataEnum) -> Vec<String> {
+// each variant takes either a single column or columns of its fields when it's inlined
+fn get_enum_headers(
+    e: &syn::DataEnum,
+    rename_all: Option<RenameRule>,
+) -> Vec<proc_macro2::TokenStream> {
     e.variants
         .iter()
-        .filter(|v| {
-            let is_ignored = find_bool_attribute(&v.attrs, "header", "hidden");
-            is_ignored != Some(true)
-        })
+        .filter(|v| !is_ignored(&v.attrs))
         .map(|v| {
-            let override_name = find_name_attribute(&v.attrs, "header", "name");
-            match override_name {
-                Some(name) => vec![name],
-                None => {
-                    let variant = v.ident.to_string();
-                    vec![format!("{}", variant)]
-                }
+            if let Some(prefix) = find_inline(&v.attrs) {
+                let headers = get_fields_headers(&v.fields, None);
+                return quote! {
+                    {
+                        let headers: Vec<Vec<String>> = vec![#(#headers,)*];
+                        headers
+                            .concat()
+                            .into_iter()
+                            .map(|header| format!("{}{}", #prefix, header))
+                            .collect::<Vec<_>>()
+                    }
+                };
             }
+
+            let name = match find_override_name(&v.attrs) {
+                Some(name) => name,
+                None => rename(&v.ident.to_string(), rename_all),
+            };
+
+            quote! { vec![String::from(#name)] }
         })
-        .collect::<Vec<Vec<_>>>()
-        .concat()
+        .collect()
 }
 
 fn get_fields(d: &syn::Data) -> proc_macro2::TokenStream {
     match d {
         syn::Data::Struct(st) => {
-            let fields = get_st_fields(st);
-            quote! { vec![#(format!("{}", #fields),)*] }
+            let fields = get_fields_values(&st.fields, |i, f| {
+                let field = field_name(i, f);
+                quote!(&self.#field)
+            });
+
+            quote! {
+                let fields: Vec<Vec<String>> = vec![#(#fields,)*];
+                fields.concat()
+            }
         }
         syn::Data::Enum(e) => get_enum_fields(e),
         syn::Data::Union(_) => todo!(),
     }
 }
 
-fn get_st_fields(st: &syn::DataStruct) -> Vec<proc_macro2::TokenStream> {
-    st.fields
-        .iter()
-        .filter(|f| {
-            let is_ignored = find_bool_attribute(&f.attrs, "header", "hidden");
-            is_ignored != Some(true)
-        })
-        .map(|f| f.ident.as_ref())
-        .enumerate()
+// each value is an expression which returns a list of fields,
+// a reference to a field is built by the `access` function
+fn get_fields_values<F>(fields: &syn::Fields, access: F) -> Vec<proc_macro2::TokenStream>
+where
+    F: Fn(usize, &syn::Field) -> proc_macro2::TokenStream,
+{
+    ordered_fields(fields)
+        .into_iter()
         .map(|(i, f)| {
-            f.map_or_else(
-                || {
-                    let mut s = quote!(self.);
-                    s.extend(syn::Index::from(i).to_token_stream());
-                    s
-                },
-                |f| quote!(self.#f),
-            )
+            let field = access(i, f);
+
+            if find_inline(&f.attrs).is_some() {
+                return quote! { ::tabled::Tabled::fields(#field) };
+            }
+
+            match find_display_with(&f.attrs) {
+                Some(DisplayWith {
+                    function,
+                    with_self: true,
+                }) => {
+                    quote! { vec![format!("{}", #function(self))] }
+                }
+                Some(DisplayWith {
+                    function,
+                    with_self: false,
+                }) => {
+                    quote! { vec![format!("{}", #function(#field))] }
+                }
+                None => quote! { vec![format!("{}", #field)] },
+            }
         })
         .collect()
 }
 
-fn get_enum_fields(e: &syn::DataEnum) -> proc_macro2::TokenStream {
-    let mut fields_per_variant = Vec::new();
-    let mut variant_field_shift = Vec::new();
-    let mut variant_fields_len = Vec::new();
-    let mut count_fields = 0;
-    let variants = e.variants.iter().filter(|v| {
-        let is_ignored = find_bool_attribute(&v.attrs, "header", "hidden");
-        is_ignored != Some(true)
-    });
-    for _ in variants {
-        let fields = vec![quote! { "+".to_string() }];
+// It returns visible fields in an order of columns together with their original indexes.
+//
+// An index must be taken before filtering so tuple fields are referenced correctly.
+// Fields with an `order` attribute are put on their positions and the rest fill gaps in declaration order.
+fn ordered_fields(fields: &syn::Fields) -> Vec<(usize, &syn::Field)> {
+    let fields = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| !is_ignored(&f.attrs))
+        .collect::<Vec<_>>();
 
-        variant_field_shift.push(count_fields);
-        variant_fields_len.push(fields.len());
-        count_fields += fields.len();
-        fields_per_variant.push(fields);
+    let mut ordered = vec![None; fields.len()];
+    let mut rest = Vec::new();
+    for (i, f) in fields {
+        match find_order(&f.attrs) {
+            Some(order) if order >= ordered.len() => panic!(
+                "Parameter order for macro tabled is out of range, {} >= {} columns",
+                order,
+                ordered.len()
+            ),
+            Some(order) if ordered[order].is_some() => {
+                panic!(
+                    "Parameter order {} for macro tabled is used more than once",
+                    order
+                )
+            }
+            Some(order) => ordered[order] = Some((i, f)),
+            None => rest.push((i, f)),
+        }
     }
 
-    let variants = e
-        .variants
+    let mut rest = rest.into_iter();
+    ordered
+        .into_iter()
+        .map(|field| field.or_else(|| rest.next()))
+        .collect::<Option<Vec<_>>>()
+        .expect("all positions must be filled")
+}
+
+fn find_order(attributes: &[Attribute]) -> Option<usize> {
+    tabled_attributes(attributes).find_map(|nested| match nested {
+        NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("order") => {
+            match value.lit {
+                Lit::Int(order) => Some(order.base10_parse().unwrap_or_else(|_| {
+                    panic!("Parameter order for macro tabled should be a non negative integer")
+                })),
+                _ => panic!("Parameter order for macro tabled should be an integer"),
+            }
+        }
+        _ => None,
+    })
+}
+
+fn field_name(i: usize, f: &syn::Field) -> proc_macro2::TokenStream {
+    match &f.ident {
+        Some(ident) => ident.to_token_stream(),
+        None => syn::Index::from(i).to_token_stream(),
+    }
+}
+
+// The attribute can be set either as `inline` or as `inline("prefix")`, it returns the prefix.
+fn find_inline(attributes: &[Attribute]) -> Option<String> {
+    tabled_attributes(attributes).find_map(|nested| match nested {
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("inline") => Some(String::new()),
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("inline") => {
+            match list.nested.first() {
+                Some(NestedMeta::Lit(Lit::Str(prefix))) => Some(prefix.value()),
+                _ => panic!("Parameter inline for macro tabled expects a prefix string"),
+            }
+        }
+        _ => None,
+    })
+}
+
+fn tabled_attributes(attributes: &[Attribute]) -> impl Iterator<Item = NestedMeta> + '_ {
+    attributes
         .iter()
-        .filter(|v| {
-            let is_ignored = find_bool_attribute(&v.attrs, "header", "hidden");
-            is_ignored != Some(true)
+        .filter(|attr| attr.path.is_ident("tabled"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
         })
-        .map(|v| {
-            let mut token = proc_macro2::TokenStream::new();
-            token.append_all(v.ident.to_token_stream());
-
-            match &v.fields {
-                syn::Fields::Named(fields) => {
-                    let parameters = fields
-                        .named
-                        .iter()
-                        .map(|f| f.ident.as_ref())
-                        .flatten()
-                        .map(|f| {
-                            quote! { #f,}
-                        })
-                        .collect::<Vec<_>>();
-
-                    syn::token::Brace::default().surround(&mut token, |s| {
-                        s.append_all(parameters);
-                    });
-                }
-                syn::Fields::Unnamed(_) => {
-                    // TODO: "a tuple based struct doesn't implemented; here supposed to be a generated Ident for a tuple"
-                    syn::token::Paren::default().surround(&mut token, |s| {
-                        s.append_all(quote! {_});
-                    });
+        .flatten()
+}
+
+/// DisplayWith is a function set by a `display_with` attribute.
+struct DisplayWith {
+    function: syn::ExprPath,
+    // the function takes a whole structure instead of a field
+    with_self: bool,
+}
+
+// The attribute can be set either as `display_with = "path"` or as `display_with("path", self)`.
+fn find_display_with(attributes: &[Attribute]) -> Option<DisplayWith> {
+    let (path, with_self) = tabled_attributes(attributes).find_map(|nested| match nested {
+        NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("display_with") => {
+            match value.lit {
+                Lit::Str(path) => Some((path, false)),
+                _ => panic!("Parameter display_with for macro tabled should be String"),
+            }
+        }
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("display_with") => {
+            let mut path = None;
+            let mut with_self = false;
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Lit(Lit::Str(value)) => path = Some(value),
+                    NestedMeta::Meta(Meta::Path(arg)) if arg.is_ident("self") => with_self = true,
+                    _ => panic!("Unexpected argument of display_with for macro tabled"),
                 }
-                syn::Fields::Unit => {}
-            };
+            }
 
-            token
-        })
+            let path = path.expect("A function must be set in display_with for macro tabled");
+            Some((path, with_self))
+        }
+        _ => None,
+    })?;
+
+    let function = path.parse().unwrap_or_else(|_| {
+        panic!(
+            "Expected a path to a function in display_with, got {:?}",
+            path.value()
+        )
+    });
+
+    Some(DisplayWith {
+        function,
+        with_self,
+    })
+}
+
+fn get_enum_fields(e: &syn::DataEnum) -> proc_macro2::TokenStream {
+    let variants = e
+        .variants
+        .iter()
+        .filter(|v| !is_ignored(&v.attrs))
         .collect::<Vec<_>>();
 
+    // a number of columns of an inlined variant may depend on types of its fields,
+    // so shifts of variants are calculated in runtime
+    let headers = get_enum_headers(e, None);
+    let shifts = 0..variants.len();
+
+    let arms = variants.iter().map(|v| {
+        let ident = &v.ident;
+        let is_inline = find_inline(&v.attrs).is_some();
+        if !is_inline {
+            let pattern = match &v.fields {
+                syn::Fields::Named(_) => quote! { Self::#ident { .. } },
+                syn::Fields::Unnamed(_) => quote! { Self::#ident(..) },
+                syn::Fields::Unit => quote! { Self::#ident },
+            };
+
+            return (pattern, quote! { vec!["+".to_string()] });
+        }
+
+        let bindings = v
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| variant_binding(i, f))
+            .collect::<Vec<_>>();
+        let pattern = match &v.fields {
+            syn::Fields::Named(_) => quote! { Self::#ident { #(#bindings,)* } },
+            syn::Fields::Unnamed(_) => quote! { Self::#ident(#(#bindings,)*) },
+            syn::Fields::Unit => quote! { Self::#ident },
+        };
+
+        let values = get_fields_values(&v.fields, |i, f| {
+            let binding = variant_binding(i, f);
+            quote!(#binding)
+        });
+        let values = quote! {
+            {
+                let fields: Vec<Vec<String>> = vec![#(#values,)*];
+                fields.concat()
+            }
+        };
+
+        (pattern, values)
+    });
+    let (patterns, values): (Vec<_>, Vec<_>) = arms.unzip();
+
     quote! {
-        let size = #count_fields;
-        let mut v: Vec<String> = std::iter::repeat(String::new()).take(size).collect();
+        let sizes: Vec<usize> = vec![#((#headers).len(),)*];
+        let mut v: Vec<String> = vec![String::new(); sizes.iter().sum()];
         #[allow(unused_variables)]
-        match &self {
-            #(Self::#variants => {
-                let fields = vec![#(#fields_per_variant.to_string()),*];
-
-                for i in #variant_field_shift..#variant_field_shift+#variant_fields_len {
-                    v[i] = fields[i-#variant_field_shift].clone();
+        match self {
+            #(#patterns => {
+                let shift: usize = sizes[..#shifts].iter().sum();
+                let fields: Vec<String> = #values;
+                for (i, field) in fields.into_iter().enumerate() {
+                    v[shift + i] = field;
                 }
 
                 v
             },)*
+            #[allow(unreachable_patterns)]
             _ => vec![],
         }
     }
 }
 
+// fields of a tuple variant are bound to generated names
+fn variant_binding(i: usize, f: &syn::Field) -> syn::Ident {
+    match &f.ident {
+        Some(ident) => ident.clone(),
+        None => quote::format_ident!("__field{}", i),
+    }
+}
+
+fn is_ignored(attributes: &[Attribute]) -> bool {
+    find_bool_attribute(attributes, "tabled", "skip") == Some(true)
+        || find_bool_attribute(attributes, "header", "hidden") == Some(true)
+}
+
+fn find_override_name(attributes: &[Attribute]) -> Option<String> {
+    find_name_attribute(attributes, "tabled", "rename")
+        .or_else(|| find_name_attribute(attributes, "header", "name"))
+}
+
+/// RenameRule is a case convention of a `rename_all` attribute.
+#[derive(Debug, Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(Self::Lower),
+            "UPPERCASE" => Some(Self::Upper),
+            "PascalCase" => Some(Self::Pascal),
+            "camelCase" => Some(Self::Camel),
+            "snake_case" => Some(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            // like in serde, only a case is changed so underscores are kept
+            Self::Lower => name.to_lowercase(),
+            Self::Upper => name.to_uppercase(),
+            Self::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            Self::Snake => words.join("_").to_lowercase(),
+            Self::ScreamingSnake => words.join("_").to_uppercase(),
+            Self::Kebab => words.join("-").to_lowercase(),
+            Self::ScreamingKebab => words.join("-").to_uppercase(),
+        }
+    }
+}
+
+fn rename(name: &str, rule: Option<RenameRule>) -> String {
+    match rule {
+        Some(rule) => rule.apply(name),
+        None => name.to_owned(),
+    }
+}
+
+// it splits both snake_case field names and PascalCase variant names
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut prev_is_lowercase = false;
+    for c in name.chars() {
+        let is_boundary = c == '_' || c == '-' || (c.is_uppercase() && prev_is_lowercase);
+        if is_boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        if c != '_' && c != '-' {
+            word.push(c);
+        }
+
+        prev_is_lowercase = c.is_lowercase() || c.is_numeric();
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(c) => c
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
 fn parse_name_attribute(attr: &Attribute, method: &str, name: &str) -> Option<String> {
     if attr.path.is_ident(method) {
         let meta = &attr.parse_meta();
@@ -222,25 +508,22 @@ fn parse_name_attribute(attr: &Attribute, method: &str, name: &str) -> Option<St
                             NestedMeta::Lit(Lit::Str(value)) => return Some(value.value()),
                             NestedMeta::Lit(Lit::ByteStr(value)) => return Some(
                                 std::str::from_utf8(&value.value())
-                                    .expect(&format!("Expected a valid UTF-8 string for a macro {macro} field {name}", macro=method, name=name))
+                                    .unwrap_or_else(|_| panic!("Expected a valid UTF-8 string for a macro {macro} field {name}", macro=method, name=name))
                                     .to_owned(),
                             ),
-                            NestedMeta::Meta(Meta::NameValue(value)) => {
-                                if value.path.is_ident(name) {
-                                    match &value.lit {
-                                        Lit::Str(value) => return Some(value.value()),
-                                        Lit::ByteStr(value) => return Some(
-                                            std::str::from_utf8(&value.value())
-                                                .expect(&format!("Expected a valid UTF-8 string for a macro {macro} field {name}", macro=method, name=name))
-                                                .to_owned(),
-                                        ),
-                                        _ => panic!("Parameter {name} for macro {macro} should be String", name=name, macro=method)
-
-                                    }
+                            NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident(name) => {
+                                match &value.lit {
+                                    Lit::Str(value) => return Some(value.value()),
+                                    Lit::ByteStr(value) => return Some(
+                                        std::str::from_utf8(&value.value())
+                                            .unwrap_or_else(|_| panic!("Expected a valid UTF-8 string for a macro {macro} field {name}", macro=method, name=name))
+                                            .to_owned(),
+                                    ),
+                                    _ => panic!("Parameter {name} for macro {macro} should be String", name=name, macro=method)
                                 }
                             }
                             _ => {
-                                
+
                             }
                         }
                     }
@@ -268,19 +551,19 @@ fn parse_bool_attribute(attr: &Attribute, method: &str, name: &str) -> Option<bo
             Ok(Meta::List(meta_list)) => {
                 for nested_meta in &meta_list.nested {
                     match nested_meta {
-                        NestedMeta::Meta(Meta::Path(path)) => if path.is_ident(name) { return Some(true) } else { return None },
-                        NestedMeta::Meta(Meta::NameValue(value)) => {
-                            if value.path.is_ident(name) {
-                                match &value.lit {
-                                    Lit::Bool(value) => return Some(value.value()),
-                                    Lit::Verbatim(literal) => panic!("{:?}", literal),
-                                    _ => panic!("Parameter {name} for macro {macro} should be a bool value", name=name, macro=method)
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident(name) => {
+                            return Some(true)
+                        }
+                        NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident(name) => {
+                            match &value.lit {
+                                Lit::Bool(value) => return Some(value.value()),
+                                Lit::Verbatim(literal) => panic!("{:?}", literal),
+                                _ => {
+                                    panic!("Parameter {name} for macro {macro} should be a bool value", name=name, macro=method)
                                 }
                             }
                         }
-                        _ => {
-                            
-                        }
+                        _ => {}
                     }
                 }
 
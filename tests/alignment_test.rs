@@ -11,7 +11,10 @@
 // copies or substantial portions of the Software.
 
 use papergrid::Alignment;
-use tabled::{table, Full, Head, HorizontalAlignment, Row, Style, Tabled};
+use tabled::{
+    build_grid_without_header, table, AlignNumbers, AlignOnChar, AlignOnDecimal, AlignmentVertical,
+    Column, Full, Head, HorizontalAlignment, Row, Style, TableOption, Tabled, VerticalAlignment,
+};
 
 #[derive(Tabled)]
 struct Linux {
@@ -41,11 +44,11 @@ fn full_alignment() {
     ];
 
     let expected = concat!(
-        "id|destribution|link                     \n",
-        "--+------------+-------------------------\n",
-        "0 |Fedora      |https://getfedora.org/   \n",
-        "2 |OpenSUSE    |https://www.opensuse.org/\n",
-        "3 |Endeavouros |https://endeavouros.com/ \n",
+        " id | destribution | link                      \n",
+        "----+--------------+---------------------------\n",
+        " 0  | Fedora       | https://getfedora.org/    \n",
+        " 2  | OpenSUSE     | https://www.opensuse.org/ \n",
+        " 3  | Endeavouros  | https://endeavouros.com/  \n",
     );
 
     let table = table!(
@@ -78,15 +81,15 @@ fn head_and_data_alignment() {
     ];
 
     let expected = concat!(
-        "+--+------------+-------------------------+\n",
-        "|id|destribution|link                     |\n",
-        "+--+------------+-------------------------+\n",
-        "| 0|      Fedora|   https://getfedora.org/|\n",
-        "+--+------------+-------------------------+\n",
-        "| 2|    OpenSUSE|https://www.opensuse.org/|\n",
-        "+--+------------+-------------------------+\n",
-        "| 3| Endeavouros| https://endeavouros.com/|\n",
-        "+--+------------+-------------------------+\n",
+        "+----+--------------+---------------------------+\n",
+        "| id | destribution | link                      |\n",
+        "+----+--------------+---------------------------+\n",
+        "|  0 |       Fedora |    https://getfedora.org/ |\n",
+        "+----+--------------+---------------------------+\n",
+        "|  2 |     OpenSUSE | https://www.opensuse.org/ |\n",
+        "+----+--------------+---------------------------+\n",
+        "|  3 |  Endeavouros |  https://endeavouros.com/ |\n",
+        "+----+--------------+---------------------------+\n",
     );
 
     let table = table!(
@@ -125,19 +128,19 @@ fn full_alignment_multiline() {
     ];
 
     let expected = concat!(
-        "id|destribution|link                     \n",
-        "--+------------+-------------------------\n",
-        "0 |Fedora      |https://getfedora.org/   \n",
-        "2 |OpenSUSE    |https://www.opensuse.org/\n",
-        "3 |Endeavouros |https://endeavouros.com/ \n",
-        "4 |Red         |https                    \n",
-        "  |Hat         |://                      \n",
-        "  |            |www                      \n",
-        "  |            |.                        \n",
-        "  |            |redhat                   \n",
-        "  |            |.                        \n",
-        "  |            |com                      \n",
-        "  |            |/en                      \n",
+        " id | destribution | link                      \n",
+        "----+--------------+---------------------------\n",
+        " 0  | Fedora       | https://getfedora.org/    \n",
+        " 2  | OpenSUSE     | https://www.opensuse.org/ \n",
+        " 3  | Endeavouros  | https://endeavouros.com/  \n",
+        " 4  | Red          | https                     \n",
+        "    | Hat          | ://                       \n",
+        "    |              | www                       \n",
+        "    |              | .                         \n",
+        "    |              | redhat                    \n",
+        "    |              | .                         \n",
+        "    |              | com                       \n",
+        "    |              | /en                       \n",
     );
 
     let table = table!(
@@ -148,3 +151,140 @@ fn full_alignment_multiline() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn align_on_char() {
+    let data = vec![
+        ("a = 1\nbb = 2\nccc", "id: 0\nname: x"),
+        ("no anchor", "x = 1"),
+    ];
+
+    let expected = concat!(
+        " &str      | &str    \n",
+        "-----------+---------\n",
+        "  a = 1    |   id: 0 \n",
+        " bb = 2    | name: x \n",
+        " ccc       |         \n",
+        " no anchor | x = 1   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        HorizontalAlignment(Full, Alignment::Left),
+        AlignOnChar(Column(..1), '='),
+        AlignOnChar(Column(1..), ':'),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn align_on_decimal() {
+    let data = vec![("$1,200", "12,5"), ("$3.5", "n/a"), ("-$0.125", "7")];
+    let expected = concat!(
+        " &str       | &str \n",
+        "------------+------\n",
+        " $1,200     | 12,5 \n",
+        "     $3.5   | n/a  \n",
+        "    -$0.125 |  7   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        HorizontalAlignment(Full, Alignment::Left),
+        AlignOnDecimal(Column(..1), '.'),
+        AlignOnDecimal(Column(1..), ','),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn align_numbers() {
+    let data = vec![("1", "-12.5", "1.0.0"), ("200", "$1,000", "2")];
+    let expected = concat!(
+        " &str |  &str  | &str  \n",
+        "------+--------+-------\n",
+        "    1 |  -12.5 | 1.0.0 \n",
+        "  200 | $1,000 |   2   \n",
+    );
+
+    let table = table!(&data, Style::psql(), AlignNumbers);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn align_numbers_skips_dates_and_phone_numbers() {
+    let data = vec![
+        ("2021-01-01", "555-1234", "1,000.5"),
+        ("2021-1-1", "55-12", "25%"),
+    ];
+    let expected = concat!(
+        "    &str    |   &str   |  &str   \n",
+        "------------+----------+---------\n",
+        " 2021-01-01 | 555-1234 | 1,000.5 \n",
+        "  2021-1-1  |  55-12   |     25% \n",
+    );
+
+    let table = table!(&data, Style::psql(), AlignNumbers);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn multiline_header_vertical_alignment() {
+    #[derive(Tabled)]
+    struct Sales {
+        region: &'static str,
+        #[tabled(rename = "Total\nRevenue\n(USD)")]
+        revenue: u32,
+    }
+
+    let data = vec![Sales {
+        region: "EMEA",
+        revenue: 1200,
+    }];
+
+    let table = table!(&data, Style::psql());
+    assert_eq!(
+        table,
+        concat!(
+            " region |  Total  \n",
+            "        | Revenue \n",
+            "        |  (USD)  \n",
+            "--------+---------\n",
+            "  EMEA  |  1200   \n",
+        )
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        VerticalAlignment(Head, AlignmentVertical::Center)
+    );
+    assert_eq!(
+        table,
+        concat!(
+            "        |  Total  \n",
+            " region | Revenue \n",
+            "        |  (USD)  \n",
+            "--------+---------\n",
+            "  EMEA  |  1200   \n",
+        )
+    );
+}
+
+#[test]
+fn align_numbers_without_header() {
+    let data = vec![("b", "2"), ("a", "10"), ("a", "1")];
+    let expected = concat!(" b |  2 \n", " a | 10 \n", " a |  1 \n");
+
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+    AlignNumbers.change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
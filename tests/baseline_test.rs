@@ -0,0 +1,71 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid_without_header, table, Baseline, Style, TableOption};
+
+#[test]
+fn baseline_column() {
+    let data = vec![
+        ("parse", "10ms", "8ms", "12.5ms"),
+        ("render", "4ms", "4ms", "n/a"),
+    ];
+    let expected = concat!(
+        "  &str  | &str | &str |  &str   \n",
+        "--------+------+------+---------\n",
+        " parse  | 10ms | 8ms+ | 12.5ms- \n",
+        " render | 4ms  | 4ms  |   n/a   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Baseline::column(1)
+            .better(|s| format!("{}+", s))
+            .worse(|s| format!("{}-", s)),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn baseline_higher_is_better() {
+    let data = vec![("main", 100), ("feature", 120)];
+    let expected = concat!(
+        "  &str   |     i32      \n",
+        "---------+--------------\n",
+        "  main   |     100      \n",
+        " feature | \u{1b}[32m120 (+20.0%)\u{1b}[0m \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Baseline::row(1).higher_is_better().delta(),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn baseline_without_header() {
+    let data = vec![("b", "2"), ("a", "10"), ("a", "1")];
+    let expected = concat!(" b | -2  \n", " a | -10 \n", " a |  1  \n");
+
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+    Baseline::row(2)
+        .better(|s| format!("+{}", s))
+        .worse(|s| format!("-{}", s))
+        .change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::collections::BTreeMap;
+use tabled::{Alignment, Builder, Style, TableOption};
+
+#[test]
+fn builder_from_maps() {
+    let rows = vec![
+        vec![("b", "1"), ("a", "2")],
+        vec![("c", "3")],
+        vec![("a", "4"), ("c", "5")],
+    ];
+    let maps = rows
+        .into_iter()
+        .map(|row| row.into_iter().collect::<BTreeMap<_, _>>())
+        .collect::<Vec<_>>();
+
+    let mut grid = Builder::from_maps(maps).build();
+    Style::psql().change(&mut grid);
+
+    let expected = concat!(
+        " a | b | c \n",
+        "---+---+---\n",
+        " 2 | 1 |   \n",
+        "   |   | 3 \n",
+        " 4 |   | 5 \n",
+    );
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn builder_rows() {
+    let mut grid = Builder::new()
+        .header(vec!["name", "version"])
+        .add_row(vec!["tabled", "0.1.3"])
+        .add_row(vec!["papergrid"])
+        .default_value("?")
+        .build();
+    Style::psql().change(&mut grid);
+
+    let expected = concat!(
+        "   name    | version \n",
+        "-----------+---------\n",
+        "  tabled   |  0.1.3  \n",
+        " papergrid |    ?    \n",
+    );
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn builder_without_header() {
+    let mut grid = Builder::new().add_row(vec![1, 2, 3]).build();
+    Style::psql().change(&mut grid);
+
+    let expected = concat!(" 0 | 1 | 2 \n", "---+---+---\n", " 1 | 2 | 3 \n",);
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn builder_ragged_rows() {
+    let mut grid = Builder::new()
+        .header(vec!["name"])
+        .add_row(vec!["tabled", "0.1.3", "MIT"])
+        .add_row(Vec::<String>::new())
+        .add_row(vec!["papergrid", "0.1.9"])
+        .default_value("-")
+        .default_header("?")
+        .build();
+    Style::psql().change(&mut grid);
+
+    let expected = concat!(
+        "   name    |   ?   |  ?  \n",
+        "-----------+-------+-----\n",
+        "  tabled   | 0.1.3 | MIT \n",
+        "     -     |   -   |  -  \n",
+        " papergrid | 0.1.9 |  -  \n",
+    );
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn builder_skips_header_row() {
+    let mut grid = Builder::new()
+        .header(vec!["name", "version", "license"])
+        .add_row(vec!["tabled", "0.1.3"])
+        .add_row(vec!["papergrid", "0.1.9"])
+        .without_header()
+        .build();
+    Style::psql().change(&mut grid);
+
+    let expected = concat!("  tabled   | 0.1.3 |  \n", " papergrid | 0.1.9 |  \n",);
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn builder_column_alignment() {
+    let mut grid = Builder::new()
+        .header(vec!["name", "version"])
+        .add_row(vec!["tabled", "0.1.3"])
+        .add_row(vec!["papergrid", "0.1.9"])
+        .alignment(0, Alignment::Left)
+        .alignment(1, Alignment::Right)
+        .alignment(2, Alignment::Right)
+        .build();
+    Style::psql().change(&mut grid);
+
+    let expected = concat!(
+        " name      | version \n",
+        "-----------+---------\n",
+        " tabled    |   0.1.3 \n",
+        " papergrid |   0.1.9 \n",
+    );
+
+    assert_eq!(grid.to_string(), expected);
+}
@@ -0,0 +1,55 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Alignment, Caption, Margin, Style};
+
+#[test]
+fn caption_top_and_bottom() {
+    let data = vec![("a", "b")];
+    let expected = concat!(
+        "Report         \n",
+        "+------+------+\n",
+        "| &str | &str |\n",
+        "+------+------+\n",
+        "|  a   |  b   |\n",
+        "+------+------+\n",
+        "    page 1     \n",
+    );
+
+    let table = table!(
+        &data,
+        Caption::top("Report").alignment(Alignment::Left),
+        Caption::bottom("page 1"),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn caption_with_margin() {
+    let data = vec!["a"];
+    let expected = concat!(
+        "  A long title\n",
+        "   &str       \n",
+        "  ------      \n",
+        "    a         \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Margin::new(2, 0, 0, 0),
+        Caption::top("A long title").alignment(Alignment::Right),
+    );
+
+    assert_eq!(table, expected);
+}
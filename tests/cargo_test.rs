@@ -0,0 +1,112 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+#![cfg(feature = "cargo")]
+
+use cargo_metadata::Package;
+use tabled::cargo::{self, Benchmark};
+
+fn package() -> Package {
+    let manifest = r#"{
+        "name": "tabled",
+        "version": "0.1.3",
+        "id": "tabled 0.1.3 (path+file:///tabled)",
+        "dependencies": [
+            {
+                "name": "colored",
+                "req": "^2.0.0",
+                "kind": "dev",
+                "optional": false,
+                "uses_default_features": true,
+                "features": [],
+                "target": null
+            },
+            {
+                "name": "papergrid",
+                "req": "^0.1.9",
+                "kind": null,
+                "optional": false,
+                "uses_default_features": true,
+                "features": [],
+                "target": null
+            },
+            {
+                "name": "chrono",
+                "req": "^0.4",
+                "kind": null,
+                "optional": true,
+                "uses_default_features": true,
+                "features": [],
+                "target": "cfg(unix)"
+            }
+        ],
+        "targets": [],
+        "features": {
+            "color": ["papergrid/color"],
+            "timestamp": ["chrono"],
+            "full": ["color", "timestamp"]
+        },
+        "manifest_path": "/tabled/Cargo.toml"
+    }"#;
+
+    serde_json::from_str(manifest).unwrap()
+}
+
+#[test]
+fn dependencies_table() {
+    let expected = concat!(
+        "   name    | version |  kind  | optional |  target   \n",
+        "-----------+---------+--------+----------+-----------\n",
+        "  chrono   |  ^0.4   | normal |    +     | cfg(unix) \n",
+        " papergrid | ^0.1.9  | normal |          |           \n",
+        "  colored  | ^2.0.0  |  dev   |          |           \n",
+    );
+
+    assert_eq!(cargo::dependencies(&package()), expected);
+}
+
+#[test]
+fn features_matrix() {
+    let expected = concat!(
+        "  feature  | chrono | color | papergrid/color | timestamp \n",
+        "-----------+--------+-------+-----------------+-----------\n",
+        "   color   |        |       |        +        |           \n",
+        "   full    |        |   +   |                 |     +     \n",
+        " timestamp |   +    |       |                 |           \n",
+    );
+
+    assert_eq!(cargo::features(&package()), expected);
+}
+
+#[test]
+fn benchmarks_comparison() {
+    let estimates = |mean: f64| {
+        format!(
+            r#"{{"mean": {{"point_estimate": {}, "standard_error": 1.0}}}}"#,
+            mean
+        )
+    };
+
+    let benchmarks = vec![
+        Benchmark::from_criterion("parse", Some(&estimates(2000.0)), &estimates(1500.0)).unwrap(),
+        Benchmark::from_criterion("render", None, &estimates(3.5e6)).unwrap(),
+    ];
+    let expected = concat!(
+        " benchmark |  base   |       new        \n",
+        "-----------+---------+------------------\n",
+        "   parse   | 2.00 µs | \u{1b}[32m1.50 µs (-25.0%)\u{1b}[0m \n",
+        "  render   |    -    |     3.50 ms      \n",
+    );
+
+    assert_eq!(cargo::benchmarks(&benchmarks), expected);
+    assert!(Benchmark::from_criterion("parse", None, "{}").is_err());
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    build_grid_without_header, table, ComputedColumn, RemoveColumn, RenameHeader, ReorderColumns,
+    Style, TableOption, Tabled,
+};
+
+#[derive(Tabled)]
+struct File {
+    name: &'static str,
+    date: &'static str,
+    size: usize,
+    checksum: &'static str,
+}
+
+fn files() -> Vec<File> {
+    vec![
+        File {
+            name: "README.md",
+            date: "2021-03-01",
+            size: 1024,
+            checksum: "d41d8cd9",
+        },
+        File {
+            name: "LICENSE",
+            date: "2021-01-12",
+            size: 1069,
+            checksum: "8f00b204",
+        },
+    ]
+}
+
+#[test]
+fn reorder_columns() {
+    let table = table!(
+        &files(),
+        Style::psql(),
+        ReorderColumns::new(vec!["size", "unknown", "name", "size"])
+    );
+
+    let expected = concat!(
+        " size |   name    |    date    | checksum \n",
+        "------+-----------+------------+----------\n",
+        " 1024 | README.md | 2021-03-01 | d41d8cd9 \n",
+        " 1069 |  LICENSE  | 2021-01-12 | 8f00b204 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn reorder_only_listed_columns() {
+    let columns = "date,name".split(',').map(String::from);
+    let table = table!(&files(), Style::psql(), ReorderColumns::new(columns).only());
+
+    let expected = concat!(
+        "    date    |   name    \n",
+        "------------+-----------\n",
+        " 2021-03-01 | README.md \n",
+        " 2021-01-12 |  LICENSE  \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn remove_column() {
+    let table = table!(
+        &files(),
+        Style::psql(),
+        RemoveColumn::new("checksum"),
+        RemoveColumn::new("unknown"),
+        RemoveColumn::new("date")
+    );
+
+    let expected = concat!(
+        "   name    | size \n",
+        "-----------+------\n",
+        " README.md | 1024 \n",
+        "  LICENSE  | 1069 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn columns_of_grid_without_header_are_not_touched() {
+    let mut grid = build_grid_without_header(files());
+    RemoveColumn::new("name").change(&mut grid);
+    ReorderColumns::new(["size"]).only().change(&mut grid);
+
+    assert_eq!(grid.count_columns(), 4);
+}
+
+#[test]
+fn computed_column() {
+    let kilobytes = |row: &[&str]| format!("{}K", row[2].parse::<usize>().unwrap() / 1024);
+    let table = table!(
+        &files(),
+        Style::psql(),
+        ComputedColumn::new("kb", kilobytes).position(2),
+        RemoveColumn::new("checksum")
+    );
+
+    let expected = concat!(
+        "   name    |    date    | kb | size \n",
+        "-----------+------------+----+------\n",
+        " README.md | 2021-03-01 | 1K | 1024 \n",
+        "  LICENSE  | 2021-01-12 | 1K | 1069 \n",
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn computed_column_without_header() {
+    let mut grid = build_grid_without_header(vec![("a", "b")]);
+    ComputedColumn::new("joined", |row| row.concat()).change(&mut grid);
+    Style::psql().change(&mut grid);
+
+    assert_eq!(grid.to_string(), " a | b | ab \n");
+}
+
+#[test]
+fn rename_header() {
+    let table = table!(
+        &files(),
+        Style::psql(),
+        RenameHeader::new("checksum", "MD5"),
+        RenameHeader::new("unknown", "?"),
+        RenameHeader::column(2, "Size"),
+        RenameHeader::column(10, "?"),
+        ReorderColumns::new(["MD5", "Size"]).only()
+    );
+
+    let expected = concat!(
+        "   MD5    | Size \n",
+        "----------+------\n",
+        " d41d8cd9 | 1024 \n",
+        " 8f00b204 | 1069 \n",
+    );
+
+    assert_eq!(table, expected);
+}
@@ -46,6 +46,64 @@ mod structure {
         );
     }
 
+    #[test]
+    fn rename_field_by_tabled_attribute() {
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(rename = "Created At")]
+            created_at: u8,
+            f2: &'static str,
+        }
+
+        let st = St {
+            created_at: 0,
+            f2: "v2",
+        };
+        assert_eq!(vec!["0".to_owned(), "v2".to_owned()], st.fields());
+        assert_eq!(
+            vec!["Created At".to_owned(), "f2".to_owned()],
+            St::headers()
+        );
+    }
+
+    #[test]
+    fn rename_all_fields() {
+        #[derive(Tabled)]
+        #[tabled(rename_all = "UPPERCASE")]
+        struct Upper {
+            created_at: u8,
+            #[tabled(rename = "Id")]
+            id: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "kebab-case")]
+        struct Kebab {
+            created_at: u8,
+            id: u8,
+        }
+
+        #[derive(Tabled)]
+        #[tabled(rename_all = "PascalCase")]
+        struct Pascal {
+            created_at: u8,
+            id: u8,
+        }
+
+        assert_eq!(
+            vec!["CREATED_AT".to_owned(), "Id".to_owned()],
+            Upper::headers()
+        );
+        assert_eq!(
+            vec!["created-at".to_owned(), "id".to_owned()],
+            Kebab::headers()
+        );
+        assert_eq!(
+            vec!["CreatedAt".to_owned(), "Id".to_owned()],
+            Pascal::headers()
+        );
+    }
+
     #[allow(dead_code)]
     #[test]
     fn structure_hidden_field() {
@@ -68,6 +126,7 @@ mod structure {
         assert_eq!(vec!["f3".to_owned()], St::headers());
     }
 
+    #[allow(dead_code)]
     #[test]
     fn enum_hidden_variant() {
         #[derive(Tabled)]
@@ -90,6 +149,181 @@ mod structure {
         assert!(E::B(String::new()).fields().is_empty());
     }
 
+    #[test]
+    fn display_with() {
+        fn display_option(o: &Option<u8>) -> String {
+            match o {
+                Some(v) => v.to_string(),
+                None => "-".to_owned(),
+            }
+        }
+
+        struct NotDisplay(&'static str);
+
+        impl NotDisplay {
+            fn as_str(&self) -> &'static str {
+                self.0
+            }
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(display_with = "display_option")]
+            f1: Option<u8>,
+            #[tabled(display_with = "NotDisplay::as_str")]
+            f2: NotDisplay,
+            #[tabled(rename = "sum", display_with("Self::sum", self))]
+            f3: u8,
+        }
+
+        impl St {
+            fn sum(&self) -> u8 {
+                self.f1.unwrap_or(0) + self.f3
+            }
+        }
+
+        let st = St {
+            f1: None,
+            f2: NotDisplay("v2"),
+            f3: 3,
+        };
+        assert_eq!(
+            vec!["-".to_owned(), "v2".to_owned(), "3".to_owned()],
+            st.fields()
+        );
+
+        let st = St {
+            f1: Some(1),
+            f2: NotDisplay("v2"),
+            f3: 3,
+        };
+        assert_eq!(
+            vec!["1".to_owned(), "v2".to_owned(), "4".to_owned()],
+            st.fields()
+        );
+        assert_eq!(
+            vec!["f1".to_owned(), "f2".to_owned(), "sum".to_owned()],
+            St::headers()
+        );
+    }
+
+    #[test]
+    fn inline_field() {
+        #[derive(Tabled)]
+        struct Address {
+            city: &'static str,
+            street: &'static str,
+        }
+
+        #[derive(Tabled)]
+        struct Person {
+            name: &'static str,
+            #[tabled(inline)]
+            home: Address,
+            #[tabled(inline("work "))]
+            work: Address,
+        }
+
+        #[derive(Tabled)]
+        struct Tuple(#[tabled(inline("0."))] Address, u8);
+
+        let address = || Address {
+            city: "Minsk",
+            street: "Lenina",
+        };
+
+        let person = Person {
+            name: "Maxim",
+            home: address(),
+            work: address(),
+        };
+        assert_eq!(
+            vec!["name", "city", "street", "work city", "work street"],
+            Person::headers()
+        );
+        assert_eq!(
+            vec!["Maxim", "Minsk", "Lenina", "Minsk", "Lenina"],
+            person.fields()
+        );
+
+        assert_eq!(vec!["0.city", "0.street", "1"], Tuple::headers());
+        assert_eq!(vec!["Minsk", "Lenina", "1"], Tuple(address(), 1).fields());
+    }
+
+    #[test]
+    fn order_fields() {
+        #[derive(Tabled)]
+        struct St {
+            name: &'static str,
+            #[tabled(skip)]
+            #[allow(dead_code)]
+            cache: u8,
+            #[tabled(order = 0)]
+            id: u8,
+            #[tabled(inline, order = 2)]
+            tuple: Tuple,
+            value: u8,
+        }
+
+        #[derive(Tabled)]
+        struct Tuple(&'static str, #[tabled(order = 0)] u8);
+
+        let st = St {
+            name: "v2",
+            cache: 0,
+            id: 1,
+            tuple: Tuple("v3", 3),
+            value: 4,
+        };
+        assert_eq!(vec!["id", "name", "1", "0", "value"], St::headers());
+        assert_eq!(vec!["1", "v2", "3", "v3", "4"], st.fields());
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn skip_fields() {
+        struct NotDisplay;
+
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(skip)]
+            id: u8,
+            name: &'static str,
+            #[tabled(rename = "cache", skip)]
+            cache: NotDisplay,
+        }
+
+        #[derive(Tabled)]
+        struct Tuple(#[tabled(skip)] NotDisplay, &'static str, u8);
+
+        let st = St {
+            id: 0,
+            name: "v2",
+            cache: NotDisplay,
+        };
+        assert_eq!(vec!["v2".to_owned()], st.fields());
+        assert_eq!(vec!["name".to_owned()], St::headers());
+
+        let st = Tuple(NotDisplay, "v2", 3);
+        assert_eq!(vec!["v2".to_owned(), "3".to_owned()], st.fields());
+        assert_eq!(vec!["1".to_owned(), "2".to_owned()], Tuple::headers());
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn skip_variant() {
+        #[derive(Tabled)]
+        enum E {
+            A,
+            #[tabled(skip)]
+            B,
+            C,
+        }
+
+        assert_eq!(vec!["A".to_owned(), "C".to_owned()], E::headers());
+        assert!(E::B.fields().is_empty());
+    }
+
     #[test]
     fn rename_enum_variant() {
         #[allow(dead_code)]
@@ -115,6 +349,81 @@ mod structure {
         );
     }
 
+    #[test]
+    fn rename_all_enum_variants() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        #[tabled(rename_all = "SCREAMING_SNAKE_CASE")]
+        enum E {
+            NotFound,
+            #[tabled(rename = "Server error")]
+            InternalServerError,
+            Ok,
+        }
+
+        assert_eq!(
+            vec![
+                "NOT_FOUND".to_owned(),
+                "Server error".to_owned(),
+                "OK".to_owned()
+            ],
+            E::headers()
+        );
+    }
+
+    #[allow(dead_code)]
+    #[test]
+    fn enum_inline_variant() {
+        #[derive(Tabled)]
+        struct Peer {
+            host: &'static str,
+            port: u16,
+        }
+
+        #[derive(Tabled)]
+        enum Event {
+            #[tabled(inline("connected "))]
+            Connected(#[tabled(inline)] Peer),
+            #[tabled(inline)]
+            Message {
+                size: usize,
+                #[tabled(rename = "text", display_with = "Clone::clone")]
+                body: String,
+                #[tabled(skip)]
+                raw: Vec<u8>,
+            },
+            Tick(u8, u8),
+            Shutdown,
+        }
+
+        assert_eq!(
+            vec![
+                "connected host",
+                "connected port",
+                "size",
+                "text",
+                "Tick",
+                "Shutdown"
+            ],
+            Event::headers()
+        );
+
+        let connected = Event::Connected(Peer {
+            host: "localhost",
+            port: 80,
+        });
+        assert_eq!(vec!["localhost", "80", "", "", "", ""], connected.fields());
+
+        let message = Event::Message {
+            size: 5,
+            body: "hello".to_owned(),
+            raw: Vec::new(),
+        };
+        assert_eq!(vec!["", "", "5", "hello", "", ""], message.fields());
+        assert_eq!(vec!["", "", "", "", "+", ""], Event::Tick(1, 2).fields());
+        assert_eq!(vec!["", "", "", "", "", "+"], Event::Shutdown.fields());
+    }
+
     #[test]
     fn empty() {
         #[derive(Tabled)]
@@ -181,6 +490,7 @@ mod structure {
         assert_eq!(vec!["1".to_owned()], fields);
     }
 
+    #[allow(dead_code)]
     #[test]
     fn enum_structure() {
         #[derive(Tabled)]
@@ -208,3 +518,34 @@ mod structure {
         );
     }
 }
+
+mod without_import {
+    #[test]
+    fn derive_by_path() {
+        #[derive(tabled::Tabled)]
+        struct Address {
+            city: &'static str,
+        }
+
+        #[derive(tabled::Tabled)]
+        struct Person {
+            name: &'static str,
+            #[tabled(inline)]
+            address: Address,
+        }
+
+        let person = Person {
+            name: "Maxim",
+            address: Address { city: "Kyiv" },
+        };
+
+        assert_eq!(
+            vec!["name".to_owned(), "city".to_owned()],
+            <Person as tabled::Tabled>::headers()
+        );
+        assert_eq!(
+            vec!["Maxim".to_owned(), "Kyiv".to_owned()],
+            tabled::Tabled::fields(&person)
+        );
+    }
+}
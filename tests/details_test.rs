@@ -0,0 +1,108 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid_without_header, table, Details, Disable, Style, TableOption};
+
+#[test]
+fn details_default_style() {
+    let data = vec![("a", 1), ("b", 2)];
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  a   |  1  |\n",
+        "+------+-----+\n",
+        "|  b   |  2  |\n",
+        "| two        |\n",
+        "+------+-----+\n",
+    );
+
+    let table = table!(
+        &data,
+        Details::new(|i| if i == 1 {
+            Some(String::from("two"))
+        } else {
+            None
+        }),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn details_hidden() {
+    let data = vec![("a", 1)];
+
+    let table = table!(&data, Details::new(|_| Some(String::from("x"))).show(false));
+
+    assert_eq!(table, table!(&data));
+}
+
+#[test]
+fn details_follow_rows() {
+    let data = vec![("a", 1), ("b", 2)];
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  b   |  2  |\n",
+        "| detail 1   |\n",
+        "+------+-----+\n",
+    );
+
+    let table = table!(
+        &data,
+        Details::new(|i| Some(format!("detail {}", i))),
+        Disable::Row(1..2),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn details_multiline() {
+    let data = vec![("a", 1)];
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  a   |  1  |\n",
+        "| first      |\n",
+        "| second     |\n",
+        "+------+-----+\n",
+    );
+
+    let table = table!(&data, Details::new(|_| Some(String::from("first\nsecond"))));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn details_none() {
+    let data = vec![("a", 1)];
+
+    let table = table!(&data, Details::new(|_| None));
+
+    assert_eq!(table, table!(&data));
+}
+
+#[test]
+fn details_without_header() {
+    let data = vec![("a", 1), ("b", 2)];
+    let expected = concat!(" a | 1 \n", " d0    \n", " b | 2 \n", " d1    \n");
+
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+    Details::new(|i| Some(format!("d{}", i))).change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
@@ -41,9 +41,9 @@ fn disable_rows() {
     ];
 
     let expected = concat!(
-        "id|destribution|link                    \n",
-        "--+------------+------------------------\n",
-        "3 |Endeavouros |https://endeavouros.com/\n",
+        " id | destribution | link                     \n",
+        "----+--------------+--------------------------\n",
+        " 3  | Endeavouros  | https://endeavouros.com/ \n",
     );
 
     let table = table!(
@@ -77,9 +77,9 @@ fn disable_header() {
     ];
 
     let expected = concat!(
-        "0|Fedora     |https://getfedora.org/   \n",
-        "2|OpenSUSE   |https://www.opensuse.org/\n",
-        "3|Endeavouros|https://endeavouros.com/ \n",
+        " 0 | Fedora      | https://getfedora.org/    \n",
+        " 2 | OpenSUSE    | https://www.opensuse.org/ \n",
+        " 3 | Endeavouros | https://endeavouros.com/  \n",
     );
 
     let table = table!(
@@ -145,12 +145,12 @@ fn disable_header_with_new_styling() {
     ];
 
     let expected = concat!(
-        "┌─┬───────────┬─────────────────────────┐\n",
-        "│0│Fedora     │https://getfedora.org/   │\n",
-        "├─┼───────────┼─────────────────────────┤\n",
-        "│2│OpenSUSE   │https://www.opensuse.org/│\n",
-        "│3│Endeavouros│https://endeavouros.com/ │\n",
-        "└─┴───────────┴─────────────────────────┘\n",
+        "┌───┬─────────────┬───────────────────────────┐\n",
+        "│ 0 │ Fedora      │ https://getfedora.org/    │\n",
+        "├───┼─────────────┼───────────────────────────┤\n",
+        "│ 2 │ OpenSUSE    │ https://www.opensuse.org/ │\n",
+        "│ 3 │ Endeavouros │ https://endeavouros.com/  │\n",
+        "└───┴─────────────┴───────────────────────────┘\n",
     );
 
     let table = table!(
@@ -184,11 +184,11 @@ fn disable_columns() {
     ];
 
     let expected = concat!(
-        "destribution|link                     \n",
-        "------------+-------------------------\n",
-        "Fedora      |https://getfedora.org/   \n",
-        "OpenSUSE    |https://www.opensuse.org/\n",
-        "Endeavouros |https://endeavouros.com/ \n",
+        " destribution | link                      \n",
+        "--------------+---------------------------\n",
+        " Fedora       | https://getfedora.org/    \n",
+        " OpenSUSE     | https://www.opensuse.org/ \n",
+        " Endeavouros  | https://endeavouros.com/  \n",
     );
 
     let table = table!(
@@ -232,3 +232,18 @@ fn disable_all_table_via_columns() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn disable_out_of_range() {
+    let data = vec!["Fedora", "OpenSUSE"];
+    let expected = concat!("  &str  \n", "--------\n", " Fedora \n");
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Disable::Row(2..10),
+        Disable::Column(1..),
+    );
+
+    assert_eq!(table, expected);
+}
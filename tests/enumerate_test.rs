@@ -0,0 +1,56 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid_without_header, table, Enumerate, Style, TableOption};
+
+#[test]
+fn enumerate_with_start_and_header() {
+    let data = vec![("Fedora", 34), ("OpenSUSE", 15)];
+    let expected = concat!(
+        " No |   &str   | i32 \n",
+        "----+----------+-----\n",
+        " 0  |  Fedora  | 34  \n",
+        " 1  | OpenSUSE | 15  \n",
+    );
+
+    let table = table!(&data, Style::psql(), Enumerate::new().start(0).header("No"));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn enumerate_default_style() {
+    let data = vec!["a"];
+    let expected = concat!(
+        "+---+------+\n",
+        "| # | &str |\n",
+        "+---+------+\n",
+        "| 1 |  a   |\n",
+        "+---+------+\n",
+    );
+
+    let table = table!(&data, Enumerate::new());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn enumerate_without_header() {
+    let data = vec![("Fedora", 34), ("OpenSUSE", 15)];
+    let expected = concat!(" 1 |  Fedora  | 34 \n", " 2 | OpenSUSE | 15 \n");
+
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+    Enumerate::new().change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
@@ -0,0 +1,53 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid_without_header, table, Filter, Style, TableOption};
+
+#[test]
+fn filter_rows() {
+    let data = vec![("a", "ok"), ("b", "failed"), ("c", "ok")];
+    let expected = concat!(
+        " &str |  &str  \n",
+        "------+--------\n",
+        "  b   | failed \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Filter(|record: &[&str]| record[1] != "ok")
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn filter_out_all_rows() {
+    let data = vec!["a", "b"];
+    let expected = concat!("+------+\n", "| &str |\n", "+------+\n");
+
+    let table = table!(&data, Filter(|_: &[&str]| false));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn filter_without_header() {
+    let data = vec![("b", "2"), ("a", "10"), ("a", "1")];
+    let expected = concat!(" a | 10 \n", " a | 1  \n");
+
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+    Filter(|record: &[&str]| record[0] == "a").change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid_without_header, table, Aggregate, Footer, Style, TableOption};
+
+#[test]
+fn footer_aggregates() {
+    let data = vec![("a", "1.5"), ("b", "2.25"), ("c", "n/a")];
+    let expected = concat!(
+        " &str |  &str  \n",
+        "------+--------\n",
+        "  a   |  1.5   \n",
+        "  b   |  2.25  \n",
+        "  c   |  n/a   \n",
+        " *3*  | *1.88* \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Footer::new()
+            .aggregate(0, Aggregate::Count)
+            .aggregate(1, Aggregate::Average)
+            .format(|s| format!("*{}*", s)),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_default_style() {
+    let data = vec![("a", 1), ("b", 2)];
+    let expected = concat!(
+        "+------+-----+\n",
+        "| &str | i32 |\n",
+        "+------+-----+\n",
+        "|  a   |  1  |\n",
+        "+------+-----+\n",
+        "|  b   |  2  |\n",
+        "+------+-----+\n",
+        "| sum  |  3  |\n",
+        "+------+-----+\n",
+    );
+
+    let table = table!(
+        &data,
+        Footer::new().label(0, "sum").aggregate(1, Aggregate::Sum)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn footer_without_header() {
+    let data = vec![("b", "2"), ("a", "10"), ("a", "1")];
+    let expected = concat!(
+        "   b   | 2  \n",
+        "   a   | 10 \n",
+        "   a   | 1  \n",
+        " Total | 13 \n",
+    );
+
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+    Footer::new()
+        .label(0, "Total")
+        .aggregate(1, Aggregate::Sum)
+        .change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
@@ -10,7 +10,10 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-use tabled::{multiline, table, Cell, Column, Format, Full, Head, Object, Row, Style, Tabled};
+use tabled::{
+    bytes, multiline, percent, precision, table, thousands, Cell, Column, Format, FormatIf, Full,
+    Head, Object, Row, Style, Tabled, Zebra,
+};
 
 #[derive(Tabled)]
 struct Linux {
@@ -329,6 +332,106 @@ fn formatting_not_combination_test() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn formatting_if_content_test() {
+    let data = vec![("Fedora", 10), ("OpenSUSE", -3), ("Endeavouros", -12)];
+
+    let expected = concat!(
+        "    &str     |  i32  \n",
+        "-------------+-------\n",
+        "   Fedora    |  10   \n",
+        "  OpenSUSE   | (-3)  \n",
+        " Endeavouros | (-12) \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        FormatIf(
+            Column(1..).not(Head),
+            |r| r.text().starts_with('-'),
+            |s| format!("({})", s)
+        ),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn formatting_if_record_test() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        "  id   | destribution |             link              \n",
+        "-------+--------------+-------------------------------\n",
+        "   0   |    Fedora    |    https://getfedora.org/     \n",
+        " [[2]] | [[OpenSUSE]] | [[https://www.opensuse.org/]] \n",
+        "   3   | Endeavouros  |   https://endeavouros.com/    \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        FormatIf(
+            Full,
+            |r| r.field(1) == Some("OpenSUSE"),
+            |s| format!("[[{}]]", s)
+        ),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn formatting_zebra_test() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "Open\nSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        " id  | destribution |            link             \n",
+        "-----+--------------+-----------------------------\n",
+        "  0  |    Fedora    |   https://getfedora.org/    \n",
+        " (2) |    (Open)    | (https://www.opensuse.org/) \n",
+        "     |    (SUSE)    |                             \n",
+        "  3  | Endeavouros  |  https://endeavouros.com/   \n",
+    );
+
+    let table = table!(&data, Style::psql(), Zebra(|s| format!("({})", s)));
+
+    assert_eq!(table, expected);
+}
+
 #[cfg(feature = "color")]
 mod color {
 
@@ -424,3 +527,28 @@ mod color {
         assert_eq!(table, expected);
     }
 }
+
+#[test]
+fn numeric_formatting() {
+    let data = vec![
+        ("disk", "1099511627776", "0.4567", "12345.678"),
+        ("n/a", "-", "-", "-"),
+    ];
+    let expected = concat!(
+        " &str |  &str   |  &str  |   &str    \n",
+        "------+---------+--------+-----------\n",
+        " disk | 1.0 TiB | 45.67% | 12 345.68 \n",
+        " n/a  |    -    |   -    |     -     \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Format(Column(1..2), bytes()),
+        Format(Column(2..3), percent(2)),
+        Format(Column(3..), precision(2)),
+        Format(Column(3..), thousands(' ')),
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use papergrid::{string_width, Grid};
+use tabled::{
+    testdata::{Content, Generator},
+    Alignment, Border, Cell, Full, Highlight, HorizontalAlignment, Style, TableOption, Zebra,
+};
+
+const SEEDS: u64 = 100;
+
+fn check<O: TableOption>(content: &[Content], option: O) {
+    for seed in 0..SEEDS {
+        let generator = Generator::new(seed)
+            .size(seed as usize % 6, 1 + seed as usize % 5)
+            .content(content);
+        let mut grid: Grid = generator.build();
+        option.change(&mut grid);
+        let table = grid.to_string();
+
+        let widths = table.lines().map(string_width).collect::<Vec<_>>();
+        assert!(
+            widths.windows(2).all(|w| w[0] == w[1]),
+            "lines have different width, seed {}\n{}",
+            seed,
+            table
+        );
+    }
+}
+
+#[test]
+fn fuzz_styles() {
+    let content = [Content::Ascii, Content::Unicode, Content::Multiline];
+    check(&content, Style::default());
+    check(&content, Style::psql());
+    check(&content, Style::github_markdown());
+    check(&content, Style::pseudo());
+    check(&content, Style::pseudo_clean());
+    check(&content, Style::noborder());
+}
+
+#[test]
+fn fuzz_settings() {
+    let content = [Content::Ascii, Content::Unicode, Content::Multiline];
+    check(&content, HorizontalAlignment(Full, Alignment::Right));
+    check(&content, Zebra(|s| format!("~{}~", s)));
+    // a header is the only row each generated grid is guaranteed to have
+    check(&content, Highlight(Cell(0, 0), Border::filled('*')));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn fuzz_ansi() {
+    let content = [Content::Ansi, Content::Multiline];
+    check(&content, Style::psql());
+    check(&content, Zebra::dimmed());
+}
+
+#[test]
+fn generator_is_deterministic() {
+    let generator = Generator::new(7).size(4, 3).content(&[
+        Content::Unicode,
+        Content::Ansi,
+        Content::Multiline,
+    ]);
+
+    assert_eq!(generator.records(), generator.records());
+    assert_ne!(generator.records(), Generator::new(8).size(4, 3).records());
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Height, Style};
+
+#[test]
+fn height_limit() {
+    let data = vec![("one\ntwo\nthree", "a")];
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        " one  |  a   \n",
+        " two  |      \n",
+    );
+
+    let table = table!(&data, Style::psql(), Height::limit(2));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_limit_with_suffix() {
+    let data = vec!["one\ntwo"];
+    let expected = concat!("  &str  \n", "--------\n", " one... \n",);
+
+    let table = table!(&data, Style::psql(), Height::limit(1).suffix("..."));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn height_increase() {
+    let data = vec![("one\ntwo\nthree", "a"), ("b", "c")];
+    let expected = concat!(
+        " &str  | &str \n",
+        "       |      \n",
+        "-------+------\n",
+        "  one  |  a   \n",
+        "  two  |      \n",
+        " three |      \n",
+        "   b   |  c   \n",
+        "       |      \n",
+    );
+
+    let table = table!(&data, Style::psql(), Height::increase(2));
+
+    assert_eq!(table, expected);
+}
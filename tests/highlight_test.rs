@@ -0,0 +1,167 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Border, Cell, CellBorders, Column, Highlight, Object, Row, Style, Tabled};
+
+#[derive(Tabled)]
+struct Linux {
+    id: u8,
+    destribution: &'static str,
+    link: &'static str,
+}
+
+fn data() -> Vec<Linux> {
+    vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ]
+}
+
+#[test]
+fn highlight_column() {
+    let expected = concat!(
+        "┌────┏━━━━━━━━━━━━━━┓───────────────────────────┐\n",
+        "│ id ┃ destribution ┃           link            │\n",
+        "├────┃──────────────┃───────────────────────────┤\n",
+        "│ 0  ┃    Fedora    ┃  https://getfedora.org/   │\n",
+        "├────┃──────────────┃───────────────────────────┤\n",
+        "│ 2  ┃   OpenSUSE   ┃ https://www.opensuse.org/ │\n",
+        "├────┃──────────────┃───────────────────────────┤\n",
+        "│ 3  ┃ Endeavouros  ┃ https://endeavouros.com/  │\n",
+        "└────┗━━━━━━━━━━━━━━┛───────────────────────────┘\n",
+    );
+
+    let table = table!(
+        &data(),
+        Style::pseudo(),
+        Highlight(
+            Column(1..2),
+            Border::full('━', '━', '┃', '┃', '┏', '┓', '┗', '┛')
+        )
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_not_rectangular_region() {
+    let expected = concat!(
+        "*************************************************\n",
+        "* id | destribution |           link            *\n",
+        "*----********************************************\n",
+        "* 0  *    Fedora    |  https://getfedora.org/   |\n",
+        "******--------------+---------------------------+\n",
+        "| 2  |   OpenSUSE   | https://www.opensuse.org/ |\n",
+        "+----+--------------+---------------------------+\n",
+        "| 3  | Endeavouros  | https://endeavouros.com/  |\n",
+        "+----+--------------+---------------------------+\n",
+    );
+
+    let table = table!(
+        &data(),
+        Style::default(),
+        Highlight(Row(..1).and(Cell(1, 0)), Border::filled('*'))
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn highlight_adds_missing_lines() {
+    let expected = concat!(
+        " id | destribution |           link            \n",
+        "----################---------------------------\n",
+        " 0  #    Fedora    #  https://getfedora.org/   \n",
+        "    ################                           \n",
+        " 2  |   OpenSUSE   | https://www.opensuse.org/ \n",
+        " 3  | Endeavouros  | https://endeavouros.com/  \n",
+    );
+
+    let table = table!(
+        &data(),
+        Style::psql(),
+        Highlight(Cell(1, 1), Border::filled('#'))
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn cell_borders_open_left_edge() {
+    let expected = concat!(
+        " ----+--------------+---------------------------+\n",
+        "  id | destribution |           link            |\n",
+        " ----+--------------+---------------------------+\n",
+        "  0  |    Fedora    |  https://getfedora.org/   |\n",
+        " ----+--------------+---------------------------+\n",
+        "  2  |   OpenSUSE   | https://www.opensuse.org/ |\n",
+        " ----+--------------+---------------------------+\n",
+        "  3  | Endeavouros  | https://endeavouros.com/  |\n",
+        " ----+--------------+---------------------------+\n",
+    );
+
+    let table = table!(
+        &data(),
+        Style::default(),
+        CellBorders(
+            Column(..1),
+            Border::empty()
+                .top('-')
+                .bottom('-')
+                .right('|')
+                .top_right_corner('+')
+                .bottom_right_corner('+')
+        )
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn cell_borders_emphasize_cell() {
+    let expected = concat!(
+        "+----+--------------+---------------------------+\n",
+        "| id | destribution |           link            |\n",
+        "+----+==============+---------------------------+\n",
+        "| 0  #    Fedora    #  https://getfedora.org/   |\n",
+        "+----+==============+---------------------------+\n",
+        "| 2  |   OpenSUSE   | https://www.opensuse.org/ |\n",
+        "+----+--------------+---------------------------+\n",
+        "| 3  | Endeavouros  | https://endeavouros.com/  |\n",
+        "+----+--------------+---------------------------+\n",
+    );
+
+    let border = Border::filled('+')
+        .top('=')
+        .bottom('=')
+        .left('#')
+        .right('#');
+    assert_eq!(border.get_left(), '#');
+    assert_eq!(border.get_top_left_corner(), '+');
+
+    let table = table!(&data(), Style::default(), CellBorders(Cell(1, 1), border));
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    table, Alignment, Column, Full, HorizontalAlignment, Indent, Padding, Row, Style, Tabled,
+};
+
+#[derive(Tabled)]
+struct Linux {
+    id: u8,
+    destribution: &'static str,
+}
+
+fn data() -> Vec<Linux> {
+    vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+        },
+    ]
+}
+
+#[test]
+fn indent_each_side() {
+    let expected = concat!(
+        "+------+----------------+\n",
+        "|      |                |\n",
+        "|   id |   destribution |\n",
+        "+------+----------------+\n",
+        "|      |                |\n",
+        "|   0  |      Fedora    |\n",
+        "+------+----------------+\n",
+        "|      |                |\n",
+        "|   2  |     OpenSUSE   |\n",
+        "+------+----------------+\n",
+    );
+
+    let table = table!(&data(), Indent(Full, Padding::new(3, 1, 1, 0)));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn indent_fill() {
+    let expected = concat!(
+        "|-id-|destribution|\n",
+        "|----+------------|\n",
+        "|-0 -|Fedora      |\n",
+        "|-2 -|OpenSUSE    |\n",
+    );
+
+    let table = table!(
+        &data(),
+        Style::github_markdown(),
+        HorizontalAlignment(Full, Alignment::Left),
+        Indent(Full, Padding::new(0, 0, 0, 0)),
+        Indent(Column(..1), Padding::new(1, 1, 0, 0).fill('-')),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn indent_keeps_alignment() {
+    let expected = concat!(
+        " id | destribution \n",
+        "----+--------------\n",
+        "  0 |       Fedora \n",
+        "  2 |     OpenSUSE \n",
+    );
+
+    let table = table!(
+        &data(),
+        Style::psql(),
+        HorizontalAlignment(Row(1..), Alignment::Right),
+        Indent(Row(1..), Padding::new(1, 1, 0, 0)),
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,94 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use tabled::{Builder, Style, TableOption};
+
+#[test]
+fn json_flatten() {
+    let value = json!([
+        {"id": 1, "user": {"name": "maxim", "address": {"city": "Minsk"}}, "tags": ["a", "b"]},
+        {"id": 2, "user": null},
+    ]);
+    let expected = concat!(
+        " id |   tags    | user | user.address.city | user.name \n",
+        "----+-----------+------+-------------------+-----------\n",
+        " 1  | [\"a\",\"b\"] |      |       Minsk       |   maxim   \n",
+        " 2  |           |      |                   |           \n",
+    );
+
+    let mut grid = Builder::from_json(&value).build();
+    Style::psql().change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn json_nested() {
+    let value = json!({"id": 1, "tags": ["a", "b"], "owner": {"name": "maxim"}});
+    let expected = concat!(
+        " id |   owner   | tags \n",
+        "----+-----------+------\n",
+        " 1  | +-------+ |  a   \n",
+        "    | | name  | |  b   \n",
+        "    | +-------+ |      \n",
+        "    | | maxim | |      \n",
+        "    | +-------+ |      \n",
+    );
+
+    let mut grid = Builder::from_json_nested(&value).build();
+    Style::psql().change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn json_scalars() {
+    let value = json!([1, "two", null]);
+    let expected = concat!(
+        " value \n",
+        "-------\n",
+        "   1   \n",
+        "  two  \n",
+        "       \n"
+    );
+
+    let mut grid = Builder::from_json(&value).build();
+    Style::psql().change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
+
+#[test]
+fn style_serialization() {
+    let data = vec![("a", "b")];
+
+    let theme = serde_json::to_string(&Style::pseudo()).unwrap();
+    let style: Style = serde_json::from_str(&theme).unwrap();
+
+    assert_eq!(
+        tabled::table!(&data, style),
+        tabled::table!(&data, Style::pseudo())
+    );
+}
+
+#[test]
+fn style_from_partial_theme() {
+    let theme = r#"{"header": {"main": "=", "intersection": "|"}, "inner": "|"}"#;
+    let style: Style = serde_json::from_str(theme).unwrap();
+    let data = vec![("a", "b")];
+    let expected = concat!(" &str | &str \n", "======|======\n", "  a   |  b   \n",);
+
+    assert_eq!(tabled::table!(&data, style), expected);
+}
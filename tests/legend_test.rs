@@ -0,0 +1,39 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Legend, Style};
+
+#[test]
+fn legend_under_table() {
+    let data = vec!["*", "**"];
+    let expected = concat!(
+        "┌──────┐\n",
+        "│ &str │\n",
+        "├──────┤\n",
+        "│  *   │\n",
+        "│  **  │\n",
+        "└──────┘\n",
+        "Legend:\n",
+        "  *  a star\n",
+        "  ** two stars\n",
+    );
+
+    let table = table!(
+        &data,
+        Style::pseudo_clean(),
+        Legend::new()
+            .symbol("*", "a star")
+            .symbol("**", "two stars"),
+    );
+
+    assert_eq!(table, expected);
+}
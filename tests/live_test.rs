@@ -0,0 +1,38 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Live, Style, TrailingNewline};
+
+#[test]
+fn live_updates_erase_previous_output() {
+    let mut live = Live::new();
+
+    let outputs = ["a\nb", "c"]
+        .iter()
+        .map(|text| live.update(table!(&vec![*text], Style::blank(0))))
+        .collect::<Vec<_>>();
+
+    assert_eq!(outputs[0], "&str\n a  \n b  \n");
+    assert_eq!(outputs[1], "\r\u{1b}[3A\u{1b}[J&str\n c  \n");
+    assert_eq!(live.update("x"), "\r\u{1b}[2A\u{1b}[Jx");
+}
+
+#[test]
+fn live_update_without_trailing_newline() {
+    let mut live = Live::new();
+    live.update(table!(&vec!["a"], Style::blank(0), TrailingNewline(false)));
+
+    let output = live.update("b");
+
+    assert_eq!(output, "\r\u{1b}[1A\u{1b}[Jb");
+    assert_eq!(live.update("c"), "\r\u{1b}[Jc");
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{col, grid, row, table, Style, TableOption};
+
+#[test]
+fn grid_macro_from_rows() {
+    let version = 34;
+    let mut grid = grid![
+        ["Fedora", version, "rpm"],
+        ["Debian", 11],
+        ["OpenSUSE", 15.3, 'z',],
+    ];
+    Style::psql().change(&mut grid);
+
+    let expected = concat!(
+        "  Fedora  |  34  | rpm \n",
+        "  Debian  |  11  |     \n",
+        " OpenSUSE | 15.3 |  z  \n",
+    );
+
+    assert_eq!(grid.to_string(), expected);
+    assert!(!grid.has_header());
+}
+
+#[test]
+fn table_macro_keeps_data_form() {
+    let data = vec!["asd"];
+
+    assert_eq!(table!(&data, Style::psql()), " &str \n------\n asd  \n");
+}
+
+#[test]
+fn table_macro_takes_an_array_as_data() {
+    let table: String = table!(["Fedora", "Debian"], Style::psql());
+    assert_eq!(table, "  &str  \n--------\n Fedora \n Debian \n");
+
+    let table: String = table!(["Fedora", "Debian"]);
+    assert_eq!(table, table!(vec!["Fedora", "Debian"]));
+}
+
+#[test]
+fn row_and_col_macros() {
+    let mut grid = row!["a", 1, "b"];
+    Style::psql().change(&mut grid);
+    assert_eq!(grid.to_string(), " a | 1 | b \n");
+
+    let mut grid = col!["a", 1, "b",];
+    Style::psql().change(&mut grid);
+    assert_eq!(grid.to_string(), " a \n 1 \n b \n");
+}
@@ -0,0 +1,98 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid_without_header, table, Merge, Style, TableOption};
+
+#[test]
+fn merge_vertical_psql() {
+    let data = vec![("east", 1), ("east", 2), ("west", 3)];
+    let expected = concat!(
+        " &str | i32 \n",
+        "------+-----\n",
+        " east |  1  \n",
+        "      |  2  \n",
+        " west |  3  \n",
+    );
+
+    let table = table!(&data, Style::psql(), Merge::vertical());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn merge_vertical_pseudo() {
+    let data = vec![("east", 1), ("east", 2)];
+    let expected = concat!(
+        "┌──────┬─────┐\n",
+        "│ &str │ i32 │\n",
+        "├──────┼─────┤\n",
+        "│ east │  1  │\n",
+        "│      ├─────┤\n",
+        "│      │  2  │\n",
+        "└──────┴─────┘\n",
+    );
+
+    let table = table!(&data, Style::pseudo(), Merge::vertical());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn merge_horizontal() {
+    let data = vec![("a", "a", "b"), ("c", "d", "")];
+    let expected = concat!(
+        " &str | &str | &str \n",
+        "------+------+------\n",
+        "      a      |  b   \n",
+        "  c   |  d   |      \n",
+    );
+
+    let table = table!(&data, Style::psql(), Merge::horizontal());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn merge_vertical_and_horizontal_skips_spanned_cells() {
+    let data = vec![("a", "a", "a"), ("a", "b", "b"), ("c", "c", "d")];
+    let expected = concat!(
+        " &str | &str | &str \n",
+        "------+------+------\n",
+        "  a   |      a      \n",
+        "      |      b      \n",
+        "      c      |  d   \n",
+    );
+
+    let table = table!(&data, Style::psql(), Merge::vertical(), Merge::horizontal());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn merge_vertical_without_header() {
+    let data = vec![("a", 1), ("a", 2), ("b", 3)];
+    let expected = concat!(
+        "+---+---+\n",
+        "| a | 1 |\n",
+        "|   +---+\n",
+        "|   | 2 |\n",
+        "+---+---+\n",
+        "| b | 3 |\n",
+        "+---+---+\n",
+    );
+
+    let mut grid = build_grid_without_header(&data);
+    Style::default().change(&mut grid);
+    Merge::vertical().change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
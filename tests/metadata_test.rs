@@ -0,0 +1,45 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    build_grid, Disable, Metadata, RowMetadata, Severity, SeverityColor, Style, TableOption,
+};
+
+#[test]
+fn metadata_follows_removed_rows() {
+    let data = vec!["a", "b", "c"];
+    let mut grid = build_grid(&data);
+    Metadata(3, RowMetadata::new().url("https://example.com/c")).change(&mut grid);
+    Disable::Row(1..2).change(&mut grid);
+
+    assert_eq!(
+        grid.get_row_metadata(2).and_then(|m| m.get_url()),
+        Some("https://example.com/c")
+    );
+    assert!(grid.get_row_metadata(3).is_none());
+}
+
+#[test]
+fn severity_color() {
+    let data = vec!["debug", "info", "warning"];
+    let mut grid = build_grid(&data);
+    Style::psql().change(&mut grid);
+    Metadata(1, RowMetadata::new().severity(Severity::Debug)).change(&mut grid);
+    Metadata(2, RowMetadata::new().severity(Severity::Info)).change(&mut grid);
+    Metadata(3, RowMetadata::new().severity(Severity::Warning)).change(&mut grid);
+    SeverityColor.change(&mut grid);
+
+    let table = grid.to_string();
+    assert!(table.contains("\u{1b}[2mdebug\u{1b}[0m"));
+    assert!(table.contains(" info "));
+    assert!(table.contains("\u{1b}[33mwarning\u{1b}[0m"));
+}
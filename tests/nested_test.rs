@@ -0,0 +1,63 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    build_grid, table, Cell, Column, Disable, Nested, Style, TableOption, TrailingNewline,
+};
+
+#[test]
+fn nested_table() {
+    let mut inner = build_grid(vec!["a", "bc"]);
+    Style::default().change(&mut inner);
+
+    let expected = concat!(
+        " &str  |   &str   \n",
+        "-------+----------\n",
+        " Linux | +------+ \n",
+        "       | | &str | \n",
+        "       | +------+ \n",
+        "       | |  a   | \n",
+        "       | +------+ \n",
+        "       | |  bc  | \n",
+        "       | +------+ \n",
+    );
+
+    let data = vec![("Linux", "")];
+    let table = table!(&data, Style::psql(), Nested::new(Cell(1, 1), inner));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn nested_frameless_table_in_column() {
+    let mut inner = build_grid(vec![(1, 2)]);
+    Style::default().change(&mut inner);
+    TrailingNewline(false).change(&mut inner);
+
+    let expected = concat!(
+        "+-----------+-----------+\n",
+        "| i32 | i32 | i32 | i32 |\n",
+        "|-----+-----|-----+-----|\n",
+        "|  1  |  2  |  1  |  2  |\n",
+        "+-----------+-----------+\n",
+    );
+
+    let data = vec![("", "")];
+    let table = table!(
+        &data,
+        Style::default(),
+        Disable::Row(..1),
+        Nested::new(Column(..), inner).frameless()
+    );
+
+    assert_eq!(table, expected);
+}
@@ -0,0 +1,58 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, LineEnding, Style, TrailingNewline};
+
+#[test]
+fn crlf_line_ending() {
+    let data = vec!["a\nb"];
+    let expected = " &str \r\n------\r\n  a   \r\n  b   \r\n";
+
+    let table = table!(&data, Style::psql(), LineEnding::CrLf);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn crlf_line_ending_of_a_content() {
+    let data = vec!["a\r\nb"];
+    let expected = " &str \n------\n  a   \n  b   \n";
+
+    let table = table!(&data, Style::psql(), LineEnding::Lf);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn no_trailing_newline() {
+    let data = vec!["a"];
+    let expected = " &str \r\n------\r\n  a   ";
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        LineEnding::CrLf,
+        TrailingNewline(false)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn no_trailing_newline_embedded_into_text() {
+    let data = vec!["a"];
+    let table = table!(&data, Style::psql(), TrailingNewline(false));
+
+    let text = format!("[{}]", table);
+
+    assert_eq!(text, "[ &str \n------\n  a   ]");
+}
@@ -0,0 +1,107 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid, build_grid_without_header, Pages, Style, TableOption};
+
+#[test]
+fn pages_repeat_header() {
+    let data = vec![(1, "a"), (2, "asdf"), (3, "b"), (4, "c"), (5, "d")];
+    let mut grid = build_grid(&data);
+    Style::psql().change(&mut grid);
+
+    let pages = Pages::new(&grid, 2).collect::<Vec<_>>();
+
+    assert_eq!(
+        pages,
+        vec![
+            concat!(
+                " i32 | &str \n",
+                "-----+------\n",
+                "  1  |  a   \n",
+                "  2  | asdf \n",
+            ),
+            concat!(
+                " i32 | &str \n",
+                "-----+------\n",
+                "  3  |  b   \n",
+                "  4  |  c   \n",
+            ),
+            concat!(" i32 | &str \n", "-----+------\n", "  5  |  d   \n",),
+        ]
+    );
+}
+
+#[test]
+fn pages_close_bottom_border() {
+    let data = vec![(1, "a"), (2, "b"), (3, "c")];
+    let mut grid = build_grid(&data);
+    Style::pseudo().change(&mut grid);
+
+    let pages = Pages::new(&grid, 2).collect::<Vec<_>>();
+
+    assert_eq!(
+        pages,
+        vec![
+            concat!(
+                "┌─────┬──────┐\n",
+                "│ i32 │ &str │\n",
+                "├─────┼──────┤\n",
+                "│  1  │  a   │\n",
+                "├─────┼──────┤\n",
+                "│  2  │  b   │\n",
+                "└─────┴──────┘\n",
+            ),
+            concat!(
+                "┌─────┬──────┐\n",
+                "│ i32 │ &str │\n",
+                "├─────┼──────┤\n",
+                "│  3  │  c   │\n",
+                "└─────┴──────┘\n",
+            ),
+        ]
+    );
+}
+
+#[test]
+fn pages_of_empty_table() {
+    let data: Vec<(i32, &str)> = Vec::new();
+    let mut grid = build_grid(&data);
+    Style::psql().change(&mut grid);
+
+    let pages = Pages::new(&grid, 10).collect::<Vec<_>>();
+
+    assert_eq!(pages, vec![" i32 | &str \n-----+------\n".to_string()]);
+}
+
+#[test]
+fn pages_of_a_size_bigger_than_table() {
+    let data = vec![(1, "a"), (2, "b")];
+    let grid = build_grid(&data);
+
+    let pages = Pages::new(&grid, 10).collect::<Vec<_>>();
+
+    assert_eq!(pages, vec![grid.to_string()]);
+}
+
+#[test]
+fn pages_without_header() {
+    let data = vec![("b", "2"), ("a", "10"), ("a", "1")];
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+
+    let pages = Pages::new(&grid, 2).collect::<Vec<_>>();
+
+    assert_eq!(
+        pages,
+        vec![concat!(" b | 2  \n", " a | 10 \n"), " a | 1  \n"]
+    );
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Builder, Style, TableOption};
+
+fn data() -> Vec<(&'static str, &'static str, usize)> {
+    vec![
+        ("Fedora", "https://getfedora.org/", 34),
+        ("OpenSUSE", "https://www.opensuse.org/", 15),
+        ("Endeavouros", "", 2021),
+    ]
+}
+
+fn reparse(text: &str) -> String {
+    let mut grid = Builder::parse(text).unwrap().build();
+    Style::psql().change(&mut grid);
+    grid.to_string()
+}
+
+#[test]
+fn parse_round_trips_styles() {
+    let expected = table!(&data(), Style::psql());
+    let styles = vec![
+        Style::default(),
+        Style::psql(),
+        Style::github_markdown(),
+        Style::pseudo(),
+        Style::pseudo_clean(),
+    ];
+
+    for style in styles {
+        let table = table!(&data(), style);
+        assert_eq!(reparse(&table), expected, "{}", table);
+    }
+}
+
+#[test]
+fn parse_multiline_rows() {
+    let table = table!(&[("Total\nRevenue", "EMEA\nAPAC\nLATAM")], Style::pseudo());
+    let builder = Builder::parse(&table).unwrap();
+
+    let mut expected = Builder::new()
+        .header(vec!["&str", "&str"])
+        .add_row(vec!["Total\nRevenue", "EMEA\nAPAC\nLATAM"])
+        .build();
+    let mut grid = builder.build();
+    Style::psql().change(&mut grid);
+    Style::psql().change(&mut expected);
+
+    assert_eq!(grid.to_string(), expected.to_string());
+}
+
+#[test]
+fn parse_hand_typed_table() {
+    let text = "
+        name      | version
+        ==========|========
+        tabled    | 0.1.3
+        papergrid | 0.1.9
+    ";
+
+    let expected = concat!(
+        "   name    | version \n",
+        "-----------+---------\n",
+        "  tabled   |  0.1.3  \n",
+        " papergrid |  0.1.9  \n",
+    );
+
+    assert_eq!(reparse(text), expected);
+}
+
+#[test]
+fn parse_vertical_line_inside_a_cell() {
+    let table = table!(&[("a|b", "c")], Style::default());
+    let expected = concat!(" &str | &str \n", "------+------\n", " a|b  |  c   \n",);
+
+    assert_eq!(reparse(&table), expected);
+}
+
+#[test]
+fn parse_empty_text() {
+    assert!(Builder::parse("").is_none());
+    assert!(Builder::parse("+---+\n+---+\n").is_none());
+}
+
+fn from_markdown(text: &str) -> String {
+    let mut grid = Builder::from_markdown(text).unwrap().build();
+    Style::psql().change(&mut grid);
+    grid.to_string()
+}
+
+#[test]
+fn from_markdown_alignment() {
+    let text = concat!(
+        "| left | center | right | default |\n",
+        "|:-----|:------:|------:|---------|\n",
+        "| a | b | c | d |\n",
+    );
+
+    assert_eq!(
+        from_markdown(text),
+        concat!(
+            " left | center | right | default \n",
+            "------+--------+-------+---------\n",
+            " a    |   b    |     c |    d    \n",
+        )
+    );
+}
+
+#[test]
+fn from_markdown_table_inside_a_text() {
+    let text = concat!(
+        "# Distributions\n",
+        "\n",
+        "Some text | with a pipe.\n",
+        "\n",
+        "name | version\n",
+        "--- | ---\n",
+        "Fedora | 34\n",
+        "OpenSUSE\n",
+        "Endeavouros | 2021 | extra\n",
+        "\n",
+        "| not | a row |\n",
+    );
+
+    assert_eq!(
+        from_markdown(text),
+        concat!(
+            "    name     | version \n",
+            "-------------+---------\n",
+            "   Fedora    |   34    \n",
+            "  OpenSUSE   |         \n",
+            " Endeavouros |  2021   \n",
+        )
+    );
+}
+
+#[test]
+fn from_markdown_escaped_pipe() {
+    let text = concat!("| expr |\n", "|---|\n", "| a \\| b |\n");
+
+    assert_eq!(
+        from_markdown(text),
+        concat!(" expr  \n", "-------\n", " a | b \n")
+    );
+}
+
+#[test]
+fn from_markdown_round_trips_github_markdown() {
+    let text = table!(&data(), Style::github_markdown());
+    assert_eq!(from_markdown(&text), table!(&data(), Style::psql()));
+}
+
+#[test]
+fn from_markdown_without_table() {
+    assert!(Builder::from_markdown("").is_none());
+    assert!(Builder::from_markdown("# Title\n---\ntext\n").is_none());
+    assert!(Builder::from_markdown("| a | b |\n|---|\n").is_none());
+}
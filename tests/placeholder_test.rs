@@ -0,0 +1,43 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Full, Placeholder, Row, Style};
+
+#[test]
+fn placeholder_for_empty_cells() {
+    let data = vec![("a", "  "), ("", "b")];
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  a   | N/A  \n",
+        " N/A  |  b   \n",
+    );
+
+    let table = table!(&data, Style::psql(), Placeholder(Full, String::from("N/A")));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn placeholder_for_particular_rows() {
+    let data = vec![("", ""), ("", "")];
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  -   |  -   \n",
+        "      |      \n",
+    );
+
+    let table = table!(&data, Style::psql(), Placeholder(Row(1..2), "-"));
+
+    assert_eq!(table, expected);
+}
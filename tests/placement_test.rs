@@ -0,0 +1,53 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Caption, Placement, Style};
+
+#[test]
+fn placement_right() {
+    let data = vec![("a", "b")];
+    let expected = concat!(
+        "    +------+------+\n",
+        "    | &str | &str |\n",
+        "    +------+------+\n",
+        "    |  a   |  b   |\n",
+        "    +------+------+\n",
+    );
+
+    let table = table!(&data, Placement::right(19));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn placement_center_with_caption() {
+    let data = vec!["a"];
+    let expected = concat!("  Title \n", "   &str \n", "  ------\n", "    a   \n",);
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Caption::top("Title"),
+        Placement::center(10)
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn placement_narrower_than_table() {
+    let data = vec!["a"];
+
+    let table = table!(&data, Style::psql(), Placement::center(2));
+
+    assert_eq!(table, table!(&data, Style::psql()));
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    table, HorizontalLine, LineText, Separator, SeparatorEvery, Style, VerticalLine,
+    VerticalSeparator,
+};
+
+#[test]
+fn separator_under_header_only() {
+    let data = vec!["a", "b", "c"];
+    let expected = concat!(
+        "+------+\n",
+        "| &str |\n",
+        "+------+\n",
+        "|  a   |\n",
+        "|  b   |\n",
+        "|  c   |\n",
+        "+------+\n",
+    );
+
+    let table = table!(&data, Separator(2.., HorizontalLine::empty()));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn separator_out_of_table_is_ignored() {
+    let data = vec!["a"];
+    let expected = concat!(
+        "+------+\n",
+        "| &str |\n",
+        "+------+\n",
+        "|  a   |\n",
+        "+------+\n",
+    );
+
+    let table = table!(&data, Separator(2..10, HorizontalLine::empty()));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn separator_every_n_rows() {
+    let data = vec![1, 2, 3, 4, 5, 6];
+    let expected = concat!(
+        "┌─────┐\n",
+        "│ i32 │\n",
+        "├─────┤\n",
+        "│  1  │\n",
+        "│  2  │\n",
+        "│  3  │\n",
+        "├═════┤\n",
+        "│  4  │\n",
+        "│  5  │\n",
+        "│  6  │\n",
+        "└─────┘\n",
+    );
+
+    let table = table!(
+        &data,
+        Style::pseudo_clean(),
+        SeparatorEvery(3, HorizontalLine::new('═', '┼', Some('├'), Some('┤'))),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn vertical_separator_removes_interior_lines() {
+    let data = vec![("a", "b", "c")];
+    let expected = concat!(
+        "+------------------+\n",
+        "| &str  &str  &str |\n",
+        "+------------------+\n",
+        "|  a     b     c   |\n",
+        "+------------------+\n",
+    );
+
+    let table = table!(&data, VerticalSeparator(1.., VerticalLine::empty()));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn vertical_separator_override() {
+    let data = vec![("a", "b")];
+    let expected = concat!(
+        "+------║------+\n",
+        "| &str ║ &str |\n",
+        "+------║------+\n",
+        "|  a   ║  b   |\n",
+        "+------║------+\n",
+    );
+
+    let table = table!(
+        &data,
+        VerticalSeparator(1..2, VerticalLine::new('║', '║', Some('║'), Some('║'))),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn line_text_as_section_title() {
+    let data = vec!["a", "b", "c", "d"];
+    let expected = concat!(
+        " &str \n", "------\n", "  a   \n", "  b   \n", "--c&d-\n", "  c   \n", "  d   \n",
+    );
+
+    let table = table!(&data, Style::psql(), LineText(3, "--c&d-"));
+
+    assert_eq!(table, expected);
+}
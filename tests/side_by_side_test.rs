@@ -0,0 +1,56 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, SideBySide, Style};
+
+#[test]
+fn side_by_side_default_gutter() {
+    let left = table!(&vec!["a"], Style::psql());
+    let right = table!(&vec![1, 2], Style::psql());
+    let expected = concat!(
+        " &str    i32 \n",
+        "------  -----\n",
+        "  a       1  \n",
+        "          2  \n",
+    );
+
+    let output = SideBySide::new().table(left).table(right).to_string();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn side_by_side_three_tables() {
+    let expected = concat!(
+        "+---+ | +-----+ | +---+\n",
+        "|a  | | |12345| | |b  |\n",
+        "+---+ | +-----+ | |c  |\n",
+        "      |         | +---+\n",
+    );
+
+    let output = SideBySide::new()
+        .gutter(1)
+        .table("+---+\n|a  |\n+---+\n")
+        .table("|\n|\n|\n|\n")
+        .table("+-----+\n|12345|\n+-----+\n")
+        .table("|\n|\n|\n|\n")
+        .table("+---+\n|b  |\n|c  |\n+---+\n")
+        .to_string();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn side_by_side_empty() {
+    assert_eq!(SideBySide::new().to_string(), "");
+    assert_eq!(SideBySide::new().table("").table("a").to_string(), "  a\n");
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid_without_header, table, Merge, Sort, Style, TableOption, Tabled};
+
+#[derive(Tabled)]
+struct File {
+    name: &'static str,
+    size: usize,
+}
+
+fn data() -> Vec<File> {
+    vec![
+        File {
+            name: "b.txt",
+            size: 9,
+        },
+        File {
+            name: "a.txt",
+            size: 10,
+        },
+        File {
+            name: "c.txt",
+            size: 9,
+        },
+    ]
+}
+
+#[test]
+fn sort_by_header() {
+    let expected = concat!(
+        " name  | size \n",
+        "-------+------\n",
+        " a.txt |  10  \n",
+        " b.txt |  9   \n",
+        " c.txt |  9   \n",
+    );
+
+    let table = table!(&data(), Style::psql(), Sort::by_header("name"));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_descending_is_stable() {
+    let expected = concat!(
+        " name  | size \n",
+        "-------+------\n",
+        " b.txt |  9   \n",
+        " c.txt |  9   \n",
+        " a.txt |  10  \n",
+    );
+
+    let table = table!(&data(), Style::psql(), Sort::by_column(1).descending());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_with_custom_comparator() {
+    let expected = concat!(
+        " name  | size \n",
+        "-------+------\n",
+        " b.txt |  9   \n",
+        " c.txt |  9   \n",
+        " a.txt |  10  \n",
+    );
+
+    let table = table!(
+        &data(),
+        Style::psql(),
+        Sort::by_column(1).compare(|a, b| {
+            let (a, b) = (a.parse::<usize>().unwrap(), b.parse::<usize>().unwrap());
+            a.cmp(&b)
+        })
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_by_unknown_header() {
+    let table = table!(&data(), Style::psql(), Sort::by_header("owner"));
+
+    assert_eq!(table, table!(&data(), Style::psql()));
+}
+
+#[test]
+fn sort_unmerges_split_cells() {
+    let data = vec![("east", "pears"), ("east", "apples"), ("west", "apples")];
+    let expected = concat!(
+        "+------+--------+\n",
+        "| &str |  &str  |\n",
+        "+------+--------+\n",
+        "| east | apples |\n",
+        "+------+--------+\n",
+        "| west | apples |\n",
+        "+------+--------+\n",
+        "| east | pears  |\n",
+        "+------+--------+\n",
+    );
+
+    let table = table!(&data, Merge::vertical(), Sort::by_column(1));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sort_without_header() {
+    let data = vec![("b", "2"), ("a", "10"), ("a", "1")];
+    let expected = concat!(" a | 10 \n", " a | 1  \n", " b | 2  \n");
+
+    let mut grid = build_grid_without_header(&data);
+    Style::psql().change(&mut grid);
+    Sort::by_column(0).change(&mut grid);
+
+    assert_eq!(grid.to_string(), expected);
+}
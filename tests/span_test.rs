@@ -0,0 +1,59 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Cell, Row, Span, SpanDistribution, Style};
+
+#[test]
+fn span_cells_of_a_row_in_pairs() {
+    let data = vec![(1, 2, 3, 4), (5, 6, 7, 8)];
+    let expected = concat!(
+        "+-----+-----+-----+-----+\n",
+        "| i32 | i32 | i32 | i32 |\n",
+        "+-----+-----+-----+-----+\n",
+        "|     1     |     3     |\n",
+        "+-----+-----+-----+-----+\n",
+        "|  5  |  6  |  7  |  8  |\n",
+        "+-----+-----+-----+-----+\n",
+    );
+
+    let table = table!(&data, Span(Row(1..2), 2));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn span_widens_columns_evenly() {
+    let data = vec![("A longer description", "b", "c")];
+    let expected = concat!(
+        " &str  | &str  | &str \n",
+        "-------+-------+------\n",
+        " A longer description \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Span(Cell(1, 0), 3),
+        SpanDistribution::Even
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn span_out_of_range_is_ignored() {
+    let data = vec![("a", "b")];
+
+    let table = table!(&data, Style::psql(), Span(Cell(1, 1), 2));
+
+    assert_eq!(table, table!(&data, Style::psql()));
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Split, Style};
+
+#[test]
+fn split_columns() {
+    let data = vec![(1, "a", "b", "c"), (2, "d", "e", "f")];
+    let expected = concat!(
+        " i32  | &str \n",
+        "------+------\n",
+        "  1   |  a   \n",
+        "  2   |  d   \n",
+        "------+------\n",
+        " &str | &str \n",
+        "------+------\n",
+        "  b   |  c   \n",
+        "  e   |  f   \n",
+    );
+
+    let table = table!(&data, Style::psql(), Split::column(2));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn split_columns_with_key() {
+    let data = vec![(1, "a", "b", "c"), (2, "d", "e", "f")];
+    let expected = concat!(
+        " i32 | &str | &str \n",
+        "-----+------+------\n",
+        "  1  |  a   |  b   \n",
+        "  2  |  d   |  e   \n",
+        "-----+------+------\n",
+        " i32 | &str |      \n",
+        "-----+------+------\n",
+        "  1  |  c   |      \n",
+        "  2  |  f   |      \n",
+    );
+
+    let table = table!(&data, Style::psql(), Split::column(3).key(0));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn split_columns_with_borders() {
+    let data = vec![(1, "a", "b"), (2, "d", "e")];
+    let expected = concat!(
+        "┌─────┬──────┐\n",
+        "│ i32 │ &str │\n",
+        "├─────┼──────┤\n",
+        "│  1  │  a   │\n",
+        "├─────┼──────┤\n",
+        "│  2  │  d   │\n",
+        "├─────┼──────┤\n",
+        "│ i32 │ &str │\n",
+        "├─────┼──────┤\n",
+        "│  1  │  b   │\n",
+        "├─────┼──────┤\n",
+        "│  2  │  e   │\n",
+        "└─────┴──────┘\n",
+    );
+
+    let table = table!(&data, Style::pseudo(), Split::column(2).key(0));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn split_narrow_table() {
+    let data = vec![(1, "a"), (2, "d")];
+
+    let table = table!(&data, Style::psql(), Split::column(2));
+
+    assert_eq!(table, table!(&data, Style::psql()));
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{build_grid, build_grid_without_header, sql, Builder};
+
+#[test]
+fn sql_insert_quotes_values() {
+    let grid = Builder::new()
+        .header(vec!["name", "version", "note"])
+        .add_row(vec!["tabled", "0.1.3", "it's \"fine\""])
+        .add_row(vec!["papergrid", "9", ""])
+        .build();
+
+    assert_eq!(
+        sql::insert(&grid, "crates"),
+        concat!(
+            "INSERT INTO crates (\"name\", \"version\", \"note\") VALUES ('tabled', '0.1.3', 'it''s \"fine\"');\n",
+            "INSERT INTO crates (\"name\", \"version\", \"note\") VALUES ('papergrid', 9, NULL);\n",
+        )
+    );
+}
+
+#[test]
+fn sql_insert_numbers() {
+    let grid = build_grid_without_header(vec![("-1.5", "0.25", "007", "1e5", "-", "1.")]);
+
+    assert_eq!(
+        sql::insert(&grid, "numbers"),
+        "INSERT INTO numbers VALUES (-1.5, 0.25, '007', '1e5', '-', '1.');\n",
+    );
+}
+
+#[test]
+fn sql_insert_quotes_header() {
+    let grid = Builder::new()
+        .header(vec!["say \"hi\""])
+        .add_row(vec!["hi"])
+        .build();
+
+    assert_eq!(
+        sql::insert(&grid, "public.greetings"),
+        "INSERT INTO public.greetings (\"say \"\"hi\"\"\") VALUES ('hi');\n",
+    );
+}
+
+#[test]
+fn sql_insert_without_records() {
+    let grid = build_grid(Vec::<(&str, i32)>::new());
+    assert_eq!(sql::insert(&grid, "empty"), "");
+}
+
+#[test]
+fn sql_insert_exports_content_of_providers() {
+    let mut grid = build_grid_without_header(vec![("", "")]);
+    grid.set_display(0, 0, 42).unwrap();
+    grid.set_display(0, 1, "42 apples").unwrap();
+
+    assert_eq!(
+        sql::insert(&grid, "fruits"),
+        "INSERT INTO fruits VALUES (42, '42 apples');\n",
+    );
+}
@@ -11,7 +11,7 @@
 // copies or substantial portions of the Software.
 
 use tabled::style::Line;
-use tabled::{table, Tabled};
+use tabled::{table, Disable, Tabled};
 
 #[derive(Tabled)]
 struct Linux {
@@ -227,6 +227,76 @@ fn noborder_style() {
     assert_eq!(table, expected);
 }
 
+#[test]
+fn frame_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        "+---------------------------------------------+\n",
+        "| id  destribution            link            |\n",
+        "| 0      Fedora      https://getfedora.org/   |\n",
+        "| 2     OpenSUSE    https://www.opensuse.org/ |\n",
+        "| 3   Endeavouros   https://endeavouros.com/  |\n",
+        "+---------------------------------------------+\n",
+    );
+
+    let table = table!(&data, tabled::Style::frame());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn blank_style() {
+    let data = vec![
+        Linux {
+            id: 0,
+            destribution: "Fedora",
+            link: "https://getfedora.org/",
+        },
+        Linux {
+            id: 2,
+            destribution: "OpenSUSE",
+            link: "https://www.opensuse.org/",
+        },
+        Linux {
+            id: 3,
+            destribution: "Endeavouros",
+            link: "https://endeavouros.com/",
+        },
+    ];
+
+    let expected = concat!(
+        "id  destribution  link                     \n",
+        "0   Fedora        https://getfedora.org/   \n",
+        "2   OpenSUSE      https://www.opensuse.org/\n",
+        "3   Endeavouros   https://endeavouros.com/ \n",
+    );
+
+    let table = table!(
+        &data,
+        tabled::Style::blank(2),
+        tabled::HorizontalAlignment(tabled::Full, tabled::Alignment::Left)
+    );
+
+    assert_eq!(table, expected);
+}
+
 #[test]
 fn style_head_changes() {
     let data = vec![
@@ -342,3 +412,92 @@ fn custom_style() {
 
     assert_eq!(table, expected);
 }
+
+#[test]
+fn style_tweaks() {
+    let data = vec![("a", "b"), ("c", "d")];
+    let expected = concat!(
+        "*============*\n",
+        "# &str  &str #\n",
+        "#  a     b   #\n",
+        "#  c     d   #\n",
+        "*============*\n",
+    );
+
+    let style = tabled::Style::default()
+        .remove_horizontal()
+        .remove_vertical()
+        .horizontal('=')
+        .vertical('#')
+        .corner_top_left('*')
+        .corner_top_right('*')
+        .corner_bottom_left('*')
+        .corner_bottom_right('*');
+    let table = table!(&data, style);
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_line_under_header() {
+    let data = vec![("a", "b")];
+    let expected = concat!(" &str | &str \n", "======|======\n", "  a   |  b   \n",);
+
+    let table = table!(
+        &data,
+        tabled::Style::noborder().inner('|').line_under_header('=')
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn style_is_independent_of_order_of_structural_changes() {
+    let data = vec![("a", "b"), ("c", "d"), ("e", "f")];
+    let expected = concat!(
+        "┌──────┬──────┐\n",
+        "│ &str │ &str │\n",
+        "├──────┼──────┤\n",
+        "│  c   │  d   │\n",
+        "└──────┴──────┘\n",
+    );
+
+    let before = table!(
+        &data,
+        tabled::Style::pseudo_clean(),
+        Disable::Row(1..2),
+        Disable::Row(2..)
+    );
+    let after = table!(
+        &data,
+        Disable::Row(1..2),
+        Disable::Row(2..),
+        tabled::Style::pseudo_clean()
+    );
+
+    assert_eq!(before, expected);
+    assert_eq!(after, expected);
+}
+
+#[test]
+fn style_header_line_is_removed_with_header() {
+    let data = vec![("a", "b"), ("c", "d")];
+
+    let removed_after_style = table!(&data, tabled::Style::pseudo_clean(), Disable::Row(..1));
+    let removed_before_style = table!(&data, Disable::Row(..1), tabled::Style::pseudo_clean());
+
+    assert_eq!(
+        removed_after_style,
+        concat!("┌───┬───┐\n", "│ a │ b │\n", "│ c │ d │\n", "└───┴───┘\n",)
+    );
+    assert_eq!(
+        removed_before_style,
+        concat!(
+            "┌───┬───┐\n",
+            "│ a │ b │\n",
+            "├───┼───┤\n",
+            "│ c │ d │\n",
+            "└───┴───┘\n",
+        )
+    );
+}
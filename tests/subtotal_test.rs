@@ -0,0 +1,80 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Aggregate, Style, Subtotals};
+
+#[test]
+fn subtotals_aggregates() {
+    let data = vec![
+        ("east", "1.5", "a"),
+        ("east", "2.25", "b"),
+        ("west", "4", "c"),
+        ("west", "n/a", "d"),
+    ];
+    let expected = concat!(
+        "  &str   | &str | &str \n",
+        "---------+------+------\n",
+        "  east   | 1.5  |  a   \n",
+        "  east   | 2.25 |  b   \n",
+        " groups  | 1.88 |  2   \n",
+        "  west   |  4   |  c   \n",
+        "  west   | n/a  |  d   \n",
+        " groups  | 4.00 |  2   \n",
+        " overall | 2.58 |  4   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Subtotals::new(0)
+            .aggregate(1, Aggregate::Average)
+            .aggregate(2, Aggregate::Count)
+            .label("groups")
+            .total_label("overall"),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn subtotals_min_max_without_total() {
+    let data = vec![("a", 3, 0.5), ("a", 1, 0.25), ("b", 2, 1.0)];
+    let expected = concat!(
+        "   &str   | i32 | f64  \n",
+        "----------+-----+------\n",
+        "    a     |  3  | 0.5  \n",
+        "    a     |  1  | 0.25 \n",
+        " Subtotal |  1  | 0.50 \n",
+        "    b     |  2  |  1   \n",
+        " Subtotal |  2  |  1   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Subtotals::new(0)
+            .aggregate(1, Aggregate::Min)
+            .aggregate(2, Aggregate::Max)
+            .no_total(),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn subtotals_group_by_out_of_range() {
+    let data = vec![("a", 1)];
+
+    let table = table!(&data, Style::psql(), Subtotals::new(2));
+
+    assert_eq!(table, table!(&data, Style::psql()));
+}
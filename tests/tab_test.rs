@@ -0,0 +1,42 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Style, TabSize};
+
+#[test]
+fn tabs_are_expanded_by_default() {
+    let data = vec![("a\tb", "1"), ("abcd\te", "2")];
+    let expected = concat!(
+        "   &str    | &str \n",
+        "-----------+------\n",
+        "   a   b   |  1   \n",
+        " abcd    e |  2   \n",
+    );
+
+    let table = table!(&data, Style::psql());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn tab_size() {
+    let data = vec!["ab\tc\td"];
+    let expected = concat!(
+        "       &str        \n",
+        "-------------------\n",
+        " ab      c       d \n",
+    );
+
+    let table = table!(&data, Style::psql(), TabSize(8));
+
+    assert_eq!(table, expected);
+}
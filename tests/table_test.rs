@@ -11,7 +11,7 @@
 // copies or substantial portions of the Software.
 
 use std::collections::{BTreeMap, BTreeSet};
-use tabled::{table, Tabled};
+use tabled::{table, Abbreviations, Sanitization, Style, Tabled};
 
 #[test]
 fn table_vector_structures() {
@@ -175,6 +175,55 @@ fn table_vector_structures_with_hidden_field() {
     assert_eq!(expected, table);
 }
 
+#[test]
+fn table_sanitizes_control_sequences() {
+    let data = vec!["\u{1b}]0;hacked\u{7}hello\u{1b}[2A"];
+    let expected = "+-------+\n\
+                         | &str  |\n\
+                         +-------+\n\
+                         | hello |\n\
+                         +-------+\n";
+
+    let table = table!(&data);
+    assert_eq!(expected, table);
+
+    let table = table!(&data, Sanitization::Off);
+    assert!(table.contains("\u{1b}]0;"));
+}
+
+#[test]
+fn table_treats_crlf_as_a_line_ending() {
+    let data = vec!["hello\r\nworld"];
+    let expected = "+-------+\n\
+                         | &str  |\n\
+                         +-------+\n\
+                         | hello |\n\
+                         | world |\n\
+                         +-------+\n";
+
+    let table = table!(&data, Sanitization::Replace('?'));
+    assert_eq!(expected, table);
+}
+
+#[test]
+fn table_abbreviates_headers_only_when_needed() {
+    let data = vec![(1, 2)];
+    let abbreviations = |width| {
+        Abbreviations::new(width)
+            .add("i32", "i")
+            .add("not a header", "-")
+    };
+
+    let table = table!(&data, Style::psql(), abbreviations(13));
+    assert_eq!(table, " i32 | i32 \n-----+-----\n  1  |  2  \n");
+
+    let table = table!(&data, Style::psql(), abbreviations(10));
+    assert_eq!(table, " i | i32 \n---+-----\n 1 |  2  \n");
+
+    let table = table!(&data, Style::psql(), abbreviations(0));
+    assert_eq!(table, " i | i \n---+---\n 1 | 2 \n");
+}
+
 mod default_types {
     use super::*;
 
@@ -431,6 +480,7 @@ mod default_types {
         assert_eq!(expected, table);
     }
 
+    #[allow(dead_code)]
     #[test]
     fn table_enum() {
         #[derive(Tabled)]
@@ -475,6 +525,7 @@ mod default_types {
         assert_eq!(expected, table);
     }
 
+    #[allow(dead_code)]
     #[test]
     fn table_enum_with_hidden_variant() {
         #[derive(Tabled)]
@@ -550,6 +601,23 @@ mod default_types {
         assert_eq!(expected, table);
     }
 
+    #[test]
+    fn table_btreemap_key_value() {
+        let mut map = BTreeMap::new();
+        map.insert("host", String::from("localhost"));
+        map.insert("port", String::from("8080"));
+
+        let expected = concat!(
+            " &str |  String   \n",
+            "------+-----------\n",
+            " host | localhost \n",
+            " port |   8080    \n",
+        );
+
+        let table = table!(&map, Style::psql());
+        assert_eq!(expected, table);
+    }
+
     #[test]
     fn table_btreeset() {
         #[derive(Tabled, PartialEq, Eq, PartialOrd, Ord)]
@@ -576,4 +644,132 @@ mod default_types {
         let table = table!(&map);
         assert_eq!(expected, table);
     }
+
+    #[test]
+    fn table_string_vec() {
+        let data = vec![String::from("hello"), String::from("world")];
+        let expected = concat!(" String \n", "--------\n", " hello  \n", " world  \n",);
+
+        let table = table!(&data, Style::psql());
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_option_vec() {
+        let data = vec![Some((1, "a")), None, Some((3, "c"))];
+        let expected = concat!(
+            " i32 | &str \n",
+            "-----+------\n",
+            "  1  |  a   \n",
+            "     |      \n",
+            "  3  |  c   \n",
+        );
+
+        let table = table!(&data, Style::psql());
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_box_vec() {
+        let data = vec![Box::new(1), Box::new(2)];
+        let expected = concat!(" i32 \n", "-----\n", "  1  \n", "  2  \n");
+
+        let table = table!(&data, Style::psql());
+        assert_eq!(expected, table);
+    }
+
+    #[test]
+    fn table_array_vec() {
+        let data = vec![[1, 2, 3], [4, 5, 6]];
+        let expected = concat!(
+            " 0 | 1 | 2 \n",
+            "---+---+---\n",
+            " 1 | 2 | 3 \n",
+            " 4 | 5 | 6 \n",
+        );
+
+        let table = table!(&data, Style::psql());
+        assert_eq!(expected, table);
+    }
+}
+
+#[test]
+fn total_size_matches_rendered_table() {
+    use tabled::{Caption, Details, Indent, Margin, Padding, Row, TableOption};
+
+    let data = vec![("Fedora\nLinux", 34), ("Debian", 11), ("OpenBSD", 7)];
+    let mut grid = tabled::build_grid(&data);
+    Style::psql().change(&mut grid);
+    Caption::top("Distributions").change(&mut grid);
+    Details::new(|i| (i == 1).then(|| String::from("a detail which is wrapped into lines")))
+        .change(&mut grid);
+    Margin::new(1, 1, 2, 1).change(&mut grid);
+    Indent(Row(2..3), Padding::new(1, 1, 1, 0)).change(&mut grid);
+
+    let table = grid.to_string();
+
+    assert_eq!(grid.total_height(), table.lines().count());
+    assert_eq!(
+        grid.total_width(),
+        table.lines().map(|l| l.chars().count()).max().unwrap()
+    );
+}
+
+#[test]
+fn appended_rows_line_up_with_full_table() {
+    use papergrid::{Entity, Settings};
+    use tabled::{ExactWidths, TableOption};
+
+    let mut grid = tabled::build_grid(vec![("Fedora", 34)]);
+    Style::pseudo().change(&mut grid);
+    ExactWidths(vec![10, 6]).change(&mut grid);
+
+    let appender = grid.appender();
+    let mut output = appender.render(&grid, 0);
+    for (name, version) in [("Debian", "11"), ("OpenSUSE", "15")] {
+        grid.push_row();
+        let row = grid.count_rows() - 1;
+        grid.set(Entity::Cell(row, 0), Settings::new().text(name));
+        grid.set(Entity::Cell(row, 1), Settings::new().text(version));
+        output.push_str(&appender.render(&grid, row));
+    }
+    output.push_str(&appender.render_bottom_line(&grid));
+
+    assert_eq!(output, grid.to_string());
+}
+
+#[test]
+fn table_without_header() {
+    use tabled::TableOption;
+
+    #[derive(Tabled)]
+    struct Distribution {
+        name: &'static str,
+        based_on: &'static str,
+    }
+
+    let data = vec![
+        Distribution {
+            name: "Manjaro",
+            based_on: "Arch",
+        },
+        Distribution {
+            name: "Ubuntu",
+            based_on: "Debian",
+        },
+    ];
+
+    let mut grid = tabled::build_grid_without_header(&data);
+    Style::pseudo().change(&mut grid);
+
+    let expected = concat!(
+        "┌─────────┬────────┐\n",
+        "│ Manjaro │  Arch  │\n",
+        "├─────────┼────────┤\n",
+        "│ Ubuntu  │ Debian │\n",
+        "└─────────┴────────┘\n",
+    );
+
+    assert_eq!(grid.to_string(), expected);
+    assert!(!grid.has_header());
 }
@@ -0,0 +1,48 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{assert_table, build_grid, table, testing::diff, Style};
+
+#[test]
+fn assert_table_accepts_tables_and_grids() {
+    let data = vec![("Fedora", 34)];
+    let expected = concat!("  &str  | i32 \n", "--------+-----\n", " Fedora | 34  \n",);
+
+    assert_table!(table!(&data, Style::psql()), expected);
+    assert_table!(table!(&data, Style::psql()), String::from(expected));
+
+    let grid = build_grid(&data);
+    assert_table!(grid, table!(&data));
+}
+
+#[test]
+#[should_panic(expected = "a table doesn't match the expected one")]
+fn assert_table_panics_on_mismatch() {
+    assert_table!(table!(&["asd"], Style::psql()), " &str \n------\n qwe  \n");
+}
+
+#[test]
+fn diff_marks_mismatched_lines() {
+    let diff = diff(" a \n b \n", " a \n b\n c\n").unwrap();
+
+    assert_eq!(
+        diff,
+        concat!(
+            "  \" a \"\n",
+            "\u{1b}[31m- \" b \"\u{1b}[0m\n",
+            "\u{1b}[32m+ \" b\"\u{1b}[0m\n",
+            "\u{1b}[31m- \"\"\u{1b}[0m\n",
+            "\u{1b}[32m+ \" c\"\u{1b}[0m\n",
+            "\u{1b}[32m+ \"\"\u{1b}[0m\n",
+        )
+    );
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+#![cfg(feature = "timestamp")]
+
+use chrono::{FixedOffset, TimeZone, Utc};
+use tabled::{table, Column, Full, Style, TimeFormat, Timestamp};
+
+#[test]
+fn timestamp_in_timezone() {
+    let data = vec![
+        ("api", "2021-06-14T22:30:00Z"),
+        ("db", "2021-06-14T10:04:05.123+03:00"),
+        ("cache", "unknown"),
+    ];
+    let expected = concat!(
+        " &str  |       &str       \n",
+        "-------+------------------\n",
+        "  api  | 2021-06-15 01:30 \n",
+        "  db   | 2021-06-14 10:04 \n",
+        " cache |     unknown      \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Timestamp(
+            Column(1..),
+            FixedOffset::east_opt(3 * 3600).unwrap(),
+            TimeFormat::pattern("%Y-%m-%d %H:%M"),
+        ),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn timestamp_relative() {
+    let data = vec![
+        "2021-06-14 12:00:00",
+        "2021-06-14T11:59:30",
+        "2021-06-14T09:00:00Z",
+        "2021-06-10T12:00:00Z",
+        "2021-06-14T12:03:00Z",
+    ];
+    let expected = concat!(
+        "  &str   \n",
+        "---------\n",
+        " 0s ago  \n",
+        " 30s ago \n",
+        " 3h ago  \n",
+        " 4d ago  \n",
+        "  in 3m  \n",
+    );
+
+    let now = Utc.with_ymd_and_hms(2021, 6, 14, 12, 0, 0).unwrap();
+    let table = table!(
+        &data,
+        Style::psql(),
+        Timestamp(Full, Utc, TimeFormat::RelativeTo(now)),
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn timestamp_with_invalid_pattern() {
+    let data = vec!["2021-06-14T22:30:00Z"];
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        Timestamp(Full, Utc, TimeFormat::pattern("%Q")),
+    );
+
+    assert_eq!(table, table!(&data, Style::psql()));
+}
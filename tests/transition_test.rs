@@ -0,0 +1,44 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{morph, table, transition, Style};
+
+#[test]
+fn transition_frames_have_the_same_shape() {
+    let data = vec![("a", 1), ("b", 2)];
+
+    let frames = transition(&data, &Style::pseudo(), &Style::psql(), 10);
+
+    assert_eq!(frames.len(), 10);
+    assert_eq!(frames[0], table!(&data, Style::pseudo()));
+    assert_eq!(frames[9], table!(&data, Style::psql()));
+    for frame in &frames[1..9] {
+        assert_eq!(frame.lines().count(), frames[0].lines().count());
+        assert!(frame
+            .lines()
+            .all(|line| line.chars().count() == frames[0].lines().next().unwrap().chars().count()));
+    }
+}
+
+#[test]
+fn morph_pads_texts() {
+    let frames = morph("ab\n", "x\ny\n", 3);
+
+    assert_eq!(frames, vec!["ab\n", "xb\n  \n", "x\ny\n"]);
+}
+
+#[test]
+fn morph_few_frames() {
+    assert!(morph("a", "b", 0).is_empty());
+    assert_eq!(morph("a", "b", 1), vec!["b"]);
+    assert_eq!(morph("a", "b", 2), vec!["a", "b"]);
+}
@@ -0,0 +1,39 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{table, Cell, Head, Style, Vertical};
+
+#[test]
+fn vertical_header() {
+    let data = vec![(1, 2, 3)];
+    let expected = concat!(
+        " i | i | i \n",
+        " 3 | 3 | 3 \n",
+        " 2 | 2 | 2 \n",
+        "---+---+---\n",
+        " 1 | 2 | 3 \n",
+    );
+
+    let table = table!(&data, Style::psql(), Vertical(Head));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn vertical_multiline_cell() {
+    let data = vec!["ab\nc"];
+    let expected = concat!(" &str \n", "------\n", "  ac  \n", "  b   \n",);
+
+    let table = table!(&data, Style::psql(), Vertical(Cell(1, 0)));
+
+    assert_eq!(table, expected);
+}
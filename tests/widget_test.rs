@@ -0,0 +1,93 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+#![cfg(feature = "ratatui")]
+
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use tabled::{build_grid, GridWidget, Style, TableOption};
+
+fn render(area: Rect, widget: GridWidget<'_>) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    widget.render(area, &mut buffer);
+    buffer
+}
+
+#[test]
+fn widget_in_big_area() {
+    let data = vec![(1, "a"), (2, "b")];
+    let grid = build_grid(&data);
+
+    let buffer = render(Rect::new(0, 0, 16, 8), GridWidget::new(&grid));
+
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec![
+            "+-----+------+  ",
+            "| i32 | &str |  ",
+            "+-----+------+  ",
+            "|  1  |  a   |  ",
+            "+-----+------+  ",
+            "|  2  |  b   |  ",
+            "+-----+------+  ",
+            "                ",
+        ])
+    );
+}
+
+#[test]
+fn widget_drops_rows_which_do_not_fit() {
+    let data = vec![(1, "a"), (2, "b"), (3, "c")];
+    let grid = build_grid(&data);
+
+    let buffer = render(Rect::new(0, 0, 14, 6), GridWidget::new(&grid));
+
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec![
+            "+-----+------+",
+            "| i32 | &str |",
+            "+-----+------+",
+            "|  1  |  a   |",
+            "+-----+------+",
+            "              ",
+        ])
+    );
+}
+
+#[test]
+fn widget_cuts_lines() {
+    let data = vec![(1, "a"), (2, "b")];
+    let mut grid = build_grid(&data);
+    Style::psql().change(&mut grid);
+
+    let buffer = render(Rect::new(0, 0, 7, 3), GridWidget::new(&grid));
+
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec![" i32 | ", "-----+-", "  1  | "])
+    );
+}
+
+#[test]
+fn widget_in_shifted_area() {
+    let data = vec!["a"];
+    let mut grid = build_grid(&data);
+    Style::psql().change(&mut grid);
+
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+    GridWidget::new(&grid).render(Rect::new(2, 1, 6, 3), &mut buffer);
+
+    assert_eq!(
+        buffer,
+        Buffer::with_lines(vec!["        ", "   &str ", "  ------", "    a   ",])
+    );
+}
@@ -0,0 +1,372 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use tabled::{
+    table, ExactWidths, Justify, LayoutChange, MaxWidth, Peaker, PercentWidths, PriorityLeft,
+    PriorityRight, SampleWidths, Shrink, Style,
+};
+
+#[test]
+fn max_width_untouched() {
+    let data = vec![("ok", "Done")];
+    let width = MaxWidth::truncating(80);
+
+    let table = table!(&data, Style::psql(), &width);
+
+    assert_eq!(table, table!(&data, Style::psql()));
+    assert!(width.report().is_empty());
+}
+
+#[test]
+fn max_width_wrapping() {
+    let data = vec![("1", "Connection established")];
+    let expected = concat!(
+        " &str |  &str   \n",
+        "------+---------\n",
+        "  1   | Connect \n",
+        "      | ion est \n",
+        "      | ablishe \n",
+        "      |    d    \n",
+    );
+
+    let width = MaxWidth::wrapping(16);
+    let table = table!(&data, Style::psql(), &width);
+
+    assert_eq!(table, expected);
+    assert_eq!(
+        width.report().changes(),
+        &[LayoutChange::Wrapped {
+            row: 1,
+            column: 1,
+            width: 22
+        }],
+    );
+}
+
+#[test]
+fn max_width_shrinks_the_widest_column() {
+    let data = vec![("abcdefgh", "abcdef")];
+    let expected = concat!(
+        "  &str  |  &str  \n",
+        "--------+--------\n",
+        " abcdef | abcdef \n",
+    );
+
+    let width = MaxWidth::truncating(17);
+    let table = table!(&data, Style::psql(), &width);
+
+    assert_eq!(table, expected);
+    assert_eq!(
+        width.report().changes(),
+        &[LayoutChange::Truncated {
+            row: 1,
+            column: 0,
+            width: 8
+        }],
+    );
+}
+
+#[test]
+fn max_width_hides_columns() {
+    let data = vec![("id", "name", "description")];
+    let expected = concat!(" &str \n", "------\n", "  id  \n",);
+
+    let width = MaxWidth::truncating(8).hide_columns();
+    let table = table!(&data, Style::psql(), &width);
+
+    assert_eq!(table, expected);
+    assert_eq!(
+        width.report().changes(),
+        &[
+            LayoutChange::ColumnHidden {
+                column: 2,
+                header: "&str".to_owned()
+            },
+            LayoutChange::ColumnHidden {
+                column: 1,
+                header: "&str".to_owned()
+            },
+        ],
+    );
+}
+
+#[test]
+fn max_width_multiline_truncation() {
+    let data = vec![("a long line\nshort")];
+    let expected = concat!(" &str  \n", "-------\n", " a...  \n", " short \n",);
+
+    let width = MaxWidth::truncating(7).suffix("...");
+    let table = table!(&data, Style::psql(), &width);
+
+    assert_eq!(table, expected);
+    assert_eq!(
+        width.report().changes(),
+        &[LayoutChange::Truncated {
+            row: 1,
+            column: 0,
+            width: 11
+        }],
+    );
+}
+
+#[test]
+fn exact_widths() {
+    let data = vec![("1", "Connection")];
+    let expected = concat!(
+        " &str  | &str  \n",
+        "-------+-------\n",
+        "   1   | Conne \n",
+        "       | ction \n",
+    );
+
+    let table = table!(&data, Style::psql(), ExactWidths(vec![7, 7]));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn percent_widths() {
+    let data = vec![("1", "Connection")];
+    let expected = concat!(
+        " &str  |    &str    \n",
+        "-------+------------\n",
+        "   1   | Connection \n",
+    );
+
+    let table = table!(&data, Style::psql(), PercentWidths::new(20, vec![35, 60]));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn percent_widths_are_rounded_to_total() {
+    let data = vec![("a", "b", "c")];
+    let expected = concat!(
+        " &str  | &str | &str  \n",
+        "-------+------+-------\n",
+        "   a   |  b   |   c   \n",
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        PercentWidths::new(20, vec![33, 33, 34])
+    );
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn sample_widths() {
+    let data = vec![("1", "Done"), ("2", "Connection")];
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  1   | Done \n",
+        "  2   | Conn \n",
+        "      | ecti \n",
+        "      |  on  \n",
+    );
+
+    let table = table!(&data, Style::psql(), SampleWidths(2));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn justify_max() {
+    let data = vec![("1", "Connection")];
+    let expected = concat!(
+        "    &str    |    &str    \n",
+        "------------+------------\n",
+        "     1      | Connection \n",
+    );
+
+    let table = table!(&data, Style::psql(), Justify::max());
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn justify_fixed_width() {
+    let data = vec![("1", "Connection")];
+    let expected = concat!(
+        " &str | &str \n",
+        "------+------\n",
+        "  1   | Conn \n",
+        "      | ecti \n",
+        "      |  on  \n",
+    );
+
+    let table = table!(&data, Style::psql(), Justify::new(6));
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn max_width_truncating_keeps_graphemes() {
+    let data = vec![("1", "cafe\u{301} cre\u{300}me")];
+    let expected = concat!(
+        " &str | &str  \n",
+        "------+-------\n",
+        "  1   | cafe\u{301}. \n",
+    );
+
+    let table = table!(&data, Style::psql(), MaxWidth::truncating(15).suffix("."));
+
+    assert_eq!(table, expected);
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn max_width_truncating_doesnt_split_wide_characters() {
+    let data = vec![("1", "ab四五六")];
+    let expected = concat!(" &str | &str \n", "------+------\n", "  1   | ab四 \n",);
+
+    let table = table!(&data, Style::psql(), MaxWidth::truncating(13));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_width_wrapping_hyphenated() {
+    let data = vec![("1", "Connection established, re-trying")];
+    let expected = concat!(
+        " &str |   &str    \n",
+        "------+-----------\n",
+        "  1   | Connecti- \n",
+        "      |    on     \n",
+        "      | establis- \n",
+        "      | hed, re-  \n",
+        "      |  trying   \n",
+    );
+
+    let width = MaxWidth::wrapping(18).hyphenate();
+    let table = table!(&data, Style::psql(), &width);
+
+    assert_eq!(table, expected);
+    assert_eq!(
+        width.report().changes(),
+        &[LayoutChange::Wrapped {
+            row: 1,
+            column: 1,
+            width: 33
+        }],
+    );
+}
+
+#[test]
+fn max_width_column_policies() {
+    let data = vec![("123456", "abcdefgh", "abcdefgh")];
+    let expected = concat!(
+        "  &str  | &str  | &str  \n",
+        "--------+-------+-------\n",
+        " 123456 | abcde | abcde \n",
+        "        |  fgh  |       \n",
+    );
+
+    let width = MaxWidth::wrapping(24)
+        .column(0, Shrink::Never)
+        .column(2, Shrink::Truncate);
+    let table = table!(&data, Style::psql(), &width);
+
+    assert_eq!(table, expected);
+    assert_eq!(
+        width.report().changes(),
+        &[
+            LayoutChange::Wrapped {
+                row: 1,
+                column: 1,
+                width: 8
+            },
+            LayoutChange::Truncated {
+                row: 1,
+                column: 2,
+                width: 8
+            },
+        ],
+    );
+}
+
+#[test]
+fn max_width_column_priorities() {
+    let data = vec![("abcdefgh", "abcdefgh")];
+    let expected = concat!(
+        "   &str   | &st \n",
+        "          |  r  \n",
+        "----------+-----\n",
+        " abcdefgh | abc \n",
+        "          | def \n",
+        "          | gh  \n",
+    );
+
+    let table = table!(&data, Style::psql(), MaxWidth::wrapping(16).priority(1, 1));
+
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn max_width_peaker_left_and_right() {
+    let data = vec![("abcdefgh", "abcdefgh")];
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        MaxWidth::truncating(16).peaker(PriorityLeft)
+    );
+    assert_eq!(
+        table,
+        concat!(
+            " &st |   &str   \n",
+            "-----+----------\n",
+            " abc | abcdefgh \n",
+        )
+    );
+
+    let table = table!(
+        &data,
+        Style::psql(),
+        MaxWidth::truncating(16).peaker(PriorityRight)
+    );
+    assert_eq!(
+        table,
+        concat!(
+            "   &str   | &st \n",
+            "----------+-----\n",
+            " abcdefgh | abc \n",
+        )
+    );
+}
+
+#[test]
+fn max_width_custom_peaker() {
+    struct Stop;
+
+    impl Peaker for Stop {
+        fn peak(&self, _: &[usize], _: &[usize]) -> Option<usize> {
+            None
+        }
+    }
+
+    let data = vec![("abcdefgh", "abcdefgh")];
+    let table = table!(&data, Style::psql(), MaxWidth::truncating(16).peaker(Stop));
+
+    assert_eq!(
+        table,
+        concat!(
+            "   &str   |   &str   \n",
+            "----------+----------\n",
+            " abcdefgh | abcdefgh \n",
+        )
+    );
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2021 Maxim Zhiburt
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+#![cfg(feature = "xlsx")]
+
+use std::io::{Cursor, Read};
+
+use tabled::{build_grid, xlsx, Alignment, Column, HorizontalAlignment, TableOption};
+
+fn read_part(bytes: Vec<u8>, name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut part = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut part)
+        .unwrap();
+    part
+}
+
+#[test]
+fn xlsx_writes_strings_and_numbers() {
+    let data = vec![("Fedora", 34), ("OpenSUSE", 15)];
+    let grid = build_grid(&data);
+
+    let bytes = xlsx::to_buffer(&grid).unwrap();
+    let sheet = read_part(bytes.clone(), "xl/worksheets/sheet1.xml");
+    let strings = read_part(bytes, "xl/sharedStrings.xml");
+
+    for text in &["&amp;str", "i32", "Fedora", "OpenSUSE"] {
+        assert!(strings.contains(&format!("<t>{}</t>", text)), "{}", text);
+    }
+
+    assert!(sheet.contains("<v>34</v>"));
+    assert!(sheet.contains("<v>15</v>"));
+    assert_eq!(sheet.matches("t=\"s\"").count(), 4);
+}
+
+#[test]
+fn xlsx_keeps_leading_zeros_and_exports_providers() {
+    let data = vec![("007", "", "1e5")];
+    let mut grid = build_grid(&data);
+    grid.set_display(1, 1, 42).unwrap();
+
+    let bytes = xlsx::to_buffer(&grid).unwrap();
+    let sheet = read_part(bytes.clone(), "xl/worksheets/sheet1.xml");
+    let strings = read_part(bytes, "xl/sharedStrings.xml");
+
+    assert!(strings.contains("<t>007</t>"));
+    assert!(strings.contains("<t>1e5</t>"));
+    assert!(sheet.contains("<v>42</v>"));
+    assert_eq!(sheet.matches("t=\"s\"").count(), 5);
+}
+
+#[test]
+fn xlsx_keeps_header_and_alignment() {
+    let data = vec![("Fedora", 34)];
+    let mut grid = build_grid(&data);
+    HorizontalAlignment(Column(..1), Alignment::Left).change(&mut grid);
+    HorizontalAlignment(Column(1..), Alignment::Right).change(&mut grid);
+
+    let styles = read_part(xlsx::to_buffer(&grid).unwrap(), "xl/styles.xml");
+
+    assert!(styles.contains("<b/>"));
+    assert!(styles.contains("horizontal=\"left\""));
+    assert!(styles.contains("horizontal=\"right\""));
+    assert!(!styles.contains("horizontal=\"center\""));
+}
+
+#[test]
+fn xlsx_saves_a_file() {
+    let path = std::env::temp_dir().join("tabled_xlsx_test.xlsx");
+    xlsx::save(&build_grid(["Fedora"]), &path).unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(read_part(bytes, "xl/sharedStrings.xml").contains("<t>Fedora</t>"));
+}